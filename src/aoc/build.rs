@@ -0,0 +1,33 @@
+//! Sets `AOC_REVISION` to the commit the solutions are built from, as `+<short hash>`, or to
+//! nothing outside of a git checkout.
+
+use std::process::Command;
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    let text = String::from_utf8(output.stdout).ok()?;
+    match output.status.success() {
+        true => Some(text.trim().to_owned()),
+        false => None,
+    }
+}
+
+fn main() {
+    let revision = git(&["rev-parse", "--short", "HEAD"]);
+    println!(
+        "cargo:rustc-env=AOC_REVISION={}",
+        revision.map_or(String::new(), |hash| format!("+{hash}"))
+    );
+
+    // Rebuilt when HEAD moves to another branch or the branch to another commit.
+    for path in ["HEAD", "packed-refs"] {
+        if let Some(path) = git(&["rev-parse", "--git-path", path]) {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+    if let Some(branch) = git(&["symbolic-ref", "-q", "HEAD"]) {
+        if let Some(path) = git(&["rev-parse", "--git-path", &branch]) {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+}
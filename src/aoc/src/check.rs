@@ -0,0 +1,62 @@
+//! Properties of an input that a solution relies on without verifying them, because every real
+//! input has them but the puzzle never promises so.
+
+use std::fmt;
+
+/// An assumption about the input, and whether it holds.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Check {
+    pub assumption: &'static str,
+    /// The part of the input that breaks the assumption, if any.
+    pub result: Result<(), String>,
+}
+
+impl Check {
+    pub fn new(assumption: &'static str, result: Result<(), String>) -> Self {
+        Self { assumption, result }
+    }
+
+    /// A check of an assumption about each of many parts of the input, which fails with every
+    /// part that breaks it.
+    pub fn from_failures(assumption: &'static str, failures: Vec<String>) -> Self {
+        let result = match failures.is_empty() {
+            true => Ok(()),
+            false => Err(failures.join("; ")),
+        };
+        Self::new(assumption, result)
+    }
+
+    pub fn holds(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.result {
+            Ok(()) => write!(f, "ok    {}", self.assumption),
+            Err(evidence) => write!(f, "FAIL  {}: {evidence}", self.assumption),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let check = Check::new("the grid is square", Ok(()));
+        assert!(check.holds());
+        assert_eq!(check.to_string(), "ok    the grid is square");
+
+        let check = Check::new("the grid is square", Err("it is 3 by 4".into()));
+        assert!(!check.holds());
+        assert_eq!(check.to_string(), "FAIL  the grid is square: it is 3 by 4");
+
+        let failures = vec!["'a' is empty".to_owned(), "'b' is empty".to_owned()];
+        let check = Check::from_failures("no line is empty", failures);
+        assert_eq!(check.result, Err("'a' is empty; 'b' is empty".to_owned()));
+        assert!(Check::from_failures("no line is empty", Vec::new()).holds());
+    }
+}
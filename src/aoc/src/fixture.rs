@@ -0,0 +1,122 @@
+//! Puzzle examples for the tests, stored as files next to each day's crate.
+//!
+//! An example called `name` lives in `fixtures/<name>/`, which holds the puzzle input in
+//! `input.txt` and the expected answers in `part1.txt` and `part2.txt`. A part without a file
+//! has no expected answer for that example. The [`example_tests!`](crate::example_tests) macro
+//! turns a list of examples into a test each.
+
+use std::{fs, path::Path};
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Example {
+    pub input: String,
+    /// The expected answer, as it is displayed.
+    pub answer: String,
+}
+
+impl Example {
+    /// Loads the example `name` of the crate in `dir`, with the answer of `part`.
+    ///
+    /// Panics if a file is missing, since this is only meant for tests.
+    pub fn load(dir: impl AsRef<Path>, name: &str, part: &str) -> Self {
+        let dir = dir.as_ref().join("fixtures").join(name);
+        let read = |file: &str| {
+            let path = dir.join(file);
+            fs::read_to_string(&path)
+                .unwrap_or_else(|err| panic!("can't read '{}': {err}", path.display()))
+        };
+        Self {
+            input: read("input.txt"),
+            answer: read(&format!("{part}.txt")).trim().to_owned(),
+        }
+    }
+}
+
+/// Removes the indentation shared by all the non-blank lines of `text`, together with the blank
+/// lines at its start and end. This lets a test write a short input as an indented literal.
+pub fn dedent(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let first = lines.iter().position(|line| !line.trim().is_empty());
+    let last = lines.iter().rposition(|line| !line.trim().is_empty());
+    let (Some(first), Some(last)) = (first, last) else {
+        return String::new();
+    };
+
+    let lines = &lines[first..=last];
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let mut result = String::new();
+    for line in lines {
+        result.push_str(line.get(indent..).unwrap_or("").trim_end());
+        result.push('\n');
+    }
+    result
+}
+
+/// Generates a `#[test]` for each listed example, checking the answer `process` gives for
+/// `part`. Attributes before an example, such as `#[ignore]`, go on its test.
+///
+/// ```ignore
+/// aoc::example_tests!(process, part1: example1, example2);
+/// aoc::example_tests!(|input| process(input, 6), part1: example);
+/// aoc::example_tests!(process, part2: #[ignore = "not solved yet"] example);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($process:expr, $part:ident: $($(#[$attr:meta])* $name:ident),+ $(,)?) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                let example = $crate::fixture::Example::load(
+                    env!("CARGO_MANIFEST_DIR"),
+                    stringify!($name),
+                    stringify!($part),
+                );
+                let answer = ($process)(example.input.as_str()).unwrap();
+                assert_eq!(answer.to_string(), example.answer);
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dedent() {
+        let text = "
+            RL
+
+              AAA = (BBB, CCC)
+            BBB = (DDD, EEE)
+        ";
+        assert_eq!(dedent(text), "RL\n\n  AAA = (BBB, CCC)\nBBB = (DDD, EEE)\n");
+        assert_eq!(dedent("a\n b"), "a\n b\n");
+        assert_eq!(dedent("\n   \n"), "");
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-fixture-{}", std::process::id()));
+        let example = dir.join("fixtures/example");
+        fs::create_dir_all(&example).unwrap();
+        fs::write(example.join("input.txt"), "1 2 3\n").unwrap();
+        fs::write(example.join("part1.txt"), "6\n").unwrap();
+
+        assert_eq!(
+            Example::load(&dir, "example", "part1"),
+            Example {
+                input: "1 2 3\n".into(),
+                answer: "6".into(),
+            }
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
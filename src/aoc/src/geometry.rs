@@ -0,0 +1,215 @@
+//! Integer vectors and the four directions of a grid.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A position or offset in the plane, with `y` growing downwards like the rows of the input.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug)]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Vec2 {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Vec3 {
+    pub const ZERO: Self = Self::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+macro_rules! impl_vector {
+    ($ty:ident: $($field:ident),*) => {
+        impl $ty {
+            /// The length of the shortest path to `other` moving only along the axes.
+            pub fn manhattan(self, other: Self) -> i64 {
+                0 $(+ (self.$field - other.$field).abs())*
+            }
+
+            /// The length of the shortest path to `other` when diagonal steps are allowed too.
+            pub fn chebyshev(self, other: Self) -> i64 {
+                0 $(.max((self.$field - other.$field).abs()))*
+            }
+        }
+
+        impl Add for $ty {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl Sub for $ty {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl Mul<i64> for $ty {
+            type Output = Self;
+
+            fn mul(self, factor: i64) -> Self {
+                Self { $($field: self.$field * factor),* }
+            }
+        }
+
+        impl Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl AddAssign for $ty {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $ty {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl MulAssign<i64> for $ty {
+            fn mul_assign(&mut self, factor: i64) {
+                *self = *self * factor;
+            }
+        }
+    };
+}
+
+impl_vector!(Vec2: x, y);
+impl_vector!(Vec3: x, y, z);
+
+/// One of the four directions on a grid.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise starting at the top.
+    pub const ALL: [Self; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parses `U`, `D`, `L` and `R`, or the arrows `^`, `v`, `<` and `>`.
+    pub fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            'U' | '^' => Some(Direction::Up),
+            'R' | '>' => Some(Direction::Right),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// A single step in this direction.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(-1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_vec2() {
+        let (a, b) = (Vec2::new(1, -2), Vec2::new(4, 2));
+        assert_eq!(a + b, Vec2::new(5, 0));
+        assert_eq!(a - b, Vec2::new(-3, -4));
+        assert_eq!(-a * 3, Vec2::new(-3, 6));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Vec2::ZERO.manhattan(Vec2::ZERO), 0);
+
+        let mut pos = Vec2::ZERO;
+        pos += b;
+        pos -= a;
+        pos *= 2;
+        assert_eq!(pos, Vec2::new(6, 8));
+    }
+
+    #[test]
+    fn test_vec3() {
+        let (a, b) = (Vec3::new(1, 0, 7), Vec3::new(-2, 5, 3));
+        assert_eq!(a + b, Vec3::new(-1, 5, 10));
+        assert_eq!(a - b, Vec3::new(3, -5, 4));
+        assert_eq!(a.manhattan(b), 12);
+        assert_eq!(a.chebyshev(b), 5);
+    }
+
+    #[test]
+    fn test_direction() {
+        use Direction::*;
+
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.turn_right(), Up);
+        assert_eq!(Down.opposite(), Up);
+        assert_eq!(Right.opposite(), Left);
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.offset() + dir.opposite().offset(), Vec2::ZERO);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+        }
+        assert!(Up.is_vertical() && !Left.is_vertical());
+
+        // Steps along the rows of the input, the way day18's dig plan and day23's slopes go.
+        assert_eq!(Vec2::new(2, 2) + Up.offset() * 3, Vec2::new(2, -1));
+        assert_eq!(Vec2::new(2, 2) + Right.offset(), Vec2::new(3, 2));
+
+        let dirs: Vec<_> = "UDLR^v<>".chars().map(Direction::from_char).collect();
+        assert_eq!(dirs[..4], dirs[4..]);
+        assert_eq!(dirs[..4], [Some(Up), Some(Down), Some(Left), Some(Right)]);
+        assert_eq!(Direction::from_char('x'), None);
+    }
+}
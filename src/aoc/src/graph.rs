@@ -0,0 +1,519 @@
+//! Search algorithms for any graph that can list the neighbours of a node.
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+    marker::PhantomData,
+    mem,
+};
+
+/// A directed graph, given by the neighbours of each node and the weights of the edges to them.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = Self::Node>;
+
+    /// The weight of the edge from `from` to its neighbour `to`.
+    fn weight(&self, _from: &Self::Node, _to: &Self::Node) -> u64 {
+        1
+    }
+}
+
+/// An unweighted graph whose neighbours are computed by a function, created by [`from_fn`].
+pub struct FromFn<N, F> {
+    neighbours: F,
+    node: PhantomData<fn(&N)>,
+}
+
+pub fn from_fn<N, I, F>(neighbours: F) -> FromFn<N, F>
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    FromFn {
+        neighbours,
+        node: PhantomData,
+    }
+}
+
+impl<N, I, F> Graph for FromFn<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Node = N;
+
+    fn neighbours(&self, node: &N) -> impl Iterator<Item = N> {
+        (self.neighbours)(node).into_iter()
+    }
+}
+
+/// An unweighted graph stored as adjacency lists.
+impl<N: Clone + Eq + Hash> Graph for HashMap<N, Vec<N>> {
+    type Node = N;
+
+    fn neighbours(&self, node: &N) -> impl Iterator<Item = N> {
+        self.get(node).into_iter().flatten().cloned()
+    }
+}
+
+/// A path through a graph, from the start to the goal.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    /// The sum of the weights of the edges along the path.
+    pub cost: u64,
+}
+
+/// The cheapest path from `start` to a node for which `is_goal` returns true.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    a_star(graph, start, is_goal, |_| 0)
+}
+
+/// Like [`dijkstra`], but guided by a `heuristic` estimating the remaining cost to a goal. The
+/// heuristic must be consistent, that is it may never decrease by more than the weight of an edge.
+pub fn a_star<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    mut heuristic: impl FnMut(&G::Node) -> u64,
+) -> Option<Path<G::Node>> {
+    // Nodes are numbered in the order they are found, so the heap doesn't need to order them.
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![start];
+    let mut costs = vec![0];
+    let mut parents: Vec<Option<usize>> = vec![None];
+    let mut done = vec![false];
+
+    while let Some(Reverse((_, index))) = heap.pop() {
+        if mem::replace(&mut done[index], true) {
+            continue;
+        }
+
+        let node = nodes[index].clone();
+        if is_goal(&node) {
+            let mut path = vec![node];
+            let mut parent = parents[index];
+            while let Some(prev) = parent {
+                path.push(nodes[prev].clone());
+                parent = parents[prev];
+            }
+            path.reverse();
+            let cost = costs[index];
+            return Some(Path { nodes: path, cost });
+        }
+
+        for next in graph.neighbours(&node) {
+            let cost = costs[index] + graph.weight(&node, &next);
+            let next_index = *indices.entry(next.clone()).or_insert_with(|| {
+                nodes.push(next.clone());
+                costs.push(u64::MAX);
+                parents.push(None);
+                done.push(false);
+                nodes.len() - 1
+            });
+
+            if cost < costs[next_index] {
+                costs[next_index] = cost;
+                parents[next_index] = Some(index);
+                heap.push(Reverse((cost + heuristic(&next), next_index)));
+            }
+        }
+    }
+    None
+}
+
+/// Iterator over the nodes reachable from a start, grouped by their distance to it in steps.
+/// Created by [`bfs_layers`].
+pub struct BfsLayers<'a, G: Graph> {
+    graph: &'a G,
+    visited: HashSet<G::Node>,
+    layer: Vec<G::Node>,
+}
+
+pub fn bfs_layers<G: Graph>(graph: &G, start: G::Node) -> BfsLayers<'_, G> {
+    BfsLayers {
+        graph,
+        visited: HashSet::from([start.clone()]),
+        layer: vec![start],
+    }
+}
+
+impl<G: Graph> Iterator for BfsLayers<'_, G> {
+    type Item = Vec<G::Node>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.layer.is_empty() {
+            return None;
+        }
+
+        let mut next_layer = Vec::new();
+        for node in self.layer.iter() {
+            for next in self.graph.neighbours(node) {
+                if self.visited.insert(next.clone()) {
+                    next_layer.push(next);
+                }
+            }
+        }
+        Some(mem::replace(&mut self.layer, next_layer))
+    }
+}
+
+/// Iterator over the nodes reachable from a start in depth first order, created by [`dfs`].
+pub struct Dfs<'a, G: Graph> {
+    graph: &'a G,
+    visited: HashSet<G::Node>,
+    stack: Vec<G::Node>,
+}
+
+pub fn dfs<G: Graph>(graph: &G, start: G::Node) -> Dfs<'_, G> {
+    Dfs {
+        graph,
+        visited: HashSet::new(),
+        stack: vec![start],
+    }
+}
+
+impl<G: Graph> Iterator for Dfs<'_, G> {
+    type Item = G::Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            if self.visited.insert(node.clone()) {
+                let unvisited: Vec<_> = self
+                    .graph
+                    .neighbours(&node)
+                    .filter(|next| !self.visited.contains(next))
+                    .collect();
+                // Reversed, so the first neighbour is visited first.
+                self.stack.extend(unvisited.into_iter().rev());
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+/// A cut splitting a graph in two.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct MinCut<N> {
+    /// The sum of the weights of the edges between the two sides.
+    pub weight: u64,
+    /// The nodes on one side of the cut, the other side holds the remaining ones.
+    pub side: Vec<N>,
+}
+
+/// The minimum cut of the undirected graph made up of `nodes`, found with the Stoer-Wagner
+/// algorithm. Every edge has to be listed from both of its ends, with the same weight. Returns
+/// `None` if there are fewer than two nodes.
+pub fn stoer_wagner<G: Graph>(
+    graph: &G,
+    nodes: impl IntoIterator<Item = G::Node>,
+) -> Option<MinCut<G::Node>> {
+    let nodes: Vec<G::Node> = nodes.into_iter().collect();
+    if nodes.len() < 2 {
+        return None;
+    }
+
+    let indices: HashMap<&G::Node, usize> = nodes.iter().zip(0..).collect();
+    let mut adjacency: Vec<BTreeMap<usize, u64>> = vec![BTreeMap::new(); nodes.len()];
+    for (node, edges) in nodes.iter().zip(adjacency.iter_mut()) {
+        for next in graph.neighbours(node) {
+            match indices.get(&next) {
+                Some(&index) if index != indices[node] => {
+                    *edges.entry(index).or_insert(0) += graph.weight(node, &next)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // The original nodes that have been merged into each node.
+    let mut members: Vec<Vec<usize>> = (0..nodes.len()).map(|i| vec![i]).collect();
+    let mut active = vec![true; nodes.len()];
+    let mut best: Option<(u64, Vec<usize>)> = None;
+
+    for _ in 1..nodes.len() {
+        let (prev, last, cut) = min_cut_phase(&adjacency, &active);
+        if best.as_ref().is_none_or(|(weight, _)| cut < *weight) {
+            best = Some((cut, members[last].clone()));
+        }
+
+        for (next, weight) in mem::take(&mut adjacency[last]) {
+            adjacency[next].remove(&last);
+            if next != prev {
+                *adjacency[next].entry(prev).or_insert(0) += weight;
+                *adjacency[prev].entry(next).or_insert(0) += weight;
+            }
+        }
+        let merged = mem::take(&mut members[last]);
+        members[prev].extend(merged);
+        active[last] = false;
+    }
+
+    best.map(|(weight, side)| MinCut {
+        weight,
+        side: side.into_iter().map(|i| nodes[i].clone()).collect(),
+    })
+}
+
+/// Adds the active nodes one by one, always the one most tightly connected to those added before.
+/// Returns the last two nodes and the weight of the edges of the last one.
+fn min_cut_phase(adjacency: &[BTreeMap<usize, u64>], active: &[bool]) -> (usize, usize, u64) {
+    let mut weights = vec![0; adjacency.len()];
+    let mut visited = vec![false; adjacency.len()];
+    let mut unvisited = (0..adjacency.len()).filter(|&i| active[i]);
+    let mut heap = BinaryHeap::new();
+    let (mut prev, mut last, mut cut) = (0, 0, 0);
+
+    loop {
+        // A disconnected node is as loosely connected as a node can get.
+        let (weight, node) = match heap.pop() {
+            Some(entry) => entry,
+            None => match unvisited.find(|&i| !visited[i]) {
+                Some(node) => (0, node),
+                None => break,
+            },
+        };
+        if mem::replace(&mut visited[node], true) {
+            continue;
+        }
+        (prev, last, cut) = (last, node, weight);
+
+        for (&next, &weight) in adjacency[node].iter() {
+            if !visited[next] {
+                weights[next] += weight;
+                heap.push((weights[next], next));
+            }
+        }
+    }
+    (prev, last, cut)
+}
+
+/// The strongly connected components of the graph made up of `nodes`, found with Tarjan's
+/// algorithm. A component comes before every component that has an edge into it.
+pub fn tarjan_scc<G: Graph>(
+    graph: &G,
+    nodes: impl IntoIterator<Item = G::Node>,
+) -> Vec<Vec<G::Node>> {
+    struct State {
+        index: usize,
+        lowlink: usize,
+        on_stack: bool,
+    }
+
+    let mut states: HashMap<G::Node, State> = HashMap::new();
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+
+    for root in nodes {
+        if states.contains_key(&root) {
+            continue;
+        }
+
+        // The recursion of the textbook version, as the nodes being visited with their neighbours
+        // and how many of those have been looked at.
+        let mut calls: Vec<(G::Node, Vec<G::Node>, usize)> = Vec::new();
+        let mut next = Some(root);
+
+        loop {
+            if let Some(node) = next.take() {
+                let index = states.len();
+                let state = State {
+                    index,
+                    lowlink: index,
+                    on_stack: true,
+                };
+                states.insert(node.clone(), state);
+                stack.push(node.clone());
+                calls.push((node.clone(), graph.neighbours(&node).collect(), 0));
+            }
+
+            let Some((node, neighbours, seen)) = calls.last_mut() else {
+                break;
+            };
+            if let Some(neighbour) = neighbours.get(*seen) {
+                *seen += 1;
+                match states.get(neighbour) {
+                    None => next = Some(neighbour.clone()),
+                    Some(state) if state.on_stack => {
+                        let index = state.index;
+                        let state = states.get_mut(node).unwrap();
+                        state.lowlink = state.lowlink.min(index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            let (node, _, _) = calls.pop().unwrap();
+            let (index, lowlink) = (states[&node].index, states[&node].lowlink);
+            if let Some((parent, _, _)) = calls.last() {
+                let parent = states.get_mut(parent).unwrap();
+                parent.lowlink = parent.lowlink.min(lowlink);
+            }
+
+            if lowlink == index {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    states.get_mut(&member).unwrap().on_stack = false;
+                    let is_root = member == node;
+                    component.push(member);
+                    if is_root {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A grid of open and blocked tiles, stepping to the open neighbours.
+    fn maze<'a>(rows: &'a [&'a str]) -> impl Graph<Node = (usize, usize)> + 'a {
+        from_fn(move |&(x, y): &(usize, usize)| {
+            let open = |x: usize, y: usize| rows.get(y)?.as_bytes().get(x).filter(|&&b| b == b'.');
+            let mut next = Vec::new();
+            for (nx, ny) in [
+                (x + 1, y),
+                (x, y + 1),
+                (x.wrapping_sub(1), y),
+                (x, y.wrapping_sub(1)),
+            ] {
+                if open(nx, ny).is_some() {
+                    next.push((nx, ny));
+                }
+            }
+            next
+        })
+    }
+
+    /// Edges with a weight, listed from both ends.
+    struct Weighted(HashMap<char, Vec<(char, u64)>>);
+
+    impl Weighted {
+        fn new(edges: &[(char, char, u64)]) -> Self {
+            let mut adjacency: HashMap<char, Vec<(char, u64)>> = HashMap::new();
+            for &(a, b, weight) in edges {
+                adjacency.entry(a).or_default().push((b, weight));
+                adjacency.entry(b).or_default().push((a, weight));
+            }
+            Self(adjacency)
+        }
+    }
+
+    impl Graph for Weighted {
+        type Node = char;
+
+        fn neighbours(&self, node: &char) -> impl Iterator<Item = char> {
+            self.0[node].iter().map(|&(next, _)| next)
+        }
+
+        fn weight(&self, from: &char, to: &char) -> u64 {
+            self.0[from].iter().find(|(next, _)| next == to).unwrap().1
+        }
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let graph = Weighted::new(&[
+            ('a', 'b', 7),
+            ('a', 'c', 9),
+            ('a', 'f', 14),
+            ('b', 'c', 10),
+            ('b', 'd', 15),
+            ('c', 'd', 11),
+            ('c', 'f', 2),
+            ('d', 'e', 6),
+            ('e', 'f', 9),
+        ]);
+        let path = dijkstra(&graph, 'a', |&node| node == 'e').unwrap();
+        assert_eq!(path.nodes, ['a', 'c', 'f', 'e']);
+        assert_eq!(path.cost, 20);
+        assert_eq!(dijkstra(&graph, 'a', |&node| node == 'x'), None);
+
+        let rows = ["..#....", ".##.##.", "....#..", "##.##.#", "......."];
+        let goal = (6, 4);
+        let distance = |(x, y): (usize, usize)| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as u64;
+        let path = a_star(
+            &maze(&rows),
+            (0, 0),
+            |&pos| pos == goal,
+            |&pos| distance(pos),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.nodes.len(), 11);
+        assert_eq!((path.nodes[0], path.nodes[10]), ((0, 0), goal));
+        assert_eq!(
+            dijkstra(&maze(&rows), (0, 0), |&pos| pos == goal),
+            Some(path)
+        );
+    }
+
+    #[test]
+    fn test_traversal() {
+        let rows = ["...#.", ".#...", "...#."];
+        let layers: Vec<usize> = bfs_layers(&maze(&rows), (0, 0)).map(|l| l.len()).collect();
+        assert_eq!(layers, [1, 2, 2, 2, 2, 1, 2]);
+
+        let graph = HashMap::from([(1, vec![2, 3]), (2, vec![4]), (3, vec![4]), (4, vec![1])]);
+        assert_eq!(dfs(&graph, 1).collect::<Vec<_>>(), [1, 2, 4, 3]);
+        assert_eq!(dfs(&graph, 5).collect::<Vec<_>>(), [5]);
+        assert_eq!(
+            bfs_layers(&graph, 1).collect::<Vec<_>>(),
+            [vec![1], vec![2, 3], vec![4]]
+        );
+    }
+
+    #[test]
+    fn test_min_cut() {
+        // Two triangles joined by a single light edge.
+        let graph = Weighted::new(&[
+            ('a', 'b', 3),
+            ('b', 'c', 3),
+            ('c', 'a', 3),
+            ('c', 'd', 1),
+            ('d', 'e', 3),
+            ('e', 'f', 3),
+            ('f', 'd', 3),
+        ]);
+        let mut cut = stoer_wagner(&graph, "abcdef".chars()).unwrap();
+        cut.side.sort();
+        assert_eq!(cut.weight, 1);
+        assert!(cut.side == ['a', 'b', 'c'] || cut.side == ['d', 'e', 'f']);
+
+        let graph = HashMap::from([(1, vec![2]), (2, vec![1]), (3, vec![])]);
+        let cut = stoer_wagner(&graph, [1, 2, 3]).unwrap();
+        assert_eq!(cut.weight, 0);
+        assert_eq!(stoer_wagner(&graph, [1]), None);
+    }
+
+    #[test]
+    fn test_tarjan_scc() {
+        let graph = HashMap::from([
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![1, 4]),
+            (4, vec![5]),
+            (5, vec![4, 6]),
+            (6, vec![]),
+        ]);
+        let mut components = tarjan_scc(&graph, 1..=6);
+        components.iter_mut().for_each(|component| component.sort());
+        assert_eq!(components, [vec![6], vec![4, 5], vec![1, 2, 3]]);
+        assert_eq!(tarjan_scc(&graph, []), Vec::<Vec<i32>>::new());
+    }
+}
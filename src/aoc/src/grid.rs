@@ -0,0 +1,356 @@
+//! A rectangular grid of tiles, as used by most of the map puzzles.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    parse::{self, Line},
+    Direction, ParseError,
+};
+
+/// A position in a grid, as `(x, y)` with the origin in the upper left corner.
+pub type Pos = (usize, usize);
+
+/// A grid stored row by row in a single `Vec`.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Parses a grid where every tile is one of `tiles`.
+    pub fn parse(input: &str, tiles: &str) -> Result<Self, ParseError> {
+        Self::from_lines(&parse::grid_lines(input, tiles)?)
+    }
+
+    /// A grid with a row for each line, which must all have the same length.
+    pub fn from_lines(lines: &[Line]) -> Result<Self, ParseError> {
+        parse::check_rectangular(lines)?;
+        let width = lines.first().map_or(0, |line| line.text.chars().count());
+        let cells = lines.iter().flat_map(|line| line.text.chars()).collect();
+        Ok(Self::new(width, lines.len(), cells))
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid from its cells in row major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Unexpected number of cells");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The position `(x, y)` if it lies inside the grid, for any kind of integer coordinates.
+    pub fn checked_pos<I: TryInto<usize>>(&self, x: I, y: I) -> Option<Pos> {
+        let (x, y) = (x.try_into().ok()?, y.try_into().ok()?);
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// The tile at `(x, y)` in the plane tiled with copies of the grid.
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        &self[(x, y)]
+    }
+
+    pub fn swap(&mut self, a: Pos, b: Pos) {
+        let (a, b) = (self.offset(a), self.offset(b));
+        self.cells.swap(a, b);
+    }
+
+    /// The first position, in row major order, whose tile matches `pred`.
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let offset = self.cells.iter().position(&mut pred)?;
+        Some((offset % self.width, offset / self.width))
+    }
+
+    /// Every position of the grid, in row major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every tile together with its position, in row major order.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The up to 4 positions above, to the right, below and to the left of `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.neighbour(pos, dir))
+    }
+
+    /// The position next to `pos` in direction `dir`, if it lies inside the grid.
+    pub fn neighbour(&self, pos: Pos, dir: Direction) -> Option<Pos> {
+        let offset = dir.offset();
+        self.step(pos, offset.x as isize, offset.y as isize)
+    }
+
+    /// The up to 8 positions around `pos`, including the diagonal ones.
+    pub fn neighbours8(&self, (x, y): Pos) -> impl Iterator<Item = Pos> + '_ {
+        [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ]
+        .into_iter()
+        .filter_map(move |(dx, dy)| self.step((x, y), dx, dy))
+    }
+
+    /// The position `(dx, dy)` away from `pos`, if it lies inside the grid.
+    pub fn step(&self, (x, y): Pos, dx: isize, dy: isize) -> Option<Pos> {
+        self.checked_pos(x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` panics on a size of 0, which an empty grid would have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// The grid rotated a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        Grid::from_fn(height, self.width, |(x, y)| {
+            self[(y, height - 1 - x)].clone()
+        })
+    }
+
+    /// The grid rotated a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        Grid::from_fn(self.height, width, |(x, y)| {
+            self[(width - 1 - y, x)].clone()
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        Grid::from_fn(width, self.height, |(x, y)| {
+            self[(width - 1 - x, y)].clone()
+        })
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        Grid::from_fn(self.width, height, |(x, y)| {
+            self[(x, height - 1 - y)].clone()
+        })
+    }
+
+    fn offset(&self, (x, y): Pos) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "Position ({x}, {y}) is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+        y * self.width + x
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        &self.cells[self.offset(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let offset = self.offset(pos);
+        &mut self.cells[offset]
+    }
+}
+
+/// Writes the grid back as text, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{tile}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse(input, "abcdef.#").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid(
+            "
+            abc
+            def
+        ",
+        );
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert_eq!(grid.position(|&ch| ch == 'e'), Some((1, 1)));
+
+        assert_eq!(
+            Grid::parse("ab\nabc", "abc"),
+            Err(ParseError::new(2, 3, "a row of 2 tiles", "3 tiles"))
+        );
+    }
+
+    #[test]
+    fn test_indexing() {
+        let grid = grid("abc\ndef");
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.checked_pos(-1, 0), None);
+        assert_eq!(grid.checked_pos(2_i32, 1), Some((2, 1)));
+        assert_eq!(grid.checked_pos(1_i64, 2), None);
+        assert_eq!(*grid.get_wrapping(-1, 3), 'f');
+        assert_eq!(*grid.get_wrapping(3, -2), 'a');
+
+        let mut grid = grid;
+        grid.swap((0, 0), (2, 1));
+        *grid.get_mut((1, 0)).unwrap() = '#';
+        assert_eq!(grid.to_string(), "f#c\ndea\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::filled(3, 3, '.');
+        let neighbours: Vec<Pos> = grid.neighbours4((1, 1)).collect();
+        assert_eq!(neighbours, [(1, 0), (2, 1), (1, 2), (0, 1)]);
+        assert_eq!(grid.neighbours4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbour((0, 2), Direction::Right), Some((1, 2)));
+        assert_eq!(grid.neighbour((0, 2), Direction::Down), None);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 0)).count(), 3);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = grid("abc\ndef");
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        let columns: Vec<String> = grid.columns().map(|col| col.rev().collect()).collect();
+        assert_eq!(columns, ["da", "eb", "fc"]);
+        assert_eq!(grid.map(|&ch| ch == 'e').iter().filter(|&&e| e).count(), 1);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid("abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(
+            grid.flip_horizontal().flip_vertical(),
+            grid.rotate_cw().rotate_cw()
+        );
+    }
+}
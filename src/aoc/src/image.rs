@@ -0,0 +1,275 @@
+//! Turning frames into images, to keep what a solution does as a PNG still or an animated GIF.
+//!
+//! Each cell of a frame becomes a square of pixels filled with its background colour, with a
+//! smaller square in its foreground colour on top unless the cell is blank. The captions aren't
+//! drawn, since that would need a font.
+
+mod gif;
+mod png;
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    iter,
+    path::Path,
+    time::Duration,
+};
+
+use crate::render::{Color, Frame};
+
+pub type Rgb = [u8; 3];
+
+/// The colours the terminal colours of a frame are drawn with.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Palette {
+    /// The colour of cells without a background colour.
+    pub background: Rgb,
+    /// The colour of cells without a foreground colour.
+    pub foreground: Rgb,
+    colors: Vec<(Color, Rgb)>,
+}
+
+impl Palette {
+    pub fn rgb(&self, color: Color) -> Rgb {
+        match color {
+            Color::Rgb(r, g, b) => [r, g, b],
+            color => self
+                .colors
+                .iter()
+                .find_map(|&(key, rgb)| (key == color).then_some(rgb))
+                .unwrap_or(self.foreground),
+        }
+    }
+
+    /// This palette, drawing `color` as `rgb`.
+    pub fn with(mut self, color: Color, rgb: Rgb) -> Self {
+        self.colors.retain(|&(key, _)| key != color);
+        self.colors.push((color, rgb));
+        self
+    }
+}
+
+/// Colours close to those of a dark terminal theme.
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            background: [24, 24, 32],
+            foreground: [208, 208, 208],
+            colors: vec![
+                (Color::Black, [0, 0, 0]),
+                (Color::Red, [220, 50, 47]),
+                (Color::Green, [80, 200, 60]),
+                (Color::Yellow, [240, 200, 40]),
+                (Color::Blue, [50, 110, 230]),
+                (Color::Magenta, [200, 60, 200]),
+                (Color::Cyan, [40, 190, 200]),
+                (Color::White, [255, 255, 255]),
+                (Color::Gray, [100, 100, 110]),
+            ],
+        }
+    }
+}
+
+/// An image stored row by row.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn filled(width: usize, height: usize, color: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![color; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Rgb]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+
+    fn fill(&mut self, x: usize, y: usize, size: usize, color: Rgb) {
+        for row in y..y + size {
+            self.pixels[row * self.width + x..row * self.width + x + size].fill(color);
+        }
+    }
+}
+
+/// How frames are turned into images.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Options {
+    pub palette: Palette,
+    /// The width and height of a cell in pixels.
+    pub cell_size: usize,
+    /// The frame rate of an animation.
+    pub fps: f64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            palette: Palette::default(),
+            cell_size: 4,
+            fps: 10.0,
+        }
+    }
+}
+
+/// Draws `frame` with `cell_size` by `cell_size` pixels for each cell.
+pub fn rasterize(frame: &Frame, palette: &Palette, cell_size: usize) -> Image {
+    assert!(cell_size > 0, "Expected a cell size of at least 1 pixel");
+    let cells = frame.cells();
+    let mut image = Image::filled(
+        cells.width() * cell_size,
+        cells.height() * cell_size,
+        palette.background,
+    );
+
+    let inset = cell_size / 4;
+    for ((x, y), cell) in cells.enumerate() {
+        let (x, y) = (x * cell_size, y * cell_size);
+        let background = cell
+            .style
+            .bg
+            .map_or(palette.background, |bg| palette.rgb(bg));
+        image.fill(x, y, cell_size, background);
+
+        if !matches!(cell.ch, ' ' | '.') {
+            let foreground = cell
+                .style
+                .fg
+                .map_or(palette.foreground, |fg| palette.rgb(fg));
+            image.fill(x + inset, y + inset, cell_size - 2 * inset, foreground);
+        }
+    }
+    image
+}
+
+pub fn encode_png(image: &Image) -> Vec<u8> {
+    png::encode(image)
+}
+
+/// Writes the frames to `out` as a GIF that loops forever.
+pub fn write_gif(
+    frames: impl IntoIterator<Item = Frame>,
+    options: &Options,
+    out: impl Write,
+) -> io::Result<()> {
+    let delay = Duration::from_secs_f64(1.0 / options.fps);
+    let mut images = frames
+        .into_iter()
+        .map(|frame| rasterize(&frame, &options.palette, options.cell_size));
+    let first = images.next().ok_or_else(no_frames)?;
+
+    let mut encoder = gif::Encoder::new(out, first.width, first.height, delay)?;
+    for image in iter::once(first).chain(images) {
+        encoder.add_frame(&image)?;
+    }
+    encoder.finish().map(drop)
+}
+
+fn no_frames() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "there are no frames to save")
+}
+
+/// Saves the frames at `path`, as an animation if it ends with `.gif` or as a still of the last
+/// frame if it ends with `.png`.
+pub fn save(
+    frames: impl IntoIterator<Item = Frame>,
+    path: &Path,
+    options: &Options,
+) -> io::Result<()> {
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    match extension.map(str::to_ascii_lowercase).as_deref() {
+        Some("gif") => {
+            let mut out = BufWriter::new(File::create(path)?);
+            write_gif(frames, options, &mut out)?;
+            out.flush()
+        }
+        Some("png") => {
+            let frame = frames.into_iter().last().ok_or_else(no_frames)?;
+            let image = rasterize(&frame, &options.palette, options.cell_size);
+            std::fs::write(path, encode_png(&image))
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "can't tell the format of '{}', expected a .gif or .png file",
+                path.display()
+            ),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        render::{Cell, Style},
+        Grid,
+    };
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::default().with(Color::Red, [1, 2, 3]);
+        assert_eq!(palette.rgb(Color::Red), [1, 2, 3]);
+        assert_eq!(palette.rgb(Color::Rgb(4, 5, 6)), [4, 5, 6]);
+        assert_eq!(palette.rgb(Color::Gray), [100, 100, 110]);
+    }
+
+    #[test]
+    fn test_rasterize() {
+        let grid = Grid::parse("#.\n..", "#.").unwrap();
+        let mut frame = Frame::plain(&grid);
+        frame.highlight([(1, 1)], Style::bg(Color::Red));
+        frame.mark([(1, 0)], 'O', Style::fg(Color::Rgb(9, 9, 9)));
+
+        let palette = Palette::default();
+        let image = rasterize(&frame, &palette, 4);
+        assert_eq!((image.width(), image.height()), (8, 8));
+        // The border of a cell keeps the background, the middle holds the character.
+        assert_eq!(image.pixel(0, 0), palette.background);
+        assert_eq!(image.pixel(1, 2), palette.foreground);
+        assert_eq!(image.pixel(5, 1), [9, 9, 9]);
+        assert_eq!(image.pixel(5, 5), palette.rgb(Color::Red));
+        assert_eq!(image.pixel(1, 5), palette.background);
+
+        let image = rasterize(&Frame::new(&grid, |_| Cell::plain('x')), &palette, 1);
+        assert!(image.rows().flatten().all(|&rgb| rgb == palette.foreground));
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = std::env::temp_dir().join(format!("aoc-image-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let frames = || vec![Frame::plain(&Grid::parse("#.\n.#", "#.").unwrap())];
+        let options = Options::default();
+
+        save(frames(), &dir.join("still.png"), &options).unwrap();
+        let png = std::fs::read(dir.join("still.png")).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+        save(frames(), &dir.join("anim.GIF"), &options).unwrap();
+        let gif = std::fs::read(dir.join("anim.GIF")).unwrap();
+        assert!(gif.starts_with(b"GIF89a") && gif.ends_with(b";"));
+
+        assert!(save(frames(), &dir.join("anim.bmp"), &options).is_err());
+        assert!(save(Vec::new(), &dir.join("empty.gif"), &options).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,291 @@
+//! An animated GIF encoder, giving every frame its own colour table.
+
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    time::Duration,
+};
+
+use super::{Image, Rgb};
+
+const MAX_CODE_SIZE: u32 = 12;
+
+pub struct Encoder<W: Write> {
+    out: W,
+    width: usize,
+    height: usize,
+    /// The time each frame is shown, in hundredths of a second.
+    delay: u16,
+}
+
+impl<W: Write> Encoder<W> {
+    /// Writes the header of an animation of `width` by `height` pixels that loops forever.
+    pub fn new(mut out: W, width: usize, height: usize, delay: Duration) -> io::Result<Self> {
+        let size = |len: usize| {
+            u16::try_from(len).map_err(|_| {
+                let msg = format!("an image of {width}x{height} pixels is too large for a GIF");
+                io::Error::new(io::ErrorKind::InvalidInput, msg)
+            })
+        };
+        let (width_u16, height_u16) = (size(width)?, size(height)?);
+
+        out.write_all(b"GIF89a")?;
+        out.write_all(&width_u16.to_le_bytes())?;
+        out.write_all(&height_u16.to_le_bytes())?;
+        // No global colour table, background colour 0 and square pixels.
+        out.write_all(&[0, 0, 0])?;
+        // The application extension that makes the animation loop forever.
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+        let delay = (delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+        Ok(Self {
+            out,
+            width,
+            height,
+            delay,
+        })
+    }
+
+    pub fn add_frame(&mut self, image: &Image) -> io::Result<()> {
+        assert_eq!(
+            (image.width, image.height),
+            (self.width, self.height),
+            "Expected every frame to have the same size"
+        );
+        let (colors, indices) = index_colors(image);
+        // The colour table holds a power of two colours, at least 2.
+        let table_bits = (colors.len().max(2) - 1).ilog2() + 1;
+
+        // The graphic control extension with the delay of this frame.
+        self.out.write_all(&[0x21, 0xf9, 0x04, 0x00])?;
+        self.out.write_all(&self.delay.to_le_bytes())?;
+        self.out.write_all(&[0x00, 0x00])?;
+
+        // The image descriptor, covering the whole screen and with a local colour table.
+        self.out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        self.out.write_all(&(self.width as u16).to_le_bytes())?;
+        self.out.write_all(&(self.height as u16).to_le_bytes())?;
+        self.out.write_all(&[0x80 | (table_bits - 1) as u8])?;
+        for i in 0..1 << table_bits {
+            self.out
+                .write_all(&colors.get(i).copied().unwrap_or_default())?;
+        }
+
+        let min_code_size = table_bits.max(2);
+        self.out.write_all(&[min_code_size as u8])?;
+        // The compressed data, in blocks of up to 255 bytes that end with an empty one.
+        for block in lzw(&indices, min_code_size).chunks(255) {
+            self.out.write_all(&[block.len() as u8])?;
+            self.out.write_all(block)?;
+        }
+        self.out.write_all(&[0])
+    }
+
+    /// Writes the trailer, returning the writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(b";")?;
+        Ok(self.out)
+    }
+}
+
+/// The colours of `image` and the index of the colour of each pixel. An image with more than 256
+/// colours is reduced to 3 bits of red and green and 2 bits of blue.
+fn index_colors(image: &Image) -> (Vec<Rgb>, Vec<u8>) {
+    let mut colors = Vec::new();
+    let mut index: HashMap<Rgb, u8> = HashMap::new();
+    let mut indices = Vec::with_capacity(image.pixels.len());
+    for &pixel in image.pixels.iter() {
+        let next = colors.len();
+        if next == 256 && !index.contains_key(&pixel) {
+            return reduce_colors(image);
+        }
+        indices.push(*index.entry(pixel).or_insert_with(|| {
+            colors.push(pixel);
+            next as u8
+        }));
+    }
+    (colors, indices)
+}
+
+fn reduce_colors(image: &Image) -> (Vec<Rgb>, Vec<u8>) {
+    let colors = (0..=255_u8)
+        .map(|i| {
+            let scale = |bits: u8, max: u8| (bits as u16 * 255 / max as u16) as u8;
+            [scale(i >> 5, 7), scale((i >> 2) & 7, 7), scale(i & 3, 3)]
+        })
+        .collect();
+    let indices = image
+        .pixels
+        .iter()
+        .map(|&[r, g, b]| (r & 0xe0) | ((g & 0xe0) >> 3) | (b >> 6))
+        .collect();
+    (colors, indices)
+}
+
+/// Writes codes of a growing number of bits, starting at the least significant bit of each byte.
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: u32,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, len: u32) {
+        self.bits |= (code as u32) << self.len;
+        self.len += len;
+        while self.len >= 8 {
+            self.bytes.push(self.bits as u8);
+            self.bits >>= 8;
+            self.len -= 8;
+        }
+    }
+}
+
+/// Compresses the colour indices with the variable length LZW codes of GIF.
+fn lzw(indices: &[u8], min_code_size: u32) -> Vec<u8> {
+    let clear = 1_u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut out = BitWriter {
+        bytes: Vec::new(),
+        bits: 0,
+        len: 0,
+    };
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next_code = end + 1;
+    let mut code_size = min_code_size + 1;
+    out.write(clear, code_size);
+
+    let mut indices = indices.iter();
+    let Some(&first) = indices.next() else {
+        out.write(end, code_size);
+        return finish(out);
+    };
+
+    let mut prefix = first as u16;
+    for &index in indices {
+        if let Some(&code) = table.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+
+        out.write(prefix, code_size);
+        // The decoder adds its entries one code later, and widens its codes once the table
+        // reaches the next power of two.
+        if next_code == 1 << code_size && code_size < MAX_CODE_SIZE {
+            code_size += 1;
+        }
+        if next_code < 1 << MAX_CODE_SIZE {
+            table.insert((prefix, index), next_code);
+            next_code += 1;
+        } else {
+            out.write(clear, code_size);
+            table.clear();
+            next_code = end + 1;
+            code_size = min_code_size + 1;
+        }
+        prefix = index as u16;
+    }
+
+    out.write(prefix, code_size);
+    if next_code == 1 << code_size && code_size < MAX_CODE_SIZE {
+        code_size += 1;
+    }
+    out.write(end, code_size);
+    finish(out)
+}
+
+fn finish(mut out: BitWriter) -> Vec<u8> {
+    if out.len > 0 {
+        out.bytes.push(out.bits as u8);
+    }
+    out.bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Decodes the LZW codes the way a GIF decoder does.
+    fn unlzw(data: &[u8], min_code_size: u32) -> Vec<u8> {
+        let clear = 1_usize << min_code_size;
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut code_size = min_code_size + 1;
+        let mut prev: Option<Vec<u8>> = None;
+        let mut out = Vec::new();
+
+        let (mut bits, mut len, mut bytes) = (0_u32, 0, data.iter());
+        loop {
+            while len < code_size {
+                bits |= (*bytes.next().unwrap() as u32) << len;
+                len += 8;
+            }
+            let code = (bits & ((1 << code_size) - 1)) as usize;
+            bits >>= code_size;
+            len -= code_size;
+
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([Vec::new(), Vec::new()]);
+                code_size = min_code_size + 1;
+                prev = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+
+            let entry = match (table.get(code), &prev) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(prev)) => [prev.as_slice(), &prev[..1]].concat(),
+                (None, None) => panic!("Unknown code {code}"),
+            };
+            if let Some(prev) = prev {
+                if table.len() < 4096 {
+                    table.push([prev.as_slice(), &entry[..1]].concat());
+                }
+            }
+            if table.len() == 1 << code_size && code_size < 12 {
+                code_size += 1;
+            }
+            out.extend(&entry);
+            prev = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw() {
+        let patterns: [Vec<u8>; 4] = [
+            vec![],
+            vec![1, 1, 1, 1, 1, 0, 1, 0, 1, 0],
+            (0..200).map(|i| (i % 7) as u8).collect(),
+            // Enough pseudo random data to fill the table and start over.
+            (0..20_000_u32)
+                .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+                .collect(),
+        ];
+        for indices in patterns {
+            let min_code_size = if indices.iter().any(|&i| i > 3) { 8 } else { 2 };
+            let data = lzw(&indices, min_code_size);
+            assert_eq!(unlzw(&data, min_code_size), indices);
+        }
+    }
+
+    #[test]
+    fn test_index_colors() {
+        let mut image = Image::filled(2, 1, [1, 2, 3]);
+        image.pixels[1] = [4, 5, 6];
+        assert_eq!(
+            index_colors(&image),
+            (vec![[1, 2, 3], [4, 5, 6]], vec![0, 1])
+        );
+
+        let mut image = Image::filled(300, 1, [0, 0, 0]);
+        for (i, pixel) in image.pixels.iter_mut().enumerate() {
+            *pixel = [i as u8, (i / 256) as u8 * 255, 255];
+        }
+        let (colors, indices) = index_colors(&image);
+        assert_eq!(colors.len(), 256);
+        assert_eq!(colors[indices[299] as usize], [36, 255, 255]);
+    }
+}
@@ -0,0 +1,303 @@
+//! A PNG encoder, compressing with LZ77 and the fixed Huffman codes of deflate.
+
+use super::Image;
+
+const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+pub fn encode(image: &Image) -> Vec<u8> {
+    let mut png = SIGNATURE.to_vec();
+
+    let mut header = Vec::new();
+    header.extend((image.width as u32).to_be_bytes());
+    header.extend((image.height as u32).to_be_bytes());
+    // 8 bits per channel, RGB, deflate, adaptive filtering, no interlacing.
+    header.extend([8, 2, 0, 0, 0]);
+    write_chunk(&mut png, b"IHDR", &header);
+
+    // Every row starts with its filter type, which is always 0 for none.
+    let mut data = Vec::with_capacity(image.height * (image.width * 3 + 1));
+    for row in image.rows().take(image.height) {
+        data.push(0);
+        data.extend(row.iter().flatten());
+    }
+    write_chunk(&mut png, b"IDAT", &zlib(&data));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        (a, b) = (a % 65521, b % 65521);
+    }
+    (b << 16) | a
+}
+
+/// `data` as a zlib stream.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window and the default compression level, whose header is divisible by 31.
+    let mut out = vec![0x78, 0x9c];
+    out.extend(deflate(data));
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+/// The lengths of matches starting at each length code from 257, and their number of extra bits.
+const LENGTHS: [(u16, u8); 29] = [
+    (3, 0),
+    (4, 0),
+    (5, 0),
+    (6, 0),
+    (7, 0),
+    (8, 0),
+    (9, 0),
+    (10, 0),
+    (11, 1),
+    (13, 1),
+    (15, 1),
+    (17, 1),
+    (19, 2),
+    (23, 2),
+    (27, 2),
+    (31, 2),
+    (35, 3),
+    (43, 3),
+    (51, 3),
+    (59, 3),
+    (67, 4),
+    (83, 4),
+    (99, 4),
+    (115, 4),
+    (131, 5),
+    (163, 5),
+    (195, 5),
+    (227, 5),
+    (258, 0),
+];
+
+/// The distances starting at each distance code, and their number of extra bits.
+const DISTANCES: [(u16, u8); 30] = [
+    (1, 0),
+    (2, 0),
+    (3, 0),
+    (4, 0),
+    (5, 1),
+    (7, 1),
+    (9, 2),
+    (13, 2),
+    (17, 3),
+    (25, 3),
+    (33, 4),
+    (49, 4),
+    (65, 5),
+    (97, 5),
+    (129, 6),
+    (193, 6),
+    (257, 7),
+    (385, 7),
+    (513, 8),
+    (769, 8),
+    (1025, 9),
+    (1537, 9),
+    (2049, 10),
+    (3073, 10),
+    (4097, 11),
+    (6145, 11),
+    (8193, 12),
+    (12289, 12),
+    (16385, 13),
+    (24577, 13),
+];
+
+const WINDOW: usize = 1 << 15;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+/// How many earlier positions with the same hash are tried when looking for a match.
+const MAX_CHAIN: usize = 64;
+
+/// Writes bits starting at the least significant bit of each byte, as deflate does.
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: u32,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, len: u32) {
+        self.bits |= value << self.len;
+        self.len += len;
+        while self.len >= 8 {
+            self.bytes.push(self.bits as u8);
+            self.bits >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    /// Writes a Huffman code, which is stored starting at its most significant bit.
+    fn write_code(&mut self, code: u32, len: u32) {
+        self.write(code.reverse_bits() >> (32 - len), len);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.bits as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Writes a literal byte or length code with the fixed Huffman codes.
+fn write_symbol(out: &mut BitWriter, symbol: u32) {
+    match symbol {
+        0..=143 => out.write_code(0x30 + symbol, 8),
+        144..=255 => out.write_code(0x190 + symbol - 144, 9),
+        256..=279 => out.write_code(symbol - 256, 7),
+        _ => out.write_code(0xc0 + symbol - 280, 8),
+    }
+}
+
+fn write_match(out: &mut BitWriter, len: usize, dist: usize) {
+    let code = LENGTHS.partition_point(|&(base, _)| base as usize <= len) - 1;
+    let (base, extra) = LENGTHS[code];
+    write_symbol(out, 257 + code as u32);
+    out.write(len as u32 - base as u32, extra as u32);
+
+    let code = DISTANCES.partition_point(|&(base, _)| base as usize <= dist) - 1;
+    let (base, extra) = DISTANCES[code];
+    out.write_code(code as u32, 5);
+    out.write(dist as u32 - base as u32, extra as u32);
+}
+
+/// The earlier positions of the strings of `MIN_MATCH` bytes, as linked lists by their hash.
+struct Chains {
+    /// The last position with each hash.
+    head: Vec<usize>,
+    /// The position before each one with the same hash.
+    prev: Vec<usize>,
+}
+
+impl Chains {
+    fn insert(&mut self, data: &[u8], pos: usize) {
+        if pos + MIN_MATCH <= data.len() {
+            let key = hash(data, pos);
+            self.prev[pos] = self.head[key];
+            self.head[key] = pos;
+        }
+    }
+}
+
+fn hash(data: &[u8], pos: usize) -> usize {
+    let key = u32::from_le_bytes([data[pos], data[pos + 1], data[pos + 2], 0]);
+    (key.wrapping_mul(2_654_435_761) >> 17) as usize
+}
+
+/// Compresses `data` into a single deflate block with the fixed Huffman codes, replacing
+/// repeated strings by references to their last occurrence.
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut out = BitWriter {
+        bytes: Vec::new(),
+        bits: 0,
+        len: 0,
+    };
+    // The final block, compressed with the fixed codes.
+    out.write(1, 1);
+    out.write(1, 2);
+
+    let mut chains = Chains {
+        head: vec![usize::MAX; 1 << 15],
+        prev: vec![usize::MAX; data.len()],
+    };
+
+    let mut pos = 0;
+    while pos < data.len() {
+        let mut best = (0, 0);
+        if pos + MIN_MATCH <= data.len() {
+            let max_len = MAX_MATCH.min(data.len() - pos);
+            let mut candidate = chains.head[hash(data, pos)];
+            for _ in 0..MAX_CHAIN {
+                if candidate == usize::MAX || pos - candidate > WINDOW {
+                    break;
+                }
+                let len = (0..max_len)
+                    .find(|&i| data[candidate + i] != data[pos + i])
+                    .unwrap_or(max_len);
+                if len > best.0 {
+                    best = (len, pos - candidate);
+                }
+                if len == max_len {
+                    break;
+                }
+                candidate = chains.prev[candidate];
+            }
+        }
+
+        let (len, dist) = best;
+        if len >= MIN_MATCH {
+            write_match(&mut out, len, dist);
+            for pos in pos..pos + len {
+                chains.insert(data, pos);
+            }
+            pos += len;
+        } else {
+            write_symbol(&mut out, data[pos] as u32);
+            chains.insert(data, pos);
+            pos += 1;
+        }
+    }
+
+    write_symbol(&mut out, 256);
+    out.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_deflate() {
+        // A literal, then a match of 4 bytes at distance 1, which reads the bytes it writes.
+        assert_eq!(deflate(b"aaaaa"), [0x4b, 0x04, 0x01, 0x00]);
+        assert_eq!(deflate(b""), [0x03, 0x00]);
+    }
+
+    #[test]
+    fn test_encode() {
+        let image = Image::filled(3, 2, [255, 0, 0]);
+        let png = encode(&image);
+        assert!(png.starts_with(SIGNATURE));
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], [0, 0, 0, 3, 0, 0, 0, 2]);
+        assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+    }
+}
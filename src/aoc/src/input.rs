@@ -0,0 +1,281 @@
+//! Loading of the puzzle input at runtime.
+//!
+//! Every binary accepts `--input <path>`, where a path of `-` reads the input from stdin. Without
+//! the flag the input is read from `input.txt` in the root of the day's crate. With
+//! `--format json` the answer is printed as a JSON object instead of a line of text.
+//!
+//! The days that can show what their solution is doing also accept `--visualize`, optionally with
+//! `--fps <n>` to choose how fast the frames are played, and `--animate <path>` to save the frames
+//! as a GIF or the last one as a PNG, with `--cell-size <n>` pixels per tile.
+
+use std::{
+    env, fmt,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::report::Format;
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Interprets a command line argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(path.into()),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, Error> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| Error::Io(self.clone(), err))?;
+                Ok(input)
+            }
+            Source::File(path) => {
+                std::fs::read_to_string(path).map_err(|err| Error::Io(self.clone(), err))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    MissingValue(String),
+    InvalidValue(String, String),
+    UnknownArgument(String),
+    /// A flag that is valid, but not supported by this binary.
+    Unsupported(String),
+    Io(Source, io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingValue(flag) => write!(f, "missing value for '{flag}'"),
+            Error::InvalidValue(flag, value) => write!(f, "invalid value '{value}' for '{flag}'"),
+            Error::UnknownArgument(arg) => write!(f, "unexpected argument '{arg}'"),
+            Error::Unsupported(flag) => write!(f, "'{flag}' is not supported by this solution"),
+            Error::Io(source, err) if err.kind() == io::ErrorKind::NotFound => {
+                write!(f, "input file '{source}' does not exist")
+            }
+            Error::Io(source, err) => write!(f, "failed to read input '{source}': {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The conventional location of the input of the crate at `crate_dir`.
+pub fn default_path(crate_dir: impl AsRef<Path>) -> PathBuf {
+    crate_dir.as_ref().join("input.txt")
+}
+
+/// The frame rate of a visualisation when `--fps` isn't given.
+pub const DEFAULT_FPS: f64 = 10.0;
+/// The size of a tile in pixels when `--cell-size` isn't given.
+pub const DEFAULT_CELL_SIZE: usize = 4;
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Args {
+    pub input: Source,
+    /// Whether to play the frames of the solution instead of printing its answer.
+    pub visualize: bool,
+    pub fps: f64,
+    /// Where to save the frames of the solution instead of printing its answer.
+    pub animate: Option<PathBuf>,
+    pub cell_size: usize,
+    pub format: Format,
+}
+
+impl Args {
+    pub fn parse_from(
+        args: impl IntoIterator<Item = String>,
+        default: PathBuf,
+    ) -> Result<Self, Error> {
+        let mut parsed = Args {
+            input: Source::File(default),
+            visualize: false,
+            fps: DEFAULT_FPS,
+            animate: None,
+            cell_size: DEFAULT_CELL_SIZE,
+            format: Format::Text,
+        };
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-i" | "--input" => {
+                    let value = args.next().ok_or(Error::MissingValue(arg))?;
+                    parsed.input = Source::from_arg(&value);
+                }
+                "--visualize" => parsed.visualize = true,
+                "--fps" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| Error::MissingValue(arg.clone()))?;
+                    parsed.fps = value
+                        .parse()
+                        .ok()
+                        .filter(|&fps: &f64| fps > 0.0 && fps.is_finite())
+                        .ok_or(Error::InvalidValue(arg, value))?;
+                }
+                "--animate" => {
+                    let value = args.next().ok_or(Error::MissingValue(arg))?;
+                    parsed.animate = Some(value.into());
+                }
+                "--cell-size" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| Error::MissingValue(arg.clone()))?;
+                    parsed.cell_size = value
+                        .parse()
+                        .ok()
+                        .filter(|&size| size > 0)
+                        .ok_or(Error::InvalidValue(arg, value))?;
+                }
+                "--format" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| Error::MissingValue(arg.clone()))?;
+                    parsed.format =
+                        Format::from_arg(&value).ok_or(Error::InvalidValue(arg, value))?;
+                }
+                _ => return Err(Error::UnknownArgument(arg)),
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Whether the frames of the solution were asked for, to play or to save.
+    pub fn wants_frames(&self) -> bool {
+        self.visualize || self.animate.is_some()
+    }
+}
+
+/// Parses the command line and reads the input it selects, falling back to `input.txt` next to the
+/// `Cargo.toml` in `manifest_dir`. Exits the process with a readable message on failure.
+pub fn load(manifest_dir: &str) -> (Args, String) {
+    let args = Args::parse_from(env::args().skip(1), default_path(manifest_dir));
+    let loaded = args.and_then(|args| match (args.visualize, args.animate.is_some()) {
+        (true, _) => Err(Error::Unsupported("--visualize".into())),
+        (_, true) => Err(Error::Unsupported("--animate".into())),
+        _ => Ok((args.input.read()?, args)),
+    });
+    let (input, args) =
+        loaded.unwrap_or_else(|err| exit_with(err, "[--input <path|->] [--format <text|json>]"));
+    (args, input)
+}
+
+/// Parses the command line of a binary that supports `--visualize` and `--animate`. Exits the process with a
+/// readable message on failure.
+pub fn args(manifest_dir: &str) -> Args {
+    Args::parse_from(env::args().skip(1), default_path(manifest_dir))
+        .unwrap_or_else(|err| exit_with(err, VISUALIZE_USAGE))
+}
+
+/// Reads the input selected by `args`, exiting the process with a readable message on failure.
+pub fn read(args: &Args) -> String {
+    args.input
+        .read()
+        .unwrap_or_else(|err| exit_with(err, VISUALIZE_USAGE))
+}
+
+const VISUALIZE_USAGE: &str = "[--input <path|->] [--format <text|json>] \
+    [--visualize | --animate <path>] [--fps <n>] [--cell-size <n>]";
+
+fn exit_with(err: Error, usage: &str) -> ! {
+    eprintln!("error: {err}");
+    eprintln!("usage: {} {usage}", binary_name());
+    process::exit(1);
+}
+
+fn binary_name() -> String {
+    env::args()
+        .next()
+        .as_deref()
+        .map(Path::new)
+        .and_then(Path::file_name)
+        .map_or("aoc".to_owned(), |name| name.to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, Error> {
+        let args = args.iter().map(|arg| arg.to_string());
+        Args::parse_from(args, PathBuf::from("day01/input.txt"))
+    }
+
+    #[test]
+    fn test_parse() {
+        let default = Source::File("day01/input.txt".into());
+        assert_eq!(parse(&[]).unwrap().input, default);
+        assert_eq!(parse(&["--input", "-"]).unwrap().input, Source::Stdin);
+        assert_eq!(
+            parse(&["-i", "other.txt"]).unwrap().input,
+            Source::File("other.txt".into())
+        );
+
+        let args = parse(&["--visualize", "--fps", "2.5"]).unwrap();
+        assert!(args.visualize);
+        assert_eq!(args.fps, 2.5);
+        assert_eq!(parse(&[]).unwrap().fps, DEFAULT_FPS);
+        assert!(!parse(&[]).unwrap().wants_frames());
+
+        let args = parse(&["--animate", "out.gif", "--cell-size", "8"]).unwrap();
+        assert_eq!(args.animate, Some(PathBuf::from("out.gif")));
+        assert_eq!(args.cell_size, 8);
+        assert!(args.wants_frames());
+
+        assert_eq!(parse(&[]).unwrap().format, Format::Text);
+        assert_eq!(parse(&["--format", "json"]).unwrap().format, Format::Json);
+        assert!(matches!(
+            parse(&["--format", "yaml"]),
+            Err(Error::InvalidValue(_, _))
+        ));
+
+        assert!(matches!(parse(&["--input"]), Err(Error::MissingValue(_))));
+        assert!(matches!(
+            parse(&["--fps", "0"]),
+            Err(Error::InvalidValue(_, _))
+        ));
+        assert!(matches!(
+            parse(&["--cell-size", "-1"]),
+            Err(Error::InvalidValue(_, _))
+        ));
+        assert!(matches!(
+            parse(&["input.txt"]),
+            Err(Error::UnknownArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let source = Source::File("does/not/exist.txt".into());
+        let err = source.read().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input file 'does/not/exist.txt' does not exist"
+        );
+    }
+}
@@ -0,0 +1,331 @@
+//! A small JSON reader and writer, enough for the files the runner saves and loads and the
+//! answers the solutions report.
+
+use std::fmt::{self, Write};
+
+use crate::ParseError;
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    /// A number, kept as written so that large integers don't lose precision.
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn object<'a>(fields: impl IntoIterator<Item = (&'a str, Value)>) -> Self {
+        Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value))
+                .collect(),
+        )
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields
+                .iter()
+                .find_map(|(name, value)| (name == key).then_some(value)),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(num) => num.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+impl From<u64> for Value {
+    fn from(num: u64) -> Self {
+        Value::Number(num.to_string())
+    }
+}
+
+impl From<&str> for Value {
+    fn from(str: &str) -> Self {
+        Value::String(str.to_owned())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(bool) => write!(f, "{bool}"),
+            Value::Number(num) => write!(f, "{num}"),
+            Value::String(str) => write_string(f, str),
+            Value::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    let sep = if i == 0 { "" } else { ", " };
+                    write!(f, "{sep}{value}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(fields) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    let sep = if i == 0 { "" } else { ", " };
+                    write!(f, "{sep}")?;
+                    write_string(f, key)?;
+                    write!(f, ": {value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, str: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for ch in str.chars() {
+        match ch {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\t' => f.write_str("\\t")?,
+            ch if ch.is_control() => write!(f, "\\u{:04x}", ch as u32)?,
+            ch => f.write_char(ch)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// Parses a whole JSON document.
+pub fn parse(text: &str) -> Result<Value, ParseError> {
+    let mut parser = Parser { text, pos: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        Some(_) => Err(parser.error("the end of the input")),
+        None => Ok(value),
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// An error pointing at the current position.
+    fn error(&self, expected: &str) -> ParseError {
+        let before = &self.text[..self.pos];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap().chars().count() + 1;
+        let found = match self.peek() {
+            Some(ch) => format!("'{ch}'"),
+            None => "end of input".to_owned(),
+        };
+        ParseError::new(line, column, expected, found)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        match self.peek() == Some(expected) {
+            true => {
+                self.bump();
+                Ok(())
+            }
+            false => Err(self.error(&format!("'{expected}'"))),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => self.string().map(Value::String),
+            Some('-' | '0'..='9') => self.number(),
+            Some('a'..='z') => self.literal(),
+            _ => Err(self.error("a value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, ParseError> {
+        self.bump();
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(Value::Object(fields));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("a key"));
+            }
+            let key = self.string()?;
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.bump(),
+                Some('}') => {
+                    self.bump();
+                    return Ok(Value::Object(fields));
+                }
+                _ => return Err(self.error("',' or '}'")),
+            };
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.bump();
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.bump();
+            return Ok(Value::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.bump(),
+                Some(']') => {
+                    self.bump();
+                    return Ok(Value::Array(values));
+                }
+                _ => return Err(self.error("',' or ']'")),
+            };
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.bump();
+        let mut str = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(str),
+                Some('\\') => {
+                    let ch = match self.bump() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('u') => {
+                            let hex = self.text.get(self.pos..self.pos + 4);
+                            let ch = hex
+                                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error("4 hex digits"))?;
+                            self.pos += 4;
+                            ch
+                        }
+                        Some(ch @ ('"' | '\\' | '/')) => ch,
+                        _ => return Err(self.error("an escape sequence")),
+                    };
+                    str.push(ch);
+                }
+                Some(ch) => str.push(ch),
+                None => return Err(self.error("'\"'")),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|ch| ch.is_ascii_digit() || "+-.eE".contains(ch))
+        {
+            self.bump();
+        }
+        let num = &self.text[start..self.pos];
+        if num.parse::<f64>().is_err() {
+            self.pos = start;
+            return Err(self.error("a number"));
+        }
+        Ok(Value::Number(num.to_owned()))
+    }
+
+    fn literal(&mut self) -> Result<Value, ParseError> {
+        let rest = &self.text[self.pos..];
+        let (value, len) = if rest.starts_with("null") {
+            (Value::Null, 4)
+        } else if rest.starts_with("true") {
+            (Value::Bool(true), 4)
+        } else if rest.starts_with("false") {
+            (Value::Bool(false), 5)
+        } else {
+            return Err(self.error("a value"));
+        };
+        self.pos += len;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let text = r#"{"day": 17, "name": "a \"b\"\n", "runs": [1, 2.5e3], "ok": true, "x": null}"#;
+        let value = parse(text).unwrap();
+        assert_eq!(value.to_string(), text);
+        assert_eq!(value.get("day").and_then(Value::as_u64), Some(17));
+        assert_eq!(
+            value.get("runs").and_then(Value::as_array).unwrap().len(),
+            2
+        );
+        assert_eq!(parse(" [ ] ").unwrap(), Value::Array(Vec::new()));
+        assert_eq!(parse(r#""é""#).unwrap(), Value::from("é"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = |text| parse(text).unwrap_err().to_string();
+        assert_eq!(
+            error("[1,\n 2 3]"),
+            "line 2, column 4: expected ',' or ']', found '3'"
+        );
+        assert_eq!(
+            error(r#"{"a" 1}"#),
+            "line 1, column 6: expected ':', found '1'"
+        );
+        assert_eq!(
+            error("[1] x"),
+            "line 1, column 5: expected the end of the input, found 'x'"
+        );
+        assert_eq!(
+            error("\"abc"),
+            "line 1, column 5: expected '\"', found end of input"
+        );
+    }
+}
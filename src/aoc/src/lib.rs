@@ -0,0 +1,22 @@
+//! Code shared between the solutions of the individual days.
+
+pub mod check;
+pub mod fixture;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod json;
+pub mod math;
+pub mod parse;
+pub mod random;
+pub mod render;
+pub mod report;
+mod solution;
+
+pub use check::Check;
+pub use geometry::{Direction, Vec2, Vec3};
+pub use grid::Grid;
+pub use parse::ParseError;
+pub use solution::{Answer, Solution};
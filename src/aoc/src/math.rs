@@ -0,0 +1,35 @@
+//! Number theory that the solutions of several days need.
+
+/// The greatest common divisor of `a` and `b`, which is `a` if `b` is 0.
+pub fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple of `a` and `b`, which has to be positive. Folding it over numbers
+/// from 1 gives the least common multiple of all of them.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    a / gcd(a, b) * b
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(18, 12), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(1009, 9), 1);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!([4, 6, 8].into_iter().fold(1, lcm), 24);
+        assert_eq!(lcm(9, 1009 * 3), 9 * 1009);
+        assert_eq!(lcm(1, 5), 5);
+    }
+}
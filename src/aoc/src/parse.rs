@@ -0,0 +1,301 @@
+//! Helpers for parsing the puzzle input, which report where and why it is malformed.
+
+use std::{error, fmt, iter, str, str::FromStr};
+
+/// Points at the part of the input that didn't look like the parser expected, or tells which
+/// assumption of a solution the input breaks.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseError {
+    /// The line number, starting at 1, or 0 for an unmet assumption.
+    pub line: usize,
+    /// The column within the line, in characters and starting at 1, or 0 for an unmet assumption.
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// The input ended while `expected` was still missing.
+    pub fn at_end(input: &str, expected: impl Into<String>) -> Self {
+        Self::new(input.lines().count() + 1, 1, expected, "end of input")
+    }
+
+    /// The input is well formed, but breaks an `assumption` of the solution that no single place
+    /// in it is to blame for, as shown by `evidence`. These are the failures of a [`Check`].
+    ///
+    /// [`Check`]: crate::Check
+    pub fn unmet(assumption: impl Into<String>, evidence: impl Into<String>) -> Self {
+        Self::new(0, 0, assumption, evidence)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            0 => write!(f, "unmet assumption: {}: {}", self.expected, self.found),
+            line => write!(
+                f,
+                "line {line}, column {}: expected {}, found {}",
+                self.column, self.expected, self.found
+            ),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Formats a set of characters as an expectation, e.g. `one of '.', '#'`.
+pub fn one_of(chars: &str) -> String {
+    let chars: Vec<String> = chars.chars().map(|ch| format!("'{ch}'")).collect();
+    format!("one of {}", chars.join(", "))
+}
+
+/// A non-blank line of the input.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    /// The line number, starting at 1.
+    pub num: usize,
+    /// The line without leading and trailing whitespace.
+    pub text: &'a str,
+    raw: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// The column at which `part`, a slice of this line, starts.
+    pub fn column(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.raw.as_ptr() as usize);
+        self.raw
+            .get(..offset)
+            .map_or(1, |before| before.chars().count() + 1)
+    }
+
+    /// An error pointing at `part`, a slice of this line.
+    pub fn error(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        let found = match part.is_empty() {
+            true => "end of line".to_owned(),
+            false => format!("'{part}'"),
+        };
+        ParseError::new(self.num, self.column(part), expected, found)
+    }
+
+    /// An error pointing at the character with the given index in `text`.
+    pub fn char_error(&self, index: usize, expected: impl Into<String>) -> ParseError {
+        let part = match self.text.char_indices().nth(index) {
+            Some((start, ch)) => &self.text[start..start + ch.len_utf8()],
+            None => &self.text[self.text.len()..],
+        };
+        self.error(part, expected)
+    }
+
+    pub fn number<T: FromStr>(&self, part: &'a str) -> Result<T, ParseError> {
+        let part = part.trim();
+        part.parse().map_err(|_| self.error(part, "a number"))
+    }
+
+    pub fn split_once(
+        &self,
+        part: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.error(part, format!("'{delimiter}'")))
+    }
+
+    pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        part.strip_prefix(prefix)
+            .ok_or_else(|| self.error(part, format!("'{prefix}'")))
+    }
+
+    pub fn strip_suffix(&self, part: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        part.strip_suffix(suffix)
+            .ok_or_else(|| self.error(part, format!("'{suffix}'")))
+    }
+
+    /// Checks that every character of `part` is one of `allowed`.
+    pub fn check_chars(&self, part: &'a str, allowed: &str) -> Result<(), ParseError> {
+        match part.char_indices().find(|(_, ch)| !allowed.contains(*ch)) {
+            Some((start, ch)) => {
+                let part = &part[start..start + ch.len_utf8()];
+                Err(self.error(part, one_of(allowed)))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Iterator over the non-blank lines of the input, created by [`lines`].
+#[derive(Clone, Debug)]
+pub struct Lines<'a> {
+    input: &'a str,
+    lines: iter::Enumerate<str::Lines<'a>>,
+}
+
+impl<'a> Lines<'a> {
+    /// The next line, or an error if the input ends before `expected`.
+    pub fn next_line(&mut self, expected: impl Into<String>) -> Result<Line<'a>, ParseError> {
+        self.next()
+            .ok_or_else(|| ParseError::at_end(self.input, expected))
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.find_map(|(i, raw)| {
+            let text = raw.trim();
+            (!text.is_empty()).then_some(Line {
+                num: i + 1,
+                text,
+                raw,
+            })
+        })
+    }
+}
+
+/// The lines of the input, skipping blank ones.
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines {
+        input,
+        lines: input.lines().enumerate(),
+    }
+}
+
+/// The groups of lines of the input that are separated by blank lines.
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![Vec::new()];
+    let mut prev_num = 0;
+
+    for line in lines(input) {
+        if line.num > prev_num + 1 && !blocks.last().unwrap().is_empty() {
+            blocks.push(Vec::new());
+        }
+        prev_num = line.num;
+        blocks.last_mut().unwrap().push(line);
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+/// Checks that all the lines have the same length.
+pub fn check_rectangular(lines: &[Line]) -> Result<(), ParseError> {
+    let width = lines.first().map_or(0, |line| line.text.chars().count());
+    for line in lines {
+        let len = line.text.chars().count();
+        if len != width {
+            return Err(ParseError::new(
+                line.num,
+                line.column(line.text) + len.min(width),
+                format!("a row of {width} tiles"),
+                format!("{len} tiles"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the lines form a rectangular grid, with every tile one of `tiles`.
+pub fn check_grid(lines: &[Line], tiles: &str) -> Result<(), ParseError> {
+    check_rectangular(lines)?;
+    lines
+        .iter()
+        .try_for_each(|line| line.check_chars(line.text, tiles))
+}
+
+/// The lines of a grid where every tile is one of `tiles`.
+pub fn grid_lines<'a>(input: &'a str, tiles: &str) -> Result<Vec<Line<'a>>, ParseError> {
+    let lines: Vec<Line> = lines(input).collect();
+    if lines.is_empty() {
+        return Err(ParseError::at_end(input, "a grid"));
+    }
+
+    check_grid(&lines, tiles)?;
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let input = "
+            Game 1: 3 blue
+
+            Game 2: x red
+        ";
+        let lines: Vec<Line> = lines(input).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!((lines[1].num, lines[1].text), (4, "Game 2: x red"));
+
+        let (_, count) = lines[1].text.split_once(": ").unwrap();
+        let count = &count[..1];
+        assert_eq!(lines[1].column(count), 21);
+        assert_eq!(
+            lines[1].number::<i32>(count),
+            Err(ParseError::new(4, 21, "a number", "'x'"))
+        );
+        assert_eq!(
+            lines[0]
+                .split_once(lines[0].text, " -> ")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 13: expected ' -> ', found 'Game 1: 3 blue'"
+        );
+
+        let mut lines = super::lines(input);
+        lines.nth(1);
+        assert_eq!(
+            lines.next_line("a game").unwrap_err().to_string(),
+            "line 6, column 1: expected a game, found end of input"
+        );
+    }
+
+    #[test]
+    fn test_unmet() {
+        let err = ParseError::unmet("the grid is square", "it is 3 by 4");
+        assert_eq!((err.line, err.column), (0, 0));
+        assert_eq!(
+            err.to_string(),
+            "unmet assumption: the grid is square: it is 3 by 4"
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks = blocks("\n  a\n  b\n\n\n  c\n");
+        let texts: Vec<Vec<&str>> = blocks
+            .iter()
+            .map(|block| block.iter().map(|line| line.text).collect())
+            .collect();
+        assert_eq!(texts, [vec!["a", "b"], vec!["c"]]);
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(grid_lines(".#\n#.", ".#").unwrap()[1].text, "#.");
+        assert_eq!(
+            grid_lines(".#\n#.#", ".#").unwrap_err(),
+            ParseError::new(2, 3, "a row of 2 tiles", "3 tiles")
+        );
+        assert_eq!(
+            grid_lines(".#\n#x", ".#").unwrap_err(),
+            ParseError::new(2, 2, "one of '.', '#'", "'x'")
+        );
+        assert!(grid_lines("\n  \n", ".#").is_err());
+    }
+}
@@ -0,0 +1,241 @@
+//! A small seeded random number generator for generating puzzle inputs, together with the random
+//! shapes that the generators of several days build on.
+
+use std::ops::{Bound, RangeBounds};
+
+use crate::{grid::Pos, Direction, Grid};
+
+/// The SplitMix64 generator, which is fast and good enough to make up puzzle inputs. The same seed
+/// always gives the same numbers.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which has to have a start and can't be empty.
+    pub fn range<T: Int>(&mut self, range: impl RangeBounds<T>) -> T {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start.to_i128(),
+            Bound::Excluded(&start) => start.to_i128() + 1,
+            Bound::Unbounded => panic!("Expected a range with a start"),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.to_i128(),
+            Bound::Excluded(&end) => end.to_i128() - 1,
+            Bound::Unbounded => T::MAX.to_i128(),
+        };
+        assert!(start <= end, "Expected a range that isn't empty");
+
+        let span = (end - start + 1) as u128;
+        T::from_i128(start + (self.next_u64() as u128 % span) as i128)
+    }
+
+    /// Whether an event with the given probability happens.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64 <= probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+
+    /// A string of `len` characters picked from `chars`.
+    pub fn string(&mut self, len: usize, chars: &str) -> String {
+        let chars: Vec<char> = chars.chars().collect();
+        (0..len).map(|_| *self.pick(&chars)).collect()
+    }
+}
+
+/// The integers [`Rng::range`] can pick from.
+pub trait Int: Copy {
+    const MAX: Self;
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Int for $ty {
+                const MAX: Self = <$ty>::MAX;
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    value as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+/// A shape of up to `cells` cells in a `width` by `height` grid, grown from a random cell. It is
+/// connected, has no holes and never touches itself only at a corner, so its outline is a single
+/// loop that doesn't cross or touch itself.
+pub fn region(rng: &mut Rng, width: usize, height: usize, cells: usize) -> Grid<bool> {
+    let mut region = Grid::filled(width, height, false);
+    let mut frontier = vec![(rng.range(0..width), rng.range(0..height))];
+
+    let mut count = 0;
+    while count < cells && !frontier.is_empty() {
+        let pos = frontier.swap_remove(rng.range(0..frontier.len()));
+        if region[pos] {
+            continue;
+        }
+        region[pos] = true;
+        if touches_corner(&region, pos) || !is_simple(&region, pos) {
+            region[pos] = false;
+            continue;
+        }
+        count += 1;
+        frontier.extend(region.neighbours4(pos).filter(|&next| !region[next]));
+    }
+    region
+}
+
+/// Whether one of the 2x2 squares around `pos` holds two cells that only share a corner.
+fn touches_corner(region: &Grid<bool>, (x, y): Pos) -> bool {
+    let inside = |x: i64, y: i64| region.checked_pos(x, y).is_some_and(|pos| region[pos]);
+    let (x, y) = (x as i64, y as i64);
+
+    [(x - 1, y - 1), (x, y - 1), (x - 1, y), (x, y)]
+        .into_iter()
+        .any(|(x, y)| {
+            let (a, b) = (inside(x, y), inside(x + 1, y));
+            let (c, d) = (inside(x, y + 1), inside(x + 1, y + 1));
+            (a && d && !b && !c) || (b && c && !a && !d)
+        })
+}
+
+/// Whether adding `pos` keeps the region free of holes. That is the case if the cells of the
+/// region in the ring of eight around `pos` form a single run, so they are joined only once.
+fn is_simple(region: &Grid<bool>, (x, y): Pos) -> bool {
+    let (x, y) = (x as i64, y as i64);
+    let ring = [
+        (1, 0),
+        (1, -1),
+        (0, -1),
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+    ]
+    .map(|(dx, dy)| {
+        region
+            .checked_pos(x + dx, y + dy)
+            .is_some_and(|pos| region[pos])
+    });
+
+    let runs = (0..8).filter(|&i| ring[i] && !ring[(i + 7) % 8]).count();
+    runs == 1 || ring.iter().all(|&cell| !cell)
+}
+
+/// The outline of a region made by [`region`], as a loop along the corners of its cells. The
+/// corner `(x, y)` is the top left one of cell `(x, y)`. Returns the corner the loop starts at,
+/// the top left one of the first cell, and its steps in clockwise order.
+pub fn outline(region: &Grid<bool>) -> (Pos, Vec<Direction>) {
+    let inside = |x: i64, y: i64| region.checked_pos(x, y).is_some_and(|pos| region[pos]);
+    // Whether there is a side of a cell of the region going from the corner `(x, y)` in `dir`.
+    let side = |(x, y): (i64, i64), dir| match dir {
+        Direction::Up => inside(x - 1, y - 1) != inside(x, y - 1),
+        Direction::Right => inside(x, y - 1) != inside(x, y),
+        Direction::Down => inside(x - 1, y) != inside(x, y),
+        Direction::Left => inside(x - 1, y - 1) != inside(x - 1, y),
+    };
+
+    let start = region
+        .position(|&cell| cell)
+        .expect("Expected a region with at least one cell");
+    let (mut pos, mut dir) = ((start.0 as i64, start.1 as i64), Direction::Right);
+    let mut steps = Vec::new();
+    loop {
+        steps.push(dir);
+        pos = (pos.0 + dir.offset().x, pos.1 + dir.offset().y);
+        if pos == (start.0 as i64, start.1 as i64) {
+            return (start, steps);
+        }
+        dir = [dir.turn_left(), dir, dir.turn_right()]
+            .into_iter()
+            .find(|&next| side(pos, next))
+            .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(42);
+        let numbers: Vec<u64> = (0..5).map(|_| rng.next_u64()).collect();
+        let mut again = Rng::new(42);
+        assert!(numbers.iter().all(|&num| num == again.next_u64()));
+
+        for _ in 0..1000 {
+            assert!((3..7).contains(&rng.range(3..7)));
+            assert!((-2..=2).contains(&rng.range(-2_i64..=2)));
+        }
+        assert_eq!(rng.range(5_usize..=5), 5);
+        assert!(!rng.chance(0.0) || rng.chance(1.0));
+
+        let mut items: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert_eq!(rng.string(4, "ab").len(), 4);
+    }
+
+    #[test]
+    fn test_outline() {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let region = region(&mut rng, 8, 6, 20);
+            let (start, steps) = outline(&region);
+
+            // The loop is closed, visits every corner at most once and surrounds every cell: by
+            // the shoelace formula its area is the number of cells.
+            let mut corners = vec![(start.0 as i64, start.1 as i64)];
+            for dir in steps.iter() {
+                let (x, y) = *corners.last().unwrap();
+                corners.push((x + dir.offset().x, y + dir.offset().y));
+            }
+            assert_eq!(corners.pop(), Some(corners[0]));
+            let mut unique = corners.clone();
+            unique.sort_unstable();
+            unique.dedup();
+            assert_eq!(unique.len(), corners.len());
+
+            let area: i64 = corners
+                .iter()
+                .zip(corners.iter().cycle().skip(1))
+                .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+                .sum();
+            assert_eq!(area / 2, region.iter().filter(|&&cell| cell).count() as i64);
+        }
+    }
+}
@@ -0,0 +1,367 @@
+//! Drawing grids in the terminal, to watch what a solution is doing.
+//!
+//! A [`Frame`] is a grid of styled characters, built from any [`Grid`] and then marked up with
+//! overlays such as a path, a set of visited tiles or the beams crossing it. A [`Player`] shows a
+//! sequence of frames in place, at a fixed number of frames per second.
+
+use std::{
+    fmt,
+    io::{self, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{grid::Pos, image, input::Args, Direction, Grid};
+
+/// A terminal colour, either one of the 16 standard ones or a 24-bit colour.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// The parameters of the SGR escape sequence selecting this colour.
+    fn sgr(self, background: bool) -> String {
+        let base = if background { 40 } else { 30 };
+        let offset = match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            // The bright variant of black.
+            Color::Gray => 60,
+            Color::Rgb(r, g, b) => return format!("{};2;{r};{g};{b}", base + 8),
+        };
+        (base + offset).to_string()
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub const PLAIN: Self = Self {
+        fg: None,
+        bg: None,
+        bold: false,
+    };
+
+    pub const fn fg(color: Color) -> Self {
+        Self {
+            fg: Some(color),
+            ..Self::PLAIN
+        }
+    }
+
+    pub const fn bg(color: Color) -> Self {
+        Self {
+            bg: Some(color),
+            ..Self::PLAIN
+        }
+    }
+
+    /// This style on top of the background `color`.
+    pub const fn on(self, color: Color) -> Self {
+        Self {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    pub const fn bold(self) -> Self {
+        Self { bold: true, ..self }
+    }
+
+    /// The escape sequence switching from the default style to this one.
+    fn escape(&self) -> String {
+        let mut params = Vec::new();
+        if self.bold {
+            params.push("1".to_owned());
+        }
+        params.extend(self.fg.map(|color| color.sgr(false)));
+        params.extend(self.bg.map(|color| color.sgr(true)));
+        format!("\x1b[{}m", params.join(";"))
+    }
+}
+
+/// A single character of a frame.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+}
+
+impl Cell {
+    pub const fn new(ch: char, style: Style) -> Self {
+        Self { ch, style }
+    }
+
+    pub const fn plain(ch: char) -> Self {
+        Self::new(ch, Style::PLAIN)
+    }
+}
+
+/// A grid drawn as styled characters, with a caption shown below it.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Frame {
+    cells: Grid<Cell>,
+    caption: String,
+}
+
+impl Frame {
+    /// A frame drawing each tile of `grid` as the cell `draw` returns for it.
+    pub fn new<T>(grid: &Grid<T>, mut draw: impl FnMut(&T) -> Cell) -> Self {
+        Self {
+            cells: grid.map(|tile| draw(tile)),
+            caption: String::new(),
+        }
+    }
+
+    /// A frame showing the characters of `grid` as they are.
+    pub fn plain(grid: &Grid<char>) -> Self {
+        Self::new(grid, |&ch| Cell::plain(ch))
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    /// Restyles the cells at `positions`, keeping their characters. Used for visited sets.
+    pub fn highlight(&mut self, positions: impl IntoIterator<Item = Pos>, style: Style) {
+        for pos in positions {
+            if let Some(cell) = self.cells.get_mut(pos) {
+                cell.style = style;
+            }
+        }
+    }
+
+    /// Replaces the cells at `positions` with `ch` drawn in `style`.
+    pub fn mark(&mut self, positions: impl IntoIterator<Item = Pos>, ch: char, style: Style) {
+        for pos in positions {
+            self.put(pos, Cell::new(ch, style));
+        }
+    }
+
+    /// Draws the steps of a path as arrows, each pointing towards the next position. The last
+    /// position is drawn as `o`.
+    pub fn path(&mut self, path: &[Pos], style: Style) {
+        for (i, &pos) in path.iter().enumerate() {
+            let ch = match path.get(i + 1) {
+                Some(&next) => direction(pos, next).map_or('o', arrow),
+                None => 'o',
+            };
+            self.put(pos, Cell::new(ch, style));
+        }
+    }
+
+    /// Draws beams as arrows pointing in the direction they travel. A tile crossed by beams in
+    /// different directions is drawn as the number of beams.
+    pub fn beams(&mut self, beams: impl IntoIterator<Item = (Pos, Direction)>, style: Style) {
+        let mut dirs = Grid::filled(self.cells.width(), self.cells.height(), 0_u8);
+        for (pos, dir) in beams {
+            if let Some(mask) = dirs.get_mut(pos) {
+                *mask |= 1 << dir as u8;
+            }
+        }
+
+        for (pos, &mask) in dirs.enumerate().filter(|(_, &mask)| mask != 0) {
+            let ch = match mask.count_ones() {
+                1 => arrow(Direction::ALL[mask.trailing_zeros() as usize]),
+                count => char::from_digit(count, 10).unwrap(),
+            };
+            self.cells[pos] = Cell::new(ch, style);
+        }
+    }
+
+    fn put(&mut self, pos: Pos, cell: Cell) {
+        if let Some(old) = self.cells.get_mut(pos) {
+            *old = cell;
+        }
+    }
+}
+
+/// Writes the frame with ANSI escape sequences, only switching style where it changes.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.rows() {
+            let mut style = Style::PLAIN;
+            for cell in row {
+                if cell.style != style {
+                    if style != Style::PLAIN {
+                        write!(f, "\x1b[0m")?;
+                    }
+                    if cell.style != Style::PLAIN {
+                        write!(f, "{}", cell.style.escape())?;
+                    }
+                    style = cell.style;
+                }
+                write!(f, "{}", cell.ch)?;
+            }
+            if style != Style::PLAIN {
+                write!(f, "\x1b[0m")?;
+            }
+            writeln!(f)?;
+        }
+        if !self.caption.is_empty() {
+            writeln!(f, "{}", self.caption)?;
+        }
+        Ok(())
+    }
+}
+
+/// The direction of a single step from `from` to `to`, if they are neighbours.
+fn direction(from: Pos, to: Pos) -> Option<Direction> {
+    Direction::ALL.into_iter().find(|dir| {
+        let offset = dir.offset();
+        from.0.checked_add_signed(offset.x as isize) == Some(to.0)
+            && from.1.checked_add_signed(offset.y as isize) == Some(to.1)
+    })
+}
+
+fn arrow(dir: Direction) -> char {
+    match dir {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+    }
+}
+
+/// Shows frames one after the other in the same place of the terminal.
+#[derive(Clone, Copy, Debug)]
+pub struct Player {
+    delay: Duration,
+}
+
+impl Player {
+    pub fn new(fps: f64) -> Self {
+        assert!(fps > 0.0, "Expected a positive frame rate, got {fps}");
+        Self {
+            delay: Duration::from_secs_f64(1.0 / fps),
+        }
+    }
+
+    /// Writes each frame over the previous one, waiting between frames so they are shown at the
+    /// player's frame rate.
+    pub fn play(
+        &self,
+        frames: impl IntoIterator<Item = Frame>,
+        out: &mut impl Write,
+    ) -> io::Result<()> {
+        let mut height = 0;
+        let mut next = Instant::now();
+        for frame in frames {
+            let text = frame.to_string();
+            if height > 0 {
+                // Back to the top of the previous frame, clearing everything below.
+                write!(out, "\x1b[{height}F\x1b[J")?;
+            }
+            write!(out, "{text}")?;
+            out.flush()?;
+            height = text.lines().count();
+
+            next += self.delay;
+            thread::sleep(next.saturating_duration_since(Instant::now()));
+        }
+        Ok(())
+    }
+}
+
+/// Plays the frames a binary's `--visualize` flag asked for, or saves them where `--animate`
+/// asked to. Exits the process on failure.
+pub fn show<I: IntoIterator<Item = Frame>, E: fmt::Display>(frames: Result<I, E>, args: &Args) {
+    let result = match (frames, &args.animate) {
+        (Ok(frames), Some(path)) => {
+            let options = image::Options {
+                cell_size: args.cell_size,
+                fps: args.fps,
+                ..image::Options::default()
+            };
+            image::save(frames, path, &options)
+                .map_err(|err| format!("failed to save '{}': {err}", path.display()))
+        }
+        (Ok(frames), None) => Player::new(args.fps)
+            .play(frames, &mut io::stdout().lock())
+            .map_err(|err| err.to_string()),
+        (Err(err), _) => Err(err.to_string()),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frame() -> Frame {
+        Frame::plain(&Grid::parse("...\n.#.\n...", ".#").unwrap())
+    }
+
+    #[test]
+    fn test_ansi() {
+        let mut frame = frame().with_caption("step 1");
+        frame.highlight([(1, 1)], Style::fg(Color::Red).bold());
+        frame.mark(
+            [(2, 1)],
+            'O',
+            Style::fg(Color::Rgb(1, 2, 3)).on(Color::Gray),
+        );
+        assert_eq!(
+            frame.to_string(),
+            "...\n.\x1b[1;31m#\x1b[0m\x1b[38;2;1;2;3;100mO\x1b[0m\n...\nstep 1\n"
+        );
+        assert_eq!(frame.cells()[(1, 1)].ch, '#');
+    }
+
+    #[test]
+    fn test_overlays() {
+        let mut frame = frame();
+        frame.path(&[(0, 0), (1, 0), (2, 0), (2, 1)], Style::PLAIN);
+        frame.beams(
+            [
+                ((0, 2), Direction::Left),
+                ((1, 2), Direction::Up),
+                ((1, 2), Direction::Down),
+            ],
+            Style::PLAIN,
+        );
+        assert_eq!(frame.to_string(), ">>v\n.#o\n<2.\n");
+    }
+
+    #[test]
+    fn test_player() {
+        let mut out = Vec::new();
+        let frames = [frame(), frame().with_caption("done")];
+        Player::new(1000.0).play(frames, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out, "...\n.#.\n...\n\x1b[3F\x1b[J...\n.#.\n...\ndone\n");
+    }
+}
@@ -0,0 +1,180 @@
+//! Printing the answer of a solution, either as a line of text or as a JSON object that can be
+//! stored and compared.
+
+use std::{
+    process,
+    time::{Duration, Instant},
+};
+
+use crate::{input::Args, json::Value, Answer, Solution};
+
+/// The version of the solutions, which every crate of the workspace shares, followed by the
+/// commit they were built from, like `0.1.0+1a2b3c4`.
+pub const VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), env!("AOC_REVISION"));
+
+/// How answers are printed, chosen with `--format`.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// One run of a solution on an input.
+pub struct Run<'a> {
+    pub day: u32,
+    pub part: u32,
+    pub answer: &'a Answer,
+    pub elapsed: Duration,
+    pub input: &'a str,
+}
+
+/// The largest integer that every reader of JSON gets right, since many of them, like JavaScript
+/// and jq, hold numbers in an `f64`.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+impl Run<'_> {
+    /// The run as a JSON object. The answer is a string if it may not fit in an `f64`, which is
+    /// always the case for a `u128` answer and for an `int` beyond 2^53.
+    pub fn to_json(&self) -> Value {
+        let answer = match self.answer {
+            Answer::Int(num) if num.unsigned_abs() <= MAX_SAFE_INTEGER => {
+                Value::Number(num.to_string())
+            }
+            Answer::Str(str) => Value::from(str.as_str()),
+            num => Value::from(num.to_string().as_str()),
+        };
+        Value::object([
+            ("day", Value::from(self.day as u64)),
+            ("part", Value::from(self.part as u64)),
+            ("answer", answer),
+            ("answer_type", Value::from(answer_type(self.answer))),
+            (
+                "time_ms",
+                Value::Number(format!("{:.3}", self.elapsed.as_secs_f64() * 1e3)),
+            ),
+            ("input_hash", Value::from(input_hash(self.input).as_str())),
+            ("version", Value::from(VERSION)),
+        ])
+    }
+}
+
+pub fn answer_type(answer: &Answer) -> &'static str {
+    match answer {
+        Answer::Int(_) => "int",
+        Answer::U128(_) => "u128",
+        Answer::Str(_) => "str",
+    }
+}
+
+/// The 64-bit FNV-1a hash of the input in hex, to tell which input an answer belongs to.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Solves `part` of `solution` and prints its answer in the format chosen by `args`. Exits the
+/// process with a readable message if the input is invalid.
+pub fn print(args: &Args, solution: &dyn Solution, part: u32, input: &str) {
+    let start = Instant::now();
+    let answer = solution.solve(part, input);
+    let elapsed = start.elapsed();
+
+    let answer = answer.unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1);
+    });
+    match args.format {
+        Format::Text => println!("Result: {answer}"),
+        Format::Json => {
+            let run = Run {
+                day: solution.day(),
+                part,
+                answer: &answer,
+                elapsed,
+                input,
+            };
+            println!("{}", run.to_json());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let answer = Answer::from(u128::MAX);
+        let run = Run {
+            day: 6,
+            part: 2,
+            answer: &answer,
+            elapsed: Duration::from_micros(1_500),
+            input: "",
+        };
+        assert_eq!(
+            run.to_json().to_string(),
+            format!(
+                "{{\"day\": 6, \"part\": 2, \"answer\": \"{}\", \"answer_type\": \"u128\", \
+                 \"time_ms\": 1.500, \"input_hash\": \"cbf29ce484222325\", \
+                 \"version\": \"{VERSION}\"}}",
+                u128::MAX
+            )
+        );
+
+        let answer = Answer::from("ABC");
+        let run = Run {
+            answer: &answer,
+            ..run
+        };
+        assert_eq!(run.to_json().get("answer"), Some(&Value::from("ABC")));
+
+        let json = |answer: Answer| {
+            let run = Run {
+                answer: &answer,
+                ..run
+            };
+            run.to_json().get("answer").unwrap().to_string()
+        };
+        assert_eq!(json(Answer::from(12_u64)), "\"12\"");
+        assert_eq!(
+            json(Answer::from(1_u128 << 100)),
+            "\"1267650600228229401496703205376\""
+        );
+        assert_eq!(
+            json(Answer::from(-9_007_199_254_740_991_i64)),
+            "-9007199254740991"
+        );
+        assert_eq!(
+            json(Answer::from(9_007_199_254_740_993_i64)),
+            "\"9007199254740993\""
+        );
+        assert_eq!(json(Answer::from(i64::MIN)), "\"-9223372036854775808\"");
+    }
+
+    #[test]
+    fn test_version() {
+        let (version, revision) = VERSION.split_once('+').unwrap_or((VERSION, ""));
+        assert_eq!(version, env!("CARGO_PKG_VERSION"));
+        assert!(revision.chars().all(|ch| ch.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("1\n2\n"), input_hash("2\n1\n"));
+    }
+}
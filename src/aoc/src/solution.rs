@@ -0,0 +1,202 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::{Check, ParseError};
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Int(i64),
+    U128(u128),
+    Str(String),
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        use Answer::*;
+        match (self, other) {
+            (Int(a), Int(b)) => a == b,
+            (U128(a), U128(b)) => a == b,
+            (Str(a), Str(b)) => a == b,
+            (Int(a), U128(b)) | (U128(b), Int(a)) => u128::try_from(*a) == Ok(*b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+/// Numbers are ordered by value, while strings can only be equal.
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        use Answer::*;
+        match (self, other) {
+            (Int(a), Int(b)) => Some(a.cmp(b)),
+            (U128(a), U128(b)) => Some(a.cmp(b)),
+            (Int(a), U128(b)) => Some(match u128::try_from(*a) {
+                Ok(a) => a.cmp(b),
+                Err(_) => Ordering::Less,
+            }),
+            (U128(_), Int(_)) => other.partial_cmp(self).map(Ordering::reverse),
+            (Str(a), Str(b)) => (a == b).then_some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(num) => write!(f, "{num}"),
+            Answer::U128(num) => write!(f, "{num}"),
+            Answer::Str(str) => write!(f, "{str}"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(num: $ty) -> Self {
+                    Answer::$variant(num as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Int: i8, i16, i32, i64);
+impl_from_int!(U128: u8, u16, u32, u64, usize, u128);
+
+impl From<String> for Answer {
+    fn from(str: String) -> Self {
+        Answer::Str(str)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(str: &str) -> Self {
+        Answer::Str(str.to_owned())
+    }
+}
+
+/// The solutions to both parts of the puzzle of one day.
+pub trait Solution: Sync {
+    fn day(&self) -> u32;
+
+    /// Only called if [`Solution::has_part`] accepts part 1, like [`Solution::part2`].
+    fn part1(&self, input: &str) -> Result<Answer, ParseError>;
+    fn part2(&self, input: &str) -> Result<Answer, ParseError>;
+
+    /// A random input that both parts can solve, which is always the same for the same `seed` and
+    /// grows with `size`.
+    fn generate(&self, seed: u64, size: usize) -> String;
+
+    /// Checks the assumptions that the solutions make about the input beyond its syntax. Most
+    /// solutions don't make any.
+    fn check(&self, _input: &str) -> Result<Vec<Check>, ParseError> {
+        Ok(Vec::new())
+    }
+
+    /// Whether `part` has been solved in rust. Some parts only have a python solution, and the
+    /// runner skips them.
+    fn has_part(&self, part: u32) -> bool {
+        matches!(part, 1 | 2)
+    }
+
+    /// Panics if `part` hasn't been solved in rust.
+    fn solve(&self, part: u32, input: &str) -> Result<Answer, ParseError> {
+        assert!(
+            self.has_part(part),
+            "Day {} part {part} isn't solved in rust",
+            self.day()
+        );
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => unreachable!("Unexpected puzzle part: {part}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(42), Answer::Int(42));
+        assert_eq!(Answer::from(42_usize), Answer::U128(42));
+        assert_eq!(Answer::from(42_i64), Answer::from(42_usize));
+        assert_ne!(Answer::from(-1), Answer::from(u128::MAX));
+        assert_ne!(Answer::from(42), Answer::from("42"));
+
+        assert!(Answer::from(-1) < Answer::from(0_u128));
+        assert!(Answer::from(u128::MAX) > Answer::from(i64::MAX));
+        assert_eq!(Answer::from("a").partial_cmp(&Answer::from("b")), None);
+
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+
+    #[test]
+    fn test_solve() {
+        struct Example;
+
+        impl Solution for Example {
+            fn day(&self) -> u32 {
+                1
+            }
+
+            fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+                Ok(input.len().into())
+            }
+
+            fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+                Ok(input.to_uppercase().into())
+            }
+
+            fn generate(&self, seed: u64, size: usize) -> String {
+                format!("{seed}").repeat(size)
+            }
+        }
+
+        assert_eq!(Example.solve(1, "abc"), Ok(Answer::from(3_usize)));
+        assert_eq!(Example.solve(2, "abc"), Ok(Answer::from("ABC")));
+        assert!(Example.has_part(2) && !Example.has_part(3));
+    }
+
+    #[test]
+    fn test_solve_unsolved_part() {
+        struct PythonOnly;
+
+        impl Solution for PythonOnly {
+            fn day(&self) -> u32 {
+                5
+            }
+
+            fn part1(&self, _input: &str) -> Result<Answer, ParseError> {
+                unreachable!()
+            }
+
+            fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+                Ok(input.len().into())
+            }
+
+            fn generate(&self, _seed: u64, _size: usize) -> String {
+                String::new()
+            }
+
+            fn has_part(&self, part: u32) -> bool {
+                part == 2
+            }
+        }
+
+        assert_eq!(PythonOnly.solve(2, "abc"), Ok(Answer::from(3_usize)));
+        let panic = std::panic::catch_unwind(|| PythonOnly.solve(1, "abc")).unwrap_err();
+        assert_eq!(
+            panic.downcast_ref::<String>().unwrap(),
+            "Day 5 part 1 isn't solved in rust"
+        );
+    }
+}
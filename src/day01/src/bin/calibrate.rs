@@ -0,0 +1,65 @@
+//! Sums the calibration values of a document of any size, reading it a buffer at a time, with the
+//! tokens of a vocabulary file instead of the english words for the digits.
+
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufReader},
+    path::PathBuf,
+    process,
+};
+
+use day01::matcher::{Matcher, Vocabulary};
+
+const USAGE: &str = "usage: day01-calibrate [--vocabulary <path>] [--input <path|->]";
+
+fn main() {
+    let mut vocabulary = None;
+    let mut input = aoc::input::default_path(env!("CARGO_MANIFEST_DIR"));
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .map(PathBuf::from)
+                .unwrap_or_else(|| exit(&format!("missing value for '{arg}'")))
+        };
+        match arg.as_str() {
+            "--vocabulary" => vocabulary = Some(value()),
+            "-i" | "--input" => input = value(),
+            _ => exit(&format!("unexpected argument '{arg}'")),
+        }
+    }
+
+    let vocabulary = match vocabulary {
+        Some(path) => {
+            let text = fs::read_to_string(&path)
+                .unwrap_or_else(|err| exit(&format!("failed to read '{}': {err}", path.display())));
+            Vocabulary::parse(&text)
+                .unwrap_or_else(|err| exit(&format!("{}: {err}", path.display())))
+        }
+        None => Vocabulary::english(),
+    };
+    let matcher = Matcher::new(&vocabulary);
+
+    let sum = match input.to_str() {
+        Some("-") => matcher.calibrate_reader(io::stdin().lock()),
+        _ => {
+            let file = File::open(&input).unwrap_or_else(|err| {
+                exit(&format!("failed to read '{}': {err}", input.display()))
+            });
+            matcher.calibrate_reader(BufReader::new(file))
+        }
+    };
+    match sum {
+        Ok(sum) => println!("Result: {sum}"),
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+fn exit(msg: &str) -> ! {
+    eprintln!("error: {msg}\n{USAGE}");
+    process::exit(1);
+}
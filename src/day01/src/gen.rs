@@ -0,0 +1,67 @@
+//! Random calibration documents.
+
+use aoc::random::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// A document of `size` lines mixing letters with digits and spelled out digits, which may
+/// overlap like in `twone`. Every line holds at least one real digit, since part 1 ignores the
+/// spelled out ones.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let pieces = rng.range(1..=6);
+        let digit = rng.range(0..pieces);
+        for i in 0..pieces {
+            let len = rng.range(1..=4);
+            match rng.range(0..3) {
+                _ if i == digit => input.push_str(&rng.range(1..=9).to_string()),
+                0 => input.push_str(&rng.string(len, "abcdefghijklmnopqrstuvwxyz")),
+                1 => input.push_str(WORDS[rng.range(0..WORDS.len())]),
+                _ => input.push_str(&rng.range(0..=9).to_string()),
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The calibration value of a line found by trying every position in turn.
+    fn calibration_value(line: &str, words: bool) -> i64 {
+        let digits: Vec<i64> = (0..line.len())
+            .filter_map(|i| {
+                let rest = &line[i..];
+                let digit = rest.chars().next()?.to_digit(10).map(|digit| digit as i64);
+                let word = (1..)
+                    .zip(WORDS)
+                    .find(|(_, word)| words && rest.starts_with(word));
+                digit.or(word.map(|(value, _)| value))
+            })
+            .collect();
+        digits[0] * 10 + digits[digits.len() - 1]
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..50 {
+            let input = input(seed, 20);
+            let sum = |words| {
+                input
+                    .lines()
+                    .map(|line| calibration_value(line, words))
+                    .sum()
+            };
+            assert_eq!(part1::process(&input).unwrap(), sum(false));
+            assert_eq!(part2::process(&input).unwrap(), sum(true));
+        }
+    }
+}
@@ -0,0 +1,26 @@
+pub mod gen;
+pub mod matcher;
+pub mod part1;
+pub mod part2;
+
+use aoc::{Answer, ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        part1::process(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        part2::process(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        gen::input(seed, size)
+    }
+}
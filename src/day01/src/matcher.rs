@@ -0,0 +1,384 @@
+//! Finds the first and the last digit of every line in a single pass over the document, with an
+//! Aho-Corasick automaton over the tokens that stand for a digit.
+
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, VecDeque},
+    fmt,
+    io::{self, BufRead},
+    str,
+};
+
+use aoc::{parse, ParseError};
+
+const WORDS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// How much of a line without a digit is shown in the error.
+const EXCERPT_LEN: usize = 40;
+
+/// The tokens that stand for a digit, with the digit they stand for.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Vocabulary {
+    tokens: Vec<(String, u8)>,
+}
+
+impl Vocabulary {
+    /// Only the digits themselves, as in part 1.
+    pub fn digits() -> Self {
+        let tokens = (0..=9).map(|digit| (digit.to_string(), digit)).collect();
+        Self { tokens }
+    }
+
+    /// The digits and the english words for them except for zero, as in part 2.
+    pub fn english() -> Self {
+        let mut vocabulary = Self::digits();
+        vocabulary.tokens.extend(
+            (1..)
+                .zip(&WORDS[1..])
+                .map(|(digit, word)| (word.to_string(), digit)),
+        );
+        vocabulary
+    }
+
+    /// Parses a vocabulary with a token and its digit on every line, separated by whitespace,
+    /// like `eins 1`.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let tokens = parse::lines(input)
+            .map(|line| {
+                let mut parts = line.text.split_whitespace();
+                let token = parts.next().unwrap();
+                let digit = parts.next().unwrap_or(&line.text[line.text.len()..]);
+                if let Some(extra) = parts.next() {
+                    return Err(line.error(extra, "end of line"));
+                }
+                match digit.parse() {
+                    Ok(value @ 0..=9) => Ok((token.to_owned(), value)),
+                    _ => Err(line.error(digit, "a digit")),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { tokens })
+    }
+}
+
+/// A deterministic automaton that is in the state of the longest token prefix that the text read
+/// so far ends with.
+pub struct Matcher {
+    next: Vec<[u32; 256]>,
+    /// The tokens that end in each state, as their length and digit.
+    ends: Vec<Vec<(usize, u8)>>,
+}
+
+impl Matcher {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        // The trie of the tokens.
+        let mut children: Vec<HashMap<u8, usize>> = vec![HashMap::new()];
+        let mut ends = vec![Vec::new()];
+        for (token, digit) in vocabulary.tokens.iter() {
+            let mut state = 0;
+            for &byte in token.as_bytes() {
+                state = match children[state].get(&byte) {
+                    Some(&child) => child,
+                    None => {
+                        let child = children.len();
+                        children.push(HashMap::new());
+                        ends.push(Vec::new());
+                        children[state].insert(byte, child);
+                        child
+                    }
+                };
+            }
+            ends[state].push((token.len(), *digit));
+        }
+
+        // Every missing edge follows the edge of the longest proper suffix that is in the trie,
+        // which is always closer to the root and so already complete in breadth first order.
+        let mut next = vec![[0; 256]; children.len()];
+        let mut fallback = vec![0; children.len()];
+        let mut queue: VecDeque<usize> = children[0].values().copied().collect();
+        for (&byte, &child) in children[0].iter() {
+            next[0][byte as usize] = child as u32;
+        }
+        while let Some(state) = queue.pop_front() {
+            let suffix_ends = ends[fallback[state]].clone();
+            ends[state].extend(suffix_ends);
+            next[state] = next[fallback[state]];
+            for (&byte, &child) in children[state].iter() {
+                fallback[child] = next[fallback[state]][byte as usize] as usize;
+                next[state][byte as usize] = child as u32;
+                queue.push_back(child);
+            }
+        }
+        Self { next, ends }
+    }
+
+    /// Sums the calibration values of a whole document.
+    pub fn calibrate(&self, input: &str) -> Result<i64, ParseError> {
+        let mut scan = Scan::new(self);
+        scan.feed(input.as_bytes())?;
+        scan.finish()
+    }
+
+    /// Sums the calibration values of a document read from `reader`, holding only a buffer of it
+    /// in memory at a time.
+    pub fn calibrate_reader(&self, mut reader: impl BufRead) -> Result<i64, Error> {
+        let mut scan = Scan::new(self);
+        loop {
+            let buf = reader.fill_buf().map_err(Error::Read)?;
+            if buf.is_empty() {
+                return scan.finish().map_err(Error::Parse);
+            }
+            scan.feed(buf).map_err(Error::Parse)?;
+            let len = buf.len();
+            reader.consume(len);
+        }
+    }
+}
+
+/// A token found in a line, by the byte offset of its start.
+#[derive(Clone, Copy)]
+struct Found {
+    start: usize,
+    len: usize,
+    digit: u8,
+}
+
+/// The progress through a document that is read in pieces.
+struct Scan<'a> {
+    matcher: &'a Matcher,
+    state: usize,
+    sum: i64,
+    /// The number of the current line, starting at 1.
+    line: usize,
+    /// The offset into the current line of the byte that is read next.
+    offset: usize,
+    /// The first bytes of the current line after its leading whitespace, for errors.
+    excerpt: Vec<u8>,
+    /// The bytes of a character at the start of the line that may still turn out to be
+    /// whitespace, as a read can end in the middle of a character.
+    partial: Vec<u8>,
+    /// The number of characters of leading whitespace, which can be any unicode whitespace like
+    /// the lines that [`parse::lines`] trims.
+    leading: usize,
+    first: Option<Found>,
+    last: Option<Found>,
+}
+
+impl<'a> Scan<'a> {
+    fn new(matcher: &'a Matcher) -> Self {
+        Self {
+            matcher,
+            state: 0,
+            sum: 0,
+            line: 1,
+            offset: 0,
+            excerpt: Vec::new(),
+            partial: Vec::new(),
+            leading: 0,
+            first: None,
+            last: None,
+        }
+    }
+
+    fn feed(&mut self, bytes: &[u8]) -> Result<(), ParseError> {
+        for &byte in bytes {
+            if byte == b'\n' {
+                self.end_line()?;
+                continue;
+            }
+
+            if self.excerpt.is_empty() {
+                self.partial.push(byte);
+                match str::from_utf8(&self.partial) {
+                    Ok(ch) if ch.chars().all(char::is_whitespace) => {
+                        self.leading += 1;
+                        self.partial.clear();
+                    }
+                    // The rest of the character is still to come.
+                    Err(err) if err.error_len().is_none() => {}
+                    _ => self.excerpt.append(&mut self.partial),
+                }
+            } else if self.excerpt.len() <= EXCERPT_LEN {
+                self.excerpt.push(byte);
+            }
+            self.state = self.matcher.next[self.state][byte as usize] as usize;
+            for &(len, digit) in self.matcher.ends[self.state].iter() {
+                // Of two tokens starting at the same byte, the longer one wins.
+                let found = Found {
+                    start: self.offset + 1 - len,
+                    len,
+                    digit,
+                };
+                if self.first.is_none_or(|first| {
+                    (found.start, Reverse(found.len)) < (first.start, Reverse(first.len))
+                }) {
+                    self.first = Some(found);
+                }
+                if self
+                    .last
+                    .is_none_or(|last| (found.start, found.len) > (last.start, last.len))
+                {
+                    self.last = Some(found);
+                }
+            }
+            self.offset += 1;
+        }
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> Result<(), ParseError> {
+        self.excerpt.append(&mut self.partial);
+        let excerpt = String::from_utf8_lossy(&self.excerpt);
+        let text = excerpt.trim_end();
+        match (self.first, self.last) {
+            (Some(first), Some(last)) => self.sum += first.digit as i64 * 10 + last.digit as i64,
+            _ if text.is_empty() => {}
+            _ => {
+                let found = match self.excerpt.len() > EXCERPT_LEN {
+                    true => format!(
+                        "'{}...'",
+                        text.chars().take(EXCERPT_LEN).collect::<String>()
+                    ),
+                    false => format!("'{text}'"),
+                };
+                return Err(ParseError::new(
+                    self.line,
+                    self.leading + 1,
+                    "a digit",
+                    found,
+                ));
+            }
+        }
+
+        *self = Self {
+            sum: self.sum,
+            line: self.line + 1,
+            ..Self::new(self.matcher)
+        };
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<i64, ParseError> {
+        self.end_line()?;
+        Ok(self.sum)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Read(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Read(err) => write!(f, "failed to read the document: {err}"),
+            Error::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::BufReader;
+
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    fn first_and_last(matcher: &Matcher, line: &str) -> Option<(u8, u8)> {
+        let mut scan = Scan::new(matcher);
+        scan.feed(line.as_bytes()).unwrap();
+        Some((scan.first?.digit, scan.last?.digit))
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let matcher = Matcher::new(&Vocabulary::english());
+        assert_eq!(first_and_last(&matcher, "eightwo"), Some((8, 2)));
+        assert_eq!(first_and_last(&matcher, "xtwone3four"), Some((2, 4)));
+        assert_eq!(first_and_last(&matcher, "sevenine"), Some((7, 9)));
+        assert_eq!(first_and_last(&matcher, "oneight"), Some((1, 8)));
+        assert_eq!(first_and_last(&matcher, "zero"), None);
+
+        let matcher = Matcher::new(&Vocabulary::digits());
+        assert_eq!(first_and_last(&matcher, "eightwo"), None);
+        assert_eq!(first_and_last(&matcher, "a1b"), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_longer_token_wins() {
+        let vocabulary = Vocabulary::parse("on 7\none 1\nn 5").unwrap();
+        let matcher = Matcher::new(&vocabulary);
+        assert_eq!(first_and_last(&matcher, "xone"), Some((1, 5)));
+        assert_eq!(first_and_last(&matcher, "onx"), Some((7, 5)));
+    }
+
+    #[test]
+    fn test_vocabulary() {
+        let input = "
+            null 0
+            eins 1
+            zwei 2
+            drei 3
+            vier 4
+            fünf 5
+            sechs 6
+            sieben 7
+            acht 8
+            neun 9
+        ";
+        let matcher = Matcher::new(&Vocabulary::parse(&dedent(input)).unwrap());
+        assert_eq!(matcher.calibrate("xzweinsx\nfünfnullacht\n"), Ok(21 + 58));
+
+        let err = Vocabulary::parse("eins 1\nzehn 10").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a digit, found '10'"
+        );
+        let err = Vocabulary::parse("eins").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected a digit, found end of line"
+        );
+        assert!(Vocabulary::parse("eins 1 2").is_err());
+    }
+
+    #[test]
+    fn test_calibrate_reader() {
+        let input = crate::gen::input(3, 500);
+        let matcher = Matcher::new(&Vocabulary::english());
+        // A tiny buffer splits lines and tokens between reads.
+        let reader = BufReader::with_capacity(3, input.as_bytes());
+        assert_eq!(
+            matcher.calibrate_reader(reader).unwrap(),
+            matcher.calibrate(&input).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let matcher = Matcher::new(&Vocabulary::english());
+        let err = matcher.calibrate("1\n\n  abc \n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: expected a digit, found 'abc'"
+        );
+
+        // Unicode whitespace counts as one column, like in the errors of other days.
+        let err = matcher.calibrate("\u{a0}\u{3000} abc").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 4, "a digit", "'abc'"));
+        let reader = BufReader::with_capacity(1, "1\n\u{3000}\u{a0}é".as_bytes());
+        match matcher.calibrate_reader(reader).unwrap_err() {
+            Error::Parse(err) => assert_eq!(err, ParseError::new(2, 3, "a digit", "'é'")),
+            err => panic!("unexpected error {err}"),
+        }
+
+        let line = "x".repeat(100);
+        let err = matcher.calibrate(&line).unwrap_err();
+        assert_eq!(err.found, format!("'{}...'", &line[..EXCERPT_LEN]));
+    }
+}
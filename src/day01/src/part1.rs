@@ -0,0 +1,21 @@
+use aoc::{parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let mut digits = line.text.chars().filter_map(|ch| ch.to_digit(10));
+            let first_digit = digits
+                .next()
+                .ok_or_else(|| line.error(line.text, "a digit"))?;
+            let last_digit = digits.next_back().unwrap_or(first_digit);
+            Ok((first_digit * 10 + last_digit) as i64)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example1);
+}
@@ -0,0 +1,14 @@
+use aoc::ParseError;
+
+use crate::matcher::{Matcher, Vocabulary};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    Matcher::new(&Vocabulary::english()).calibrate(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example2);
+}
@@ -0,0 +1,199 @@
+//! The records of the games played with a bag of cubes, and the questions they can answer.
+
+use std::collections::BTreeMap;
+
+use aoc::{
+    parse::{self, Line},
+    ParseError,
+};
+
+/// The colours of the cubes in the puzzle.
+pub const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// The number of cubes of each colour in a bag.
+pub type Bag<'a> = BTreeMap<&'a str, u32>;
+
+/// A handful of cubes shown from the bag, as their colours and counts.
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct Draw<'a> {
+    pub cubes: Vec<(&'a str, u32)>,
+}
+
+impl Draw<'_> {
+    /// The number of cubes of `color` that were shown.
+    pub fn count(&self, color: &str) -> u32 {
+        self.cubes
+            .iter()
+            .filter(|(name, _)| *name == color)
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Game<'a> {
+    pub id: u32,
+    pub draws: Vec<Draw<'a>>,
+    /// The line the game was read from, to point errors at it.
+    pub line: Line<'a>,
+}
+
+impl<'a> Game<'a> {
+    /// Parses a line like `Game 1: 3 blue, 4 red; 1 red, 2 green`, with cubes of any colour.
+    pub fn parse(line: Line<'a>) -> Result<Self, ParseError> {
+        let (id, draws) = line.split_once(line.text, ":")?;
+        let id = line.number(line.strip_prefix(id.trim(), "Game ")?)?;
+        let draws = draws
+            .split(';')
+            .map(|draw| {
+                let cubes = draw
+                    .split(',')
+                    .map(|cubes| {
+                        let (count, color) = line.split_once(cubes.trim(), " ")?;
+                        Ok((color, line.number(count)?))
+                    })
+                    .collect::<Result<_, ParseError>>()?;
+                Ok(Draw { cubes })
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self { id, draws, line })
+    }
+
+    /// Checks that every cube shown has one of the `colors`.
+    pub fn check_colors(&self, colors: &[&str]) -> Result<(), ParseError> {
+        let unknown = self
+            .draws
+            .iter()
+            .flat_map(|draw| draw.cubes.iter())
+            .find(|(color, _)| !colors.contains(color));
+        match unknown {
+            Some((color, _)) => Err(self.line.error(color, expectation(colors))),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether `bag` holds enough cubes for every draw, where a colour missing from the bag has
+    /// no cubes.
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.minimum_bag()
+            .iter()
+            .all(|(color, &count)| count <= bag.get(color).copied().unwrap_or(0))
+    }
+
+    /// The fewest cubes of each colour that the bag must have held, which is the most that were
+    /// shown at once.
+    pub fn minimum_bag(&self) -> Bag<'a> {
+        let mut bag = Bag::new();
+        for draw in self.draws.iter() {
+            for &(color, _) in draw.cubes.iter() {
+                let count = bag.entry(color).or_default();
+                *count = (*count).max(draw.count(color));
+            }
+        }
+        bag
+    }
+
+    /// The product of the fewest cubes of each of the `colors`, which is zero if one of them was
+    /// never shown.
+    pub fn power(&self, colors: &[&str]) -> u64 {
+        let bag = self.minimum_bag();
+        colors
+            .iter()
+            .map(|color| bag.get(color).copied().unwrap_or(0) as u64)
+            .product()
+    }
+}
+
+/// Parses every game of the record.
+pub fn parse(input: &str) -> Result<Vec<Game<'_>>, ParseError> {
+    parse::lines(input).map(Game::parse).collect()
+}
+
+/// The games that need the most cubes of `color`, in the order of the record.
+pub fn most_of<'g, 'a>(games: &'g [Game<'a>], color: &str) -> Vec<&'g Game<'a>> {
+    let needed = |game: &Game| game.minimum_bag().get(color).copied().unwrap_or(0);
+    let most = games.iter().map(needed).max().unwrap_or(0);
+    games.iter().filter(|game| needed(game) == most).collect()
+}
+
+/// Formats the colours as an expectation, e.g. `'red', 'green' or 'blue'`.
+fn expectation(colors: &[&str]) -> String {
+    let quoted: Vec<String> = colors.iter().map(|color| format!("'{color}'")).collect();
+    match quoted.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+        None => "no cubes".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    fn record() -> String {
+        dedent(
+            "
+            Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 7: 5 purple, 1 blue; 6 purple
+            ",
+        )
+    }
+
+    #[test]
+    fn test_parse() {
+        let record = record();
+        let games = parse(&record).unwrap();
+        assert_eq!(
+            games.iter().map(|game| game.id).collect::<Vec<_>>(),
+            [1, 2, 7]
+        );
+        assert_eq!(games[0].draws.len(), 3);
+        assert_eq!(
+            games[0].draws[1].cubes,
+            [("red", 1), ("green", 2), ("blue", 6)]
+        );
+        assert_eq!(games[0].draws[2].count("red"), 0);
+
+        let err = parse("Game 1: 3 blue, four red").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 17, "a number", "'four'"));
+        assert!(parse("Game one: 3 blue").is_err());
+    }
+
+    #[test]
+    fn test_queries() {
+        let record = record();
+        let games = parse(&record).unwrap();
+        let bag = Bag::from([("red", 4), ("green", 3), ("blue", 6)]);
+        assert!(games[0].is_possible(&bag) && games[1].is_possible(&bag));
+        assert!(!games[2].is_possible(&bag));
+
+        assert_eq!(
+            games[1].minimum_bag(),
+            Bag::from([("red", 1), ("green", 3), ("blue", 4)])
+        );
+        assert_eq!(games[1].power(&["red", "green", "blue"]), 12);
+        assert_eq!(games[2].power(&["red", "green", "blue"]), 0);
+        assert_eq!(games[2].power(&["purple"]), 6);
+
+        let ids = |games: Vec<&Game>| games.iter().map(|game| game.id).collect::<Vec<_>>();
+        assert_eq!(ids(most_of(&games, "blue")), [1]);
+        assert_eq!(ids(most_of(&games, "green")), [2]);
+        assert_eq!(ids(most_of(&games, "orange")), [1, 2, 7]);
+    }
+
+    #[test]
+    fn test_check_colors() {
+        let record = record();
+        let games = parse(&record).unwrap();
+        let colors = ["red", "green", "blue"];
+        assert!(games[0].check_colors(&colors).is_ok());
+        assert_eq!(
+            games[2].check_colors(&colors).unwrap_err(),
+            ParseError::new(3, 11, "'red', 'green' or 'blue'", "'purple'")
+        );
+        assert_eq!(expectation(&["red"]), "'red'");
+    }
+}
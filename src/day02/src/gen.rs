@@ -0,0 +1,73 @@
+//! Random records of games with the bag of cubes.
+
+use aoc::random::Rng;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// `size` games of up to 6 draws each, showing up to 20 cubes of each colour, so that about half
+/// the games are possible with the bag of part 1.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+
+    for game in 1..=size.max(1) {
+        let draws: Vec<String> = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colors = COLORS;
+                rng.shuffle(&mut colors);
+                let count = rng.range(1..=3);
+                let cubes: Vec<String> = colors[..count]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.range(1..=20)))
+                    .collect();
+                cubes.join(", ")
+            })
+            .collect();
+        input.push_str(&format!("Game {game}: {}\n", draws.join("; ")));
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The most cubes of each colour shown at once in every game.
+    fn fewest_cubes(input: &str) -> Vec<[i64; 3]> {
+        input
+            .lines()
+            .map(|line| {
+                let mut fewest = [0; 3];
+                let (_, draws) = line.split_once(": ").unwrap();
+                for cubes in draws.split("; ").flat_map(|draw| draw.split(", ")) {
+                    let (count, color) = cubes.split_once(' ').unwrap();
+                    let i = COLORS.iter().position(|&name| name == color).unwrap();
+                    fewest[i] = fewest[i].max(count.parse().unwrap());
+                }
+                fewest
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..50 {
+            let input = input(seed, 30);
+            let fewest = fewest_cubes(&input);
+
+            let possible = (1..)
+                .zip(fewest.iter())
+                .filter(|(_, [red, green, blue])| *red <= 12 && *green <= 13 && *blue <= 14)
+                .map(|(game, _)| game)
+                .sum();
+            assert_eq!(part1::process(&input).unwrap(), possible);
+
+            let power = fewest
+                .iter()
+                .map(|cubes| cubes.iter().product::<i64>())
+                .sum();
+            assert_eq!(part2::process(&input).unwrap(), power);
+        }
+    }
+}
@@ -0,0 +1,369 @@
+//! Estimates what the bag of a game held from the draws that were shown, rather than only the
+//! fewest cubes it could have held.
+//!
+//! Every bag with up to a given number of cubes of each colour is equally likely beforehand, and
+//! each draw takes a handful of cubes from the full bag, which are put back before the next one.
+
+use aoc::random::Rng;
+
+use crate::game::{Bag, Draw, Game};
+
+/// The most candidate bags [`Posterior::infer`] weighs, such as up to 99 cubes of each of three
+/// colours. Each bag is weighed against every draw and kept with its counts in memory.
+pub const MAX_BAGS: u64 = 1_000_000;
+
+/// How the cubes of a single draw are taken from the bag.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Model {
+    /// Each cube is put back before the next one is taken, so a cube can be shown more than once.
+    WithReplacement,
+    /// The cubes of a handful are taken at once, so a draw never shows more cubes of a colour
+    /// than the bag holds.
+    WithoutReplacement,
+}
+
+/// The probability of every candidate bag of a game given its draws.
+#[derive(Clone, Debug)]
+pub struct Posterior<'a> {
+    pub colors: Vec<&'a str>,
+    /// The bags that could have produced the draws, with the counts in the order of `colors`, and
+    /// their probabilities, which add up to 1.
+    pub bags: Vec<(Vec<u32>, f64)>,
+}
+
+impl<'a> Posterior<'a> {
+    /// Weighs every bag with up to `max` cubes of each colour by how likely it makes the draws of
+    /// `game`. The colours are `colors` followed by any other colour the game shows, so there are
+    /// `(max + 1)` to the power of their number bags to weigh.
+    ///
+    /// Returns `None` if that is more than [`MAX_BAGS`], or if no such bag could have produced
+    /// the draws.
+    pub fn infer(game: &Game<'a>, colors: &[&'a str], max: u32, model: Model) -> Option<Self> {
+        let mut colors = colors.to_vec();
+        for color in game.minimum_bag().into_keys() {
+            if !colors.contains(&color) {
+                colors.push(color);
+            }
+        }
+        let candidates = (max as u64 + 1).checked_pow(colors.len() as u32);
+        if candidates.is_none_or(|candidates| candidates > MAX_BAGS) {
+            return None;
+        }
+        let draws: Vec<Vec<u32>> = game
+            .draws
+            .iter()
+            .map(|draw| colors.iter().map(|color| draw.count(color)).collect())
+            .collect();
+
+        let ln_factorials = LnFactorials::new(max as usize * colors.len());
+        let mut bags = Vec::new();
+        let mut bag = vec![0; colors.len()];
+        loop {
+            let ln_likelihood = draws
+                .iter()
+                .map(|draw| ln_factorials.ln_likelihood(&bag, draw, model))
+                .sum::<f64>();
+            if ln_likelihood.is_finite() {
+                bags.push((bag.clone(), ln_likelihood));
+            }
+            // The next bag, counting in base `max + 1`.
+            match bag.iter().position(|&count| count < max) {
+                Some(i) => {
+                    bag[i] += 1;
+                    bag[..i].fill(0);
+                }
+                None => break,
+            }
+        }
+
+        // Normalises in log space, as the likelihood of many draws is too small for an `f64`.
+        let most = bags
+            .iter()
+            .map(|(_, ln)| *ln)
+            .fold(f64::NEG_INFINITY, f64::max);
+        if most == f64::NEG_INFINITY {
+            return None;
+        }
+        let total: f64 = bags.iter().map(|(_, ln)| (ln - most).exp()).sum();
+        for (_, p) in bags.iter_mut() {
+            *p = (*p - most).exp() / total;
+        }
+        Some(Self { colors, bags })
+    }
+
+    /// The probability of exactly `bag`, where a colour missing from it has no cubes.
+    pub fn probability(&self, bag: &Bag) -> f64 {
+        let counts = self.counts(bag);
+        self.bags
+            .iter()
+            .find(|(candidate, _)| *candidate == counts)
+            .map_or(0.0, |(_, p)| *p)
+    }
+
+    /// The most likely bag and its probability, preferring the smallest bag on a tie.
+    pub fn most_likely(&self) -> (Bag<'a>, f64) {
+        let (counts, p) = self
+            .bags
+            .iter()
+            .max_by(|(a, p), (b, q)| {
+                p.total_cmp(q)
+                    .then_with(|| b.iter().sum::<u32>().cmp(&a.iter().sum()))
+            })
+            .unwrap();
+        (self.bag(counts), *p)
+    }
+
+    /// The expected number of cubes of `color`.
+    pub fn mean(&self, color: &str) -> f64 {
+        self.marginal(color)
+            .iter()
+            .enumerate()
+            .map(|(count, p)| count as f64 * p)
+            .sum()
+    }
+
+    /// The smallest and largest number of cubes of `color` between which the bag holds with
+    /// probability `confidence`, leaving out the same probability below and above.
+    pub fn bounds(&self, color: &str, confidence: f64) -> (u32, u32) {
+        let tail = (1.0 - confidence) / 2.0;
+        let marginal = self.marginal(color);
+        let mut cumulative = 0.0;
+        let mut lower = None;
+        for (count, p) in marginal.iter().enumerate() {
+            cumulative += p;
+            if lower.is_none() && cumulative > tail {
+                lower = Some(count as u32);
+            }
+            if cumulative >= 1.0 - tail - 1e-12 {
+                return (lower.unwrap_or(count as u32), count as u32);
+            }
+        }
+        let last = marginal.len().saturating_sub(1) as u32;
+        (lower.unwrap_or(last), last)
+    }
+
+    /// The probability of each number of cubes of `color`.
+    fn marginal(&self, color: &str) -> Vec<f64> {
+        let mut marginal = Vec::new();
+        let Some(i) = self.colors.iter().position(|&name| name == color) else {
+            return vec![1.0];
+        };
+        for (bag, p) in self.bags.iter() {
+            let count = bag[i] as usize;
+            if marginal.len() <= count {
+                marginal.resize(count + 1, 0.0);
+            }
+            marginal[count] += p;
+        }
+        marginal
+    }
+
+    fn counts(&self, bag: &Bag) -> Vec<u32> {
+        self.colors
+            .iter()
+            .map(|color| bag.get(color).copied().unwrap_or(0))
+            .collect()
+    }
+
+    fn bag(&self, counts: &[u32]) -> Bag<'a> {
+        self.colors
+            .iter()
+            .copied()
+            .zip(counts.iter().copied())
+            .collect()
+    }
+}
+
+/// The natural logarithms of the factorials up to a limit.
+struct LnFactorials(Vec<f64>);
+
+impl LnFactorials {
+    fn new(limit: usize) -> Self {
+        let mut ln = vec![0.0; limit + 1];
+        for n in 1..=limit {
+            ln[n] = ln[n - 1] + (n as f64).ln();
+        }
+        Self(ln)
+    }
+
+    fn ln_choose(&self, n: u32, k: u32) -> f64 {
+        match k <= n {
+            true => self.0[n as usize] - self.0[k as usize] - self.0[(n - k) as usize],
+            false => f64::NEG_INFINITY,
+        }
+    }
+
+    /// The logarithm of the probability that a handful of the size of `draw` from `bag` shows
+    /// exactly the counts of `draw`.
+    fn ln_likelihood(&self, bag: &[u32], draw: &[u32], model: Model) -> f64 {
+        let total: u32 = bag.iter().sum();
+        let handful: u32 = draw.iter().sum();
+        match model {
+            Model::WithoutReplacement => {
+                bag.iter()
+                    .zip(draw)
+                    .map(|(&n, &k)| self.ln_choose(n, k))
+                    .sum::<f64>()
+                    - self.ln_choose(total, handful)
+            }
+            Model::WithReplacement => {
+                if handful == 0 {
+                    return 0.0;
+                } else if total == 0 {
+                    return f64::NEG_INFINITY;
+                }
+                let mut ln = self.ln_factorial(handful);
+                for (&n, &k) in bag.iter().zip(draw) {
+                    if k > 0 {
+                        ln += k as f64 * (n as f64 / total as f64).ln() - self.ln_factorial(k);
+                    }
+                }
+                ln
+            }
+        }
+    }
+
+    /// Handfuls with replacement can be larger than any bag, so those factorials are computed.
+    fn ln_factorial(&self, n: u32) -> f64 {
+        match self.0.get(n as usize) {
+            Some(&ln) => ln,
+            None => (1..=n).map(|i| (i as f64).ln()).sum(),
+        }
+    }
+}
+
+/// Takes `draws` handfuls of `handful` cubes from `bag` under `model`, putting them back after
+/// each draw. The bag has to hold at least `handful` cubes without replacement, and at least one
+/// with it.
+pub fn simulate<'a>(
+    rng: &mut Rng,
+    bag: &Bag<'a>,
+    handful: u32,
+    draws: usize,
+    model: Model,
+) -> Vec<Draw<'a>> {
+    let cubes: Vec<&str> = bag
+        .iter()
+        .flat_map(|(&color, &count)| (0..count).map(move |_| color))
+        .collect();
+    (0..draws)
+        .map(|_| {
+            let mut shown = Bag::new();
+            match model {
+                Model::WithReplacement => {
+                    for _ in 0..handful {
+                        *shown.entry(*rng.pick(&cubes)).or_default() += 1;
+                    }
+                }
+                Model::WithoutReplacement => {
+                    let mut cubes = cubes.clone();
+                    rng.shuffle(&mut cubes);
+                    for &color in cubes[..handful as usize].iter() {
+                        *shown.entry(color).or_default() += 1;
+                    }
+                }
+            }
+            Draw {
+                cubes: shown.into_iter().collect(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::game::COLORS;
+
+    fn game(draws: Vec<Draw>) -> Game {
+        let mut game = crate::game::parse("Game 1: 0 red").unwrap().remove(0);
+        game.draws = draws;
+        game
+    }
+
+    #[test]
+    fn test_small_bag() {
+        let game = game(vec![Draw {
+            cubes: vec![("red", 1)],
+        }]);
+        let colors = ["red", "blue"];
+
+        // Of the bags with up to one cube of each colour, one red cube is as likely as a red and
+        // a blue one would be to give the red one, which is half as likely.
+        let posterior = Posterior::infer(&game, &colors, 1, Model::WithoutReplacement).unwrap();
+        assert_eq!(posterior.bags.len(), 2);
+        let one_red = Bag::from([("red", 1)]);
+        assert!((posterior.probability(&one_red) - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(posterior.probability(&Bag::from([("blue", 1)])), 0.0);
+        assert_eq!(
+            posterior.most_likely().0,
+            Bag::from([("red", 1), ("blue", 0)])
+        );
+        assert_eq!(posterior.bounds("red", 0.9), (1, 1));
+        assert_eq!(posterior.bounds("blue", 0.9), (0, 1));
+        assert!((posterior.mean("blue") - 1.0 / 3.0).abs() < 1e-9);
+
+        // Two red cubes at once need a bag with two of them.
+        let game = self::game(vec![Draw {
+            cubes: vec![("red", 2)],
+        }]);
+        assert!(Posterior::infer(&game, &colors, 1, Model::WithoutReplacement).is_none());
+        let posterior = Posterior::infer(&game, &colors, 1, Model::WithReplacement).unwrap();
+        assert!((posterior.probability(&one_red) - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_too_many_bags() {
+        let game = game(vec![Draw {
+            cubes: vec![("red", 1)],
+        }]);
+        // Up to 100 cubes of each of three colours are 101 * 101 * 101 bags.
+        assert!(Posterior::infer(&game, &COLORS, 100, Model::WithReplacement).is_none());
+        assert!(Posterior::infer(&game, &["red"], 100, Model::WithReplacement).is_some());
+        assert!(Posterior::infer(&game, &COLORS, u32::MAX, Model::WithReplacement).is_none());
+    }
+
+    #[test]
+    fn test_respects_minimum_bag() {
+        let record = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let games = crate::game::parse(record).unwrap();
+        let posterior =
+            Posterior::infer(&games[0], &COLORS, 10, Model::WithoutReplacement).unwrap();
+        let total: f64 = posterior.bags.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        let fewest = posterior.counts(&games[0].minimum_bag());
+        for (bag, _) in posterior.bags.iter() {
+            assert!(bag.iter().zip(fewest.iter()).all(|(n, least)| n >= least));
+        }
+        assert!(posterior.bounds("red", 0.99).0 >= 4);
+    }
+
+    #[test]
+    fn test_simulations() {
+        let truth = Bag::from([("red", 6), ("green", 2), ("blue", 4)]);
+        for model in [Model::WithReplacement, Model::WithoutReplacement] {
+            let mut covered = 0;
+            for seed in 0..40 {
+                let draws = simulate(&mut Rng::new(seed), &truth, 5, 40, model);
+                let posterior = Posterior::infer(&game(draws), &COLORS, 12, model).unwrap();
+                covered += COLORS
+                    .iter()
+                    .filter(|color| {
+                        let (lower, upper) = posterior.bounds(color, 0.9);
+                        (lower..=upper).contains(&truth[*color])
+                    })
+                    .count();
+            }
+            // The 90% bounds should hold the true counts about 90% of the time.
+            let coverage = covered as f64 / (40 * COLORS.len()) as f64;
+            assert!(coverage >= 0.8, "{model:?} covered {coverage}");
+        }
+
+        // Without replacement, many draws pin the bag down.
+        let draws = simulate(&mut Rng::new(1), &truth, 10, 200, Model::WithoutReplacement);
+        let posterior =
+            Posterior::infer(&game(draws), &COLORS, 12, Model::WithoutReplacement).unwrap();
+        assert_eq!(posterior.most_likely().0, truth);
+    }
+}
@@ -0,0 +1,27 @@
+pub mod game;
+pub mod gen;
+pub mod infer;
+pub mod part1;
+pub mod part2;
+
+use aoc::{Answer, ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        part1::process(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        part2::process(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        gen::input(seed, size)
+    }
+}
@@ -0,0 +1,34 @@
+use aoc::ParseError;
+
+use crate::game::{self, Bag, COLORS};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let bag = Bag::from([("red", 12), ("green", 13), ("blue", 14)]);
+    let mut sum = 0;
+
+    for game in game::parse(input)? {
+        game.check_colors(&COLORS)?;
+        if game.is_possible(&bag) {
+            sum += game.id as i64;
+        }
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+
+    #[test]
+    fn test_parse_error() {
+        let err = process("Game 1: 3 blue, 4 red\nGame 2: 1 blue; 2 grene").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 19, "'red', 'green' or 'blue'", "'grene'")
+        );
+        assert!(process("Game 1: 3 blue, 4 red\nGame 2 1 blue").is_err());
+        assert!(process("Game 1: 3 blue, red").is_err());
+    }
+}
@@ -0,0 +1,20 @@
+use aoc::ParseError;
+
+use crate::game::{self, COLORS};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut sum = 0;
+
+    for game in game::parse(input)? {
+        game.check_colors(&COLORS)?;
+        sum += game.power(&COLORS) as i64;
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+}
@@ -0,0 +1,63 @@
+//! Shows a schematic with its part numbers, other numbers and gears in different colours, either
+//! in the terminal or as an HTML page, with a gear rule chosen on the command line.
+
+use std::{env, process};
+
+use aoc::input::Source;
+use day03::{
+    schematic::{Aggregate, GearRule, Schematic},
+    visualize,
+};
+
+const USAGE: &str = "usage: day03-gears [--symbols <chars>] [--radius <n>] \
+    [--neighbours <n|min-max>] [--aggregate product|sum|max] [--html] [--input <path|->]";
+
+fn main() {
+    let mut rule = GearRule::default();
+    let mut html = false;
+    let mut source = Source::File(aoc::input::default_path(env!("CARGO_MANIFEST_DIR")));
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .unwrap_or_else(|| exit(&format!("missing value for '{arg}'")))
+        };
+        match arg.as_str() {
+            "--symbols" => rule.symbols = value().chars().collect(),
+            "--radius" => rule.radius = number(&value(), "radius"),
+            "--neighbours" => {
+                let value = value();
+                let (min, max) = value.split_once('-').unwrap_or((&value, &value));
+                rule.neighbours = number(min, "neighbours")..=number(max, "neighbours");
+            }
+            "--aggregate" => {
+                let value = value();
+                rule.aggregate = Aggregate::from_arg(&value)
+                    .unwrap_or_else(|| exit(&format!("invalid aggregate '{value}'")));
+            }
+            "--html" => html = true,
+            "-i" | "--input" => source = Source::from_arg(&value()),
+            _ => exit(&format!("unexpected argument '{arg}'")),
+        }
+    }
+
+    let input = source.read().unwrap_or_else(|err| exit(&err.to_string()));
+    let schematic = Schematic::parse(&input).unwrap_or_else(|err| {
+        eprintln!("error: {source}: {err}");
+        process::exit(1);
+    });
+    match html {
+        true => print!("{}", visualize::html(&schematic, &rule)),
+        false => print!("{}", visualize::frame(&schematic, &rule)),
+    }
+}
+
+fn number(arg: &str, what: &str) -> usize {
+    arg.parse()
+        .unwrap_or_else(|_| exit(&format!("invalid {what} '{arg}'")))
+}
+
+fn exit(msg: &str) -> ! {
+    eprintln!("error: {msg}\n{USAGE}");
+    process::exit(1);
+}
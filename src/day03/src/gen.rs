@@ -0,0 +1,105 @@
+//! Random engine schematics.
+
+use aoc::random::Rng;
+
+const SYMBOLS: &str = "*#+$/@=%&-";
+
+/// A schematic of `size` by `size` characters, with numbers of up to three digits on every row
+/// and symbols scattered between them. Gears are common, since a third of the symbols are `*`.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(3);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut row = vec!['.'; size];
+        let mut x = rng.range(0..3);
+        loop {
+            let len = rng.range(1..=3);
+            if x + len > size {
+                break;
+            }
+            row[x] = char::from(b'0' + rng.range(1..=9));
+            for ch in row[x + 1..x + len].iter_mut() {
+                *ch = char::from(b'0' + rng.range(0..=9));
+            }
+            x += len + rng.range(1..=4);
+        }
+
+        for ch in row.iter_mut().filter(|ch| **ch == '.') {
+            if rng.chance(0.1) {
+                *ch = match rng.chance(0.3) {
+                    true => '*',
+                    false => *rng.pick(&SYMBOLS.chars().collect::<Vec<_>>()),
+                };
+            }
+        }
+        input.extend(row);
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    /// Every number with its row and the columns it spans.
+    fn numbers(rows: &[&[u8]]) -> Vec<(usize, usize, usize, i64)> {
+        let mut numbers = Vec::new();
+        for (y, row) in rows.iter().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let len = row[x..].iter().take_while(|ch| ch.is_ascii_digit()).count();
+                if len > 0 {
+                    let value = std::str::from_utf8(&row[x..x + len]).unwrap();
+                    numbers.push((y, x, x + len, value.parse().unwrap()));
+                }
+                x += len.max(1);
+            }
+        }
+        numbers
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..30 {
+            let input = input(seed, 25);
+            let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+            let numbers = numbers(&rows);
+            let is_symbol = |ch: u8| !ch.is_ascii_digit() && ch != b'.';
+            // The numbers next to the symbol at `(x, y)`.
+            let adjacent = |x: usize, y: usize| {
+                numbers.iter().filter(move |&&(row, start, end, _)| {
+                    row.abs_diff(y) <= 1 && start <= x + 1 && x <= end
+                })
+            };
+
+            let mut part_numbers = 0;
+            let mut gear_ratios = 0;
+            for (y, row) in rows.iter().enumerate() {
+                for (x, &ch) in row.iter().enumerate() {
+                    if ch == b'*' {
+                        let gears: Vec<i64> = adjacent(x, y).map(|number| number.3).collect();
+                        if let [a, b] = gears[..] {
+                            gear_ratios += a * b;
+                        }
+                    }
+                }
+            }
+            for &(y, start, end, value) in numbers.iter() {
+                let near_symbol = (y.saturating_sub(1)..(y + 2).min(rows.len())).any(|y| {
+                    (start.saturating_sub(1)..(end + 1).min(rows[y].len()))
+                        .any(|x| is_symbol(rows[y][x]))
+                });
+                if near_symbol {
+                    part_numbers += value;
+                }
+            }
+
+            assert_eq!(part1::process(&input).unwrap(), part_numbers);
+            assert_eq!(part2::process(&input).unwrap(), gear_ratios);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+pub mod gen;
+pub mod part1;
+pub mod part2;
+pub mod schematic;
+pub mod visualize;
+
+use aoc::{Answer, ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        part1::process(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        part2::process(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        gen::input(seed, size)
+    }
+}
@@ -0,0 +1,18 @@
+use aoc::ParseError;
+
+use crate::schematic::Schematic;
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let schematic = Schematic::parse(input)?;
+    Ok(schematic
+        .part_numbers()
+        .map(|number| number.value as i64)
+        .sum())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example1);
+}
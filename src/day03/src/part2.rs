@@ -0,0 +1,19 @@
+use aoc::ParseError;
+
+use crate::schematic::{GearRule, Schematic};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let schematic = Schematic::parse(input)?;
+    let rule = GearRule::default();
+    Ok(schematic
+        .gears(&rule)
+        .map(|(_, numbers)| rule.aggregate.apply(&numbers) as i64)
+        .sum())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example2);
+}
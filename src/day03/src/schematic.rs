@@ -0,0 +1,364 @@
+//! An index of the numbers and symbols of an engine schematic, which answers which of them are
+//! next to each other.
+
+use std::ops::{Range, RangeInclusive};
+
+use aoc::{
+    grid::Pos,
+    parse::{self, Line},
+    Grid, ParseError,
+};
+
+/// A number of the schematic, spanning some columns of a row.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Number {
+    /// The index of the number in [`Schematic::numbers`], in reading order.
+    pub id: usize,
+    pub value: u32,
+    pub y: usize,
+    pub xs: Range<usize>,
+}
+
+/// A symbol of the schematic, which is any character other than a digit or `.`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Symbol {
+    /// The index of the symbol in [`Schematic::symbols`], in reading order.
+    pub id: usize,
+    pub kind: char,
+    pub pos: Pos,
+}
+
+/// How the numbers next to a gear are combined.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Aggregate {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregate {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "product" => Some(Aggregate::Product),
+            "sum" => Some(Aggregate::Sum),
+            "max" => Some(Aggregate::Max),
+            _ => None,
+        }
+    }
+
+    pub fn apply(self, numbers: &[&Number]) -> u64 {
+        let values = numbers.iter().map(|number| number.value as u64);
+        match self {
+            Aggregate::Product => values.product(),
+            Aggregate::Sum => values.sum(),
+            Aggregate::Max => values.max().unwrap_or(0),
+        }
+    }
+}
+
+/// Which symbols are gears and what they are worth.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct GearRule {
+    /// The kinds of symbol that can be gears.
+    pub symbols: Vec<char>,
+    /// How many rows and columns away from the symbol a digit of a number can be.
+    pub radius: usize,
+    /// How many numbers a gear has within the radius.
+    pub neighbours: RangeInclusive<usize>,
+    pub aggregate: Aggregate,
+}
+
+/// The rule of part 2: a `*` next to exactly two numbers, worth their product.
+impl Default for GearRule {
+    fn default() -> Self {
+        Self {
+            symbols: vec!['*'],
+            radius: 1,
+            neighbours: 2..=2,
+            aggregate: Aggregate::Product,
+        }
+    }
+}
+
+/// What covers a cell of the schematic.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Cell {
+    Empty,
+    Number(usize),
+    Symbol(usize),
+}
+
+#[derive(Clone, Debug)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    chars: Grid<char>,
+    cells: Grid<Cell>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let rows: Vec<Line> = parse::lines(input).collect();
+        let chars = Grid::from_lines(&rows)?;
+        let width = chars.width();
+
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for (y, row) in rows.iter().enumerate() {
+            // The byte offset and column of the number being read.
+            let mut start = None;
+            let chars = row.text.char_indices().chain([(row.text.len(), '.')]);
+            for (x, (offset, ch)) in chars.enumerate() {
+                if ch.is_ascii_digit() {
+                    start.get_or_insert((offset, x));
+                    cells.push(Cell::Number(numbers.len()));
+                    continue;
+                }
+                if let Some((start_offset, start_x)) = start.take() {
+                    numbers.push(Number {
+                        id: numbers.len(),
+                        value: row.number(&row.text[start_offset..offset])?,
+                        y,
+                        xs: start_x..x,
+                    });
+                }
+                if x == width {
+                    break;
+                } else if ch == '.' {
+                    cells.push(Cell::Empty);
+                } else {
+                    cells.push(Cell::Symbol(symbols.len()));
+                    symbols.push(Symbol {
+                        id: symbols.len(),
+                        kind: ch,
+                        pos: (x, y),
+                    });
+                }
+            }
+        }
+
+        let cells = Grid::new(width, rows.len(), cells);
+        Ok(Self {
+            numbers,
+            symbols,
+            chars,
+            cells,
+        })
+    }
+
+    /// The characters of the schematic.
+    pub fn chars(&self) -> &Grid<char> {
+        &self.chars
+    }
+
+    /// The numbers with a digit next to `symbol`, including diagonally.
+    pub fn numbers_next_to(&self, symbol: &Symbol) -> Vec<&Number> {
+        self.numbers_within(symbol, 1)
+    }
+
+    /// The numbers with a digit at most `radius` rows and columns away from `symbol`.
+    pub fn numbers_within(&self, symbol: &Symbol, radius: usize) -> Vec<&Number> {
+        let (x, y) = symbol.pos;
+        let mut ids: Vec<usize> = self
+            .around(y, x..x + 1, radius)
+            .filter_map(|cell| match cell {
+                Cell::Number(id) => Some(id),
+                _ => None,
+            })
+            .collect();
+        ids.dedup();
+        ids.into_iter().map(|id| &self.numbers[id]).collect()
+    }
+
+    /// The symbols next to any digit of `number`, including diagonally.
+    pub fn symbols_next_to(&self, number: &Number) -> Vec<&Symbol> {
+        self.around(number.y, number.xs.clone(), 1)
+            .filter_map(|cell| match cell {
+                Cell::Symbol(id) => Some(&self.symbols[id]),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The numbers next to at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .filter(|number| !self.symbols_next_to(number).is_empty())
+    }
+
+    /// The symbols of `kind` next to exactly `count` numbers, together with those numbers.
+    pub fn symbols_with(
+        &self,
+        kind: char,
+        count: usize,
+    ) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+        self.symbols
+            .iter()
+            .filter(move |symbol| symbol.kind == kind)
+            .map(|symbol| (symbol, self.numbers_next_to(symbol)))
+            .filter(move |(_, numbers)| numbers.len() == count)
+    }
+
+    /// The symbols that are gears under `rule`, together with their numbers.
+    pub fn gears<'s>(
+        &'s self,
+        rule: &'s GearRule,
+    ) -> impl Iterator<Item = (&'s Symbol, Vec<&'s Number>)> {
+        self.symbols
+            .iter()
+            .filter(|symbol| rule.symbols.contains(&symbol.kind))
+            .map(|symbol| (symbol, self.numbers_within(symbol, rule.radius)))
+            .filter(|(_, numbers)| rule.neighbours.contains(&numbers.len()))
+    }
+
+    /// The cells up to `radius` rows and columns around a span of a row, in reading order,
+    /// including the span itself.
+    fn around(&self, y: usize, xs: Range<usize>, radius: usize) -> impl Iterator<Item = Cell> + '_ {
+        // Saturating, as `radius` can be as large as `day03-gears --radius` lets it be.
+        let (width, height) = (self.cells.width(), self.cells.height());
+        let ys = y.saturating_sub(radius)..=y.saturating_add(radius).min(height - 1);
+        let xs = xs.start.saturating_sub(radius)..xs.end.saturating_add(radius).min(width);
+        ys.flat_map(move |y| self.cells.row(y)[xs.clone()].iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    fn schematic() -> Schematic {
+        let input = "
+            467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..
+        ";
+        Schematic::parse(&dedent(input)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let schematic = schematic();
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(
+            schematic.numbers[2],
+            Number {
+                id: 2,
+                value: 35,
+                y: 2,
+                xs: 2..4
+            }
+        );
+        let kinds: String = schematic.symbols.iter().map(|symbol| symbol.kind).collect();
+        assert_eq!(kinds, "*#*+$*");
+        assert_eq!(schematic.symbols[1].pos, (6, 3));
+
+        // A number at the end of a row doesn't run on into the next one.
+        let schematic = Schematic::parse(".12\n3..").unwrap();
+        let values: Vec<u32> = schematic
+            .numbers
+            .iter()
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(values, [12, 3]);
+
+        let err = Schematic::parse("1..........\n99999999999").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert!(Schematic::parse("...\n..").is_err());
+    }
+
+    #[test]
+    fn test_adjacency() {
+        let schematic = schematic();
+        let values = |numbers: Vec<&Number>| -> Vec<u32> {
+            numbers.iter().map(|number| number.value).collect()
+        };
+        assert_eq!(
+            values(schematic.numbers_next_to(&schematic.symbols[0])),
+            [467, 35]
+        );
+        assert_eq!(
+            values(schematic.numbers_next_to(&schematic.symbols[3])),
+            [592]
+        );
+
+        let kinds = |number: &Number| -> String {
+            schematic
+                .symbols_next_to(number)
+                .iter()
+                .map(|symbol| symbol.kind)
+                .collect()
+        };
+        assert_eq!(kinds(&schematic.numbers[0]), "*");
+        assert_eq!(kinds(&schematic.numbers[1]), "");
+        assert_eq!(kinds(&schematic.numbers[4]), "*");
+
+        let part_numbers: Vec<usize> = schematic.part_numbers().map(|number| number.id).collect();
+        assert_eq!(part_numbers, [0, 2, 3, 4, 6, 7, 8, 9]);
+
+        let gears: Vec<Pos> = schematic
+            .symbols_with('*', 2)
+            .map(|(symbol, _)| symbol.pos)
+            .collect();
+        assert_eq!(gears, [(3, 1), (5, 8)]);
+        assert_eq!(schematic.symbols_with('*', 1).count(), 1);
+        assert_eq!(schematic.symbols_with('#', 0).count(), 0);
+    }
+
+    #[test]
+    fn test_gear_rules() {
+        let schematic = schematic();
+        let worth = |rule: &GearRule| -> Vec<u64> {
+            schematic
+                .gears(rule)
+                .map(|(_, numbers)| rule.aggregate.apply(&numbers))
+                .collect()
+        };
+        assert_eq!(worth(&GearRule::default()), [16345, 451490]);
+
+        let rule = GearRule {
+            symbols: vec!['*', '#', '+', '$'],
+            neighbours: 1..=2,
+            aggregate: Aggregate::Max,
+            ..GearRule::default()
+        };
+        assert_eq!(worth(&rule), [467, 633, 617, 592, 664, 755]);
+
+        // Two rows and columns away, the `+` also reaches 58 and 755, but the `#` only 633 and 58.
+        let rule = GearRule {
+            symbols: vec!['#', '+'],
+            radius: 2,
+            neighbours: 3..=usize::MAX,
+            aggregate: Aggregate::Sum,
+        };
+        assert_eq!(worth(&rule), [58 + 592 + 755]);
+
+        // A radius as large as the `--radius` of `day03-gears` accepts reaches every number.
+        let rule = GearRule {
+            symbols: vec!['$'],
+            radius: usize::MAX,
+            neighbours: 0..=usize::MAX,
+            aggregate: Aggregate::Max,
+        };
+        assert_eq!(worth(&rule), [755]);
+    }
+
+    #[test]
+    fn test_numbers_on_both_sides() {
+        // One number on each side of a symbol, and one above it that spans it.
+        let schematic = Schematic::parse("123\n4*5\n...").unwrap();
+        let numbers = schematic.numbers_next_to(&schematic.symbols[0]);
+        let ids: Vec<usize> = numbers.iter().map(|number| number.id).collect();
+        assert_eq!(ids, [0, 1, 2]);
+    }
+}
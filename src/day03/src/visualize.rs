@@ -0,0 +1,207 @@
+//! The schematic with its part numbers, other numbers and gears told apart, drawn in the terminal
+//! for `--visualize` or as an HTML page.
+
+use std::iter;
+
+use aoc::{
+    render::{Cell, Color, Frame, Style},
+    Grid, ParseError,
+};
+
+use crate::schematic::{GearRule, Schematic};
+
+/// What a cell of the schematic turned out to be.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Kind {
+    Empty,
+    Symbol,
+    Gear,
+    PartNumber,
+    Number,
+}
+
+impl Kind {
+    fn style(self) -> Style {
+        match self {
+            Kind::Empty => Style::fg(Color::Gray),
+            Kind::Symbol => Style::fg(Color::Cyan).bold(),
+            Kind::Gear => Style::fg(Color::Black).on(Color::Yellow).bold(),
+            Kind::PartNumber => Style::fg(Color::Green).bold(),
+            Kind::Number => Style::fg(Color::Red),
+        }
+    }
+
+    /// The class of the HTML element around the cell, if it has one.
+    fn class(self) -> Option<&'static str> {
+        match self {
+            Kind::Empty => None,
+            Kind::Symbol => Some("symbol"),
+            Kind::Gear => Some("gear"),
+            Kind::PartNumber => Some("part"),
+            Kind::Number => Some("number"),
+        }
+    }
+}
+
+const STYLESHEET: &str = "\
+pre.schematic { background: #111; color: #666; padding: 1em; }
+.symbol { color: #0cc; font-weight: bold; }
+.gear { background: #cc0; color: #000; font-weight: bold; }
+.part { color: #0c0; font-weight: bold; }
+.number { color: #e33; }";
+
+fn classify(schematic: &Schematic, rule: &GearRule) -> Grid<Kind> {
+    let chars = schematic.chars();
+    let mut kinds = chars.map(|&ch| match ch {
+        '.' => Kind::Empty,
+        _ => Kind::Symbol,
+    });
+    for number in schematic.numbers.iter() {
+        let kind = match schematic.symbols_next_to(number).is_empty() {
+            true => Kind::Number,
+            false => Kind::PartNumber,
+        };
+        for x in number.xs.clone() {
+            kinds[(x, number.y)] = kind;
+        }
+    }
+    for (gear, _) in schematic.gears(rule) {
+        kinds[gear.pos] = Kind::Gear;
+    }
+    kinds
+}
+
+/// The schematic in ANSI colours, with a caption counting the numbers and what the gears are
+/// worth.
+pub fn frame(schematic: &Schematic, rule: &GearRule) -> Frame {
+    let kinds = classify(schematic, rule);
+    let chars = schematic.chars();
+    let cells = Grid::from_fn(chars.width(), chars.height(), |pos| {
+        Cell::new(chars[pos], kinds[pos].style())
+    });
+    Frame::new(&cells, |&cell| cell).with_caption(caption(schematic, rule))
+}
+
+/// A page showing the schematic with the same colours as [`frame`].
+pub fn html(schematic: &Schematic, rule: &GearRule) -> String {
+    let kinds = classify(schematic, rule);
+    let chars = schematic.chars();
+    let mut body = String::new();
+    for (kinds, chars) in kinds.rows().zip(chars.rows()) {
+        let mut x = 0;
+        while x < chars.len() {
+            let len = kinds[x..]
+                .iter()
+                .take_while(|&&kind| kind == kinds[x])
+                .count();
+            let text = escape(&chars[x..x + len].iter().collect::<String>());
+            match kinds[x].class() {
+                Some(class) => body.push_str(&format!("<span class=\"{class}\">{text}</span>")),
+                None => body.push_str(&text),
+            }
+            x += len;
+        }
+        body.push('\n');
+    }
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Engine schematic</title>\n\
+         <style>\n{STYLESHEET}\n</style>\n</head>\n<body>\n<pre class=\"schematic\">\n{body}</pre>\n\
+         <p>{}</p>\n</body>\n</html>\n",
+        escape(&caption(schematic, rule))
+    )
+}
+
+fn caption(schematic: &Schematic, rule: &GearRule) -> String {
+    let parts = schematic.part_numbers().count();
+    let gears: Vec<u64> = schematic
+        .gears(rule)
+        .map(|(_, numbers)| rule.aggregate.apply(&numbers))
+        .collect();
+    format!(
+        "{parts} part numbers, {} other numbers, {} gears worth {}",
+        schematic.numbers.len() - parts,
+        gears.len(),
+        gears.iter().sum::<u64>()
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// The schematic as a single frame, with the sum of its part numbers.
+pub fn part1(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let schematic = Schematic::parse(input)?;
+    let frame = frame(&schematic, &GearRule::default());
+    let sum: u64 = schematic
+        .part_numbers()
+        .map(|number| number.value as u64)
+        .sum();
+    let caption = format!("{}; the part numbers add up to {sum}", frame.caption());
+    Ok(iter::once(frame.with_caption(caption)))
+}
+
+/// The schematic as a single frame, whose caption ends with the sum of the gear ratios.
+pub fn part2(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let schematic = Schematic::parse(input)?;
+    Ok(iter::once(frame(&schematic, &GearRule::default())))
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::Example;
+
+    use super::*;
+
+    fn schematic() -> Schematic {
+        let example = Example::load(env!("CARGO_MANIFEST_DIR"), "example1", "part1");
+        Schematic::parse(&example.input).unwrap()
+    }
+
+    #[test]
+    fn test_classify() {
+        let kinds = classify(&schematic(), &GearRule::default());
+        assert_eq!(kinds[(0, 0)], Kind::PartNumber);
+        assert_eq!(kinds[(5, 0)], Kind::Number);
+        assert_eq!(kinds[(3, 1)], Kind::Gear);
+        assert_eq!(kinds[(3, 4)], Kind::Symbol);
+        assert_eq!(kinds[(0, 1)], Kind::Empty);
+    }
+
+    #[test]
+    fn test_frames() {
+        for (part, name) in [(1, "example1"), (2, "example2")] {
+            let example = Example::load(env!("CARGO_MANIFEST_DIR"), name, &format!("part{part}"));
+            let frames: Vec<Frame> = match part {
+                1 => part1(&example.input).unwrap().collect(),
+                _ => part2(&example.input).unwrap().collect(),
+            };
+            assert_eq!(frames.len(), 1);
+            assert!(frames[0]
+                .caption()
+                .ends_with(&format!(" {}", example.answer)));
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let schematic = schematic();
+        let frame = frame(&schematic, &GearRule::default());
+        assert_eq!(
+            frame.caption(),
+            "8 part numbers, 2 other numbers, 2 gears worth 467835"
+        );
+        assert_eq!(frame.cells()[(3, 1)].style, Kind::Gear.style());
+
+        let page = html(&schematic, &GearRule::default());
+        assert!(page.starts_with("<!DOCTYPE html>"));
+        assert!(page
+            .contains("\n<span class=\"part\">467</span>..<span class=\"number\">114</span>..\n"));
+        assert!(page.contains("<span class=\"symbol\">$</span>"));
+
+        let schematic = Schematic::parse("1&<").unwrap();
+        assert!(html(&schematic, &GearRule::default()).contains("\"symbol\">&amp;&lt;</span>"));
+    }
+}
@@ -0,0 +1,85 @@
+//! Random almanacs.
+
+use aoc::random::Rng;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// An almanac with `size` ranges of seeds and up to `size` ranges in each map, with numbers below
+/// `1000 * size`. The source ranges of a map never overlap, like in the puzzle.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1) as i64;
+    let space = 1000 * size;
+
+    let seeds: Vec<String> = (0..size)
+        .map(|_| format!("{} {}", rng.range(0..space), rng.range(1..=space / 20)))
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for pair in CATEGORIES.windows(2) {
+        input.push_str(&format!("\n{}-to-{} map:\n", pair[0], pair[1]));
+
+        // Cut the numbers into pieces and map some of them somewhere else.
+        let mut cuts: Vec<i64> = (0..2 * size).map(|_| rng.range(0..space)).collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+        for piece in cuts.windows(2) {
+            if rng.chance(0.5) {
+                let len = piece[1] - piece[0];
+                input.push_str(&format!("{} {} {len}\n", rng.range(0..space), piece[0]));
+            }
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::part2;
+
+    /// The lowest location of any seed, mapping each seed on its own.
+    fn lowest_location(input: &str) -> i64 {
+        let mut blocks = input.split("\n\n");
+        let numbers = |text: &str| -> Vec<i64> {
+            text.split_whitespace()
+                .map(|num| num.parse().unwrap())
+                .collect()
+        };
+        let seeds = numbers(blocks.next().unwrap().strip_prefix("seeds:").unwrap());
+        let maps: Vec<Vec<i64>> = blocks
+            .map(|block| numbers(block.split_once('\n').map_or("", |(_, ranges)| ranges)))
+            .collect();
+
+        seeds
+            .chunks(2)
+            .flat_map(|pair| pair[0]..pair[0] + pair[1])
+            .map(|seed| {
+                maps.iter().fold(seed, |num, ranges| {
+                    ranges
+                        .chunks(3)
+                        .find(|range| (range[1]..range[1] + range[2]).contains(&num))
+                        .map_or(num, |range| range[0] + num - range[1])
+                })
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..30 {
+            let input = input(seed, 8);
+            assert_eq!(part2::process(&input).unwrap(), lowest_location(&input));
+        }
+    }
+}
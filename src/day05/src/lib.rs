@@ -0,0 +1,29 @@
+pub mod gen;
+pub mod part2;
+
+use aoc::{Answer, ParseError, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u32 {
+        5
+    }
+
+    /// Never called, as part 1 is only solved in python and [`Solution::has_part`] says so.
+    fn part1(&self, _input: &str) -> Result<Answer, ParseError> {
+        unreachable!("Day 5 part 1 is only solved in python")
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        part2::process(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        gen::input(seed, size)
+    }
+
+    fn has_part(&self, part: u32) -> bool {
+        part == 2
+    }
+}
@@ -0,0 +1,185 @@
+use std::cmp;
+
+use aoc::{parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut lines = parse::lines(input);
+
+    let line = lines.next_line("the seeds")?;
+    let seeds = line.strip_prefix(line.text, "seeds:")?;
+    let vals = seeds
+        .split_whitespace()
+        .map(|num| line.number(num))
+        .collect::<Result<Vec<i64>, _>>()?;
+
+    if vals.len() % 2 == 1 {
+        return Err(line.error(&line.text[line.text.len()..], "a seed range length"));
+    }
+
+    let mut vals = (vals.iter().step_by(2))
+        .zip(vals.iter().skip(1).step_by(2))
+        .map(|(start, len)| Range::new(*start, *len))
+        .collect::<Vec<_>>();
+
+    let maps = parse_maps(lines)?;
+    for map in maps {
+        vals = vals.into_iter().flat_map(|val| map.map(val)).collect();
+    }
+
+    vals.into_iter()
+        .map(|range| range.start)
+        .min()
+        .ok_or_else(|| ParseError::at_end(input, "a seed"))
+}
+
+fn parse_maps(lines: parse::Lines) -> Result<Vec<Map>, ParseError> {
+    let mut maps: Vec<Map> = Vec::new();
+
+    for line in lines {
+        let is_header = !line.text.starts_with(|ch: char| ch.is_ascii_digit());
+        if is_header {
+            line.strip_suffix(line.text, " map:")?;
+            maps.push(Map::default());
+            continue;
+        }
+
+        let nums = line
+            .text
+            .split_whitespace()
+            .map(|num| line.number(num))
+            .collect::<Result<Vec<i64>, _>>()?;
+
+        let [dest_start, src_start, len] = nums[..] else {
+            return Err(line.error(line.text, "three numbers"));
+        };
+
+        maps.last_mut()
+            .ok_or_else(|| line.error(line.text, "a map header"))?
+            .mappers
+            .push(RangeMapper::new(dest_start, src_start, len));
+    }
+    Ok(maps)
+}
+
+#[derive(Debug, Clone)]
+struct Range {
+    start: i64,
+    len: i64,
+}
+
+impl Range {
+    fn new(start: i64, len: i64) -> Self {
+        Self { start, len }
+    }
+
+    fn checked(start: i64, len: i64) -> Option<Self> {
+        if len > 0 {
+            Some(Self { start, len })
+        } else {
+            None
+        }
+    }
+
+    fn intersect(&self, other: &Range) -> Option<Range> {
+        let start = cmp::max(self.start, other.start);
+        let end = cmp::min(self.start + self.len, other.start + other.len);
+        if start >= end {
+            return None;
+        }
+
+        Some(Range::new(start, end - start))
+    }
+}
+
+struct RangeMapper {
+    src_range: Range,
+    dest_start: i64,
+}
+
+impl RangeMapper {
+    fn new(dest_start: i64, src_start: i64, len: i64) -> Self {
+        Self {
+            src_range: Range::new(src_start, len),
+            dest_start,
+        }
+    }
+
+    fn map(&self, range: &Range) -> Option<(Range, [Option<Range>; 2])> {
+        let src_end = self.src_range.start + self.src_range.len;
+        let range_end = range.start + range.len;
+
+        if let Some(intersection) = self.src_range.intersect(range) {
+            let pre_diff = Range::checked(range.start, self.src_range.start - range.start);
+            let post_diff = Range::checked(src_end, range_end - src_end);
+
+            let mapped_range = Range::new(
+                self.dest_start + (intersection.start - self.src_range.start),
+                intersection.len,
+            );
+            return Some((mapped_range, [pre_diff, post_diff]));
+        }
+        None
+    }
+}
+
+#[derive(Default)]
+struct Map {
+    pub mappers: Vec<RangeMapper>,
+}
+
+impl Map {
+    fn map(&self, range: Range) -> Vec<Range> {
+        let mut src_ranges = vec![range];
+        let mut mapped_ranges = Vec::new();
+
+        for mapper in self.mappers.iter() {
+            src_ranges = src_ranges
+                .into_iter()
+                .flat_map(|range| {
+                    if let Some((mapped_range, diffs)) = mapper.map(&range) {
+                        mapped_ranges.push(mapped_range);
+                        return diffs;
+                    }
+                    [Some(range), None]
+                })
+                .flatten()
+                .collect();
+        }
+
+        mapped_ranges.extend(src_ranges);
+        mapped_ranges
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+
+    #[test]
+    fn test_parse_error() {
+        let input = "
+            seeds: 79 14 55 13
+
+            50 98 2
+        ";
+        let err = process(&dedent(input)).unwrap_err();
+        assert_eq!(err, ParseError::new(3, 1, "a map header", "'50 98 2'"));
+
+        let input = "
+            seeds: 79 14 55 13
+
+            seed-to-soil map:
+            50 98
+        ";
+        let err = process(&dedent(input)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected three numbers, found '50 98'"
+        );
+        assert!(process("seeds: 79 14 55").is_err());
+    }
+}
@@ -0,0 +1,71 @@
+//! Random race records.
+
+use aoc::random::Rng;
+
+/// `size` races of up to 99 milliseconds, whose records can be beaten in most of them. Since part
+/// 2 joins the numbers into one race, each race adds about two digits to its time, so there are at
+/// most four like in the puzzle.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let races: Vec<(u64, u64)> = (0..size.clamp(1, 4))
+        .map(|_| {
+            let time = rng.range(1..100);
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.range(0..=best))
+        })
+        .collect();
+
+    let mut times = String::from("Time:");
+    let mut distances = String::from("Distance:");
+    for (time, distance) in races {
+        let width = time.to_string().len().max(distance.to_string().len()) + 1;
+        times.push_str(&format!("{time:>width$}"));
+        distances.push_str(&format!("{distance:>width$}"));
+    }
+    format!("{times}\n{distances}\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The number of ways to beat the record, from the roots of `hold * (time - hold) = record`.
+    fn ways_to_win(time: i64, record: i64) -> i64 {
+        let discriminant = time * time - 4 * record;
+        if discriminant < 0 {
+            return 0;
+        }
+        let root = discriminant.isqrt();
+        let mut low = (time - root) / 2;
+        while low > 0 && (low - 1) * (time - low + 1) > record {
+            low -= 1;
+        }
+        while low < time && low * (time - low) <= record {
+            low += 1;
+        }
+        (time - 2 * low + 1).max(0)
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..50 {
+            let input = input(seed, 3);
+            let lines: Vec<Vec<&str>> = input
+                .lines()
+                .map(|line| line.split_whitespace().skip(1).collect())
+                .collect();
+
+            let product = lines[0]
+                .iter()
+                .zip(lines[1].iter())
+                .map(|(time, record)| ways_to_win(time.parse().unwrap(), record.parse().unwrap()))
+                .product();
+            assert_eq!(part1::process(&input).unwrap(), product);
+
+            let (time, record) = (lines[0].concat(), lines[1].concat());
+            let ways = ways_to_win(time.parse().unwrap(), record.parse().unwrap());
+            assert_eq!(part2::process(&input).unwrap(), ways);
+        }
+    }
+}
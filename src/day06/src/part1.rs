@@ -0,0 +1,33 @@
+use aoc::{parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut lines = parse::lines(input);
+
+    let time_records = parse_line(&mut lines, "Time:")?
+        .into_iter()
+        .zip(parse_line(&mut lines, "Distance:")?);
+
+    Ok(time_records
+        .map(|(time, record)| {
+            (1..time)
+                .map(|wait_time| wait_time * (time - wait_time))
+                .filter(|dist| *dist > record)
+                .count() as i64
+        })
+        .product())
+}
+
+fn parse_line(lines: &mut parse::Lines, label: &str) -> Result<Vec<i64>, ParseError> {
+    let line = lines.next_line(format!("'{label}'"))?;
+    line.strip_prefix(line.text, label)?
+        .split_whitespace()
+        .map(|num| line.number(num))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+}
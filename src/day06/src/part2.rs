@@ -0,0 +1,31 @@
+use aoc::{parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut lines = parse::lines(input);
+
+    let (time, record) = (
+        parse_line(&mut lines, "Time:")?,
+        parse_line(&mut lines, "Distance:")?,
+    );
+    Ok((1..time)
+        .map(|wait_time| wait_time * (time - wait_time))
+        .filter(|dist| *dist > record)
+        .count() as i64)
+}
+
+fn parse_line(lines: &mut parse::Lines, label: &str) -> Result<i64, ParseError> {
+    let line = lines.next_line(format!("'{label}'"))?;
+    let nums = line.strip_prefix(line.text, label)?;
+    nums.split_whitespace()
+        .flat_map(|num| num.chars())
+        .collect::<String>()
+        .parse()
+        .map_err(|_| line.error(nums.trim(), "a number"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+}
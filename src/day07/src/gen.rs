@@ -0,0 +1,78 @@
+//! Random hands of Camel Cards.
+
+use std::collections::HashSet;
+
+use aoc::random::Rng;
+
+const CARDS: &str = "23456789TJQKA";
+
+/// `size` different hands with bids of up to 1000. Each hand is drawn from only a few kinds of
+/// card, so that pairs, full houses and jokers are common.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let cards: Vec<char> = CARDS.chars().collect();
+    // There are only 13^5 different hands.
+    let size = size.clamp(1, 100_000);
+
+    let mut hands = HashSet::new();
+    let mut input = String::new();
+    while hands.len() < size {
+        let kinds: Vec<char> = (0..rng.range(1..=5)).map(|_| *rng.pick(&cards)).collect();
+        let hand: String = (0..5).map(|_| *rng.pick(&kinds)).collect();
+        if hands.insert(hand.clone()) {
+            input.push_str(&format!("{hand} {}\n", rng.range(1..=1000)));
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The strength of a hand: how many of the most common card it holds and of the second most
+    /// common one, which orders the types of hands.
+    fn strength(hand: &str) -> (usize, usize) {
+        let mut counts: Vec<usize> = CARDS
+            .chars()
+            .map(|card| hand.chars().filter(|&ch| ch == card).count())
+            .collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        (counts[0], counts[1])
+    }
+
+    /// The total winnings, trying every card for the jokers when `order` puts them first.
+    fn winnings(input: &str, order: &str) -> i64 {
+        let mut hands: Vec<((usize, usize), Vec<usize>, i64)> = input
+            .lines()
+            .map(|line| {
+                let (hand, bid) = line.split_once(' ').unwrap();
+                let strength = match order.starts_with('J') {
+                    true => CARDS
+                        .chars()
+                        .map(|card| strength(&hand.replace('J', &card.to_string())))
+                        .max()
+                        .unwrap(),
+                    false => strength(hand),
+                };
+                let values = hand.chars().map(|ch| order.find(ch).unwrap()).collect();
+                (strength, values, bid.parse().unwrap())
+            })
+            .collect();
+        hands.sort_unstable();
+        (1..).zip(hands).map(|(rank, (_, _, bid))| rank * bid).sum()
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..50 {
+            let input = input(seed, 100);
+            assert_eq!(part1::process(&input).unwrap(), winnings(&input, CARDS));
+            assert_eq!(
+                part2::process(&input).unwrap(),
+                winnings(&input, "J23456789TQKA")
+            );
+        }
+    }
+}
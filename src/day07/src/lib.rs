@@ -0,0 +1,25 @@
+pub mod gen;
+pub mod part1;
+pub mod part2;
+
+use aoc::{Answer, ParseError, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u32 {
+        7
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        part1::process(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        part2::process(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        gen::input(seed, size)
+    }
+}
@@ -0,0 +1,143 @@
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use aoc::{
+    parse::{self, Line},
+    ParseError,
+};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut hands = parse(parse::lines(input))?;
+    hands.sort_unstable_by(|(hand1, _), (hand2, _)| hand1.cmp(hand2));
+
+    Ok(hands
+        .into_iter()
+        .zip(1..)
+        .map(|((_hand, bid), rank)| rank * bid)
+        .sum())
+}
+
+fn parse<'a>(lines: impl Iterator<Item = Line<'a>>) -> Result<Vec<(Hand, i64)>, ParseError> {
+    lines
+        .map(|line| {
+            let (cards, bid) = line.split_once(line.text, " ")?;
+            line.check_chars(cards, "J23456789TQKA")?;
+            if cards.len() != 5 {
+                return Err(line.error(cards, "a hand of 5 cards"));
+            }
+            Ok((Hand::new(cards.to_owned()), line.number(bid)?))
+        })
+        .collect()
+}
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Eq, PartialEq)]
+struct Hand {
+    hand_type: HandType,
+    hand: String,
+}
+
+impl Hand {
+    fn new(cards: String) -> Self {
+        let mut map = BTreeMap::new();
+        for ch in cards.chars() {
+            if let Some(count) = map.get_mut(&ch) {
+                *count += 1;
+            } else {
+                map.insert(ch, 1);
+            }
+        }
+
+        if map.len() > 1 {
+            if let Some(joker_count) = map.remove(&'J') {
+                let max_count = map.values_mut().max_by_key(|count| **count).unwrap();
+                *max_count += joker_count;
+            }
+        }
+
+        use HandType::*;
+        let hand_type = match map.len() {
+            5 => HighCard,
+            4 => OnePair,
+            3 => {
+                if map.into_iter().find(|(_ch, count)| *count == 3).is_some() {
+                    ThreeOfAKind
+                } else {
+                    TwoPair
+                }
+            }
+            2 => {
+                if map.into_iter().find(|(_ch, count)| *count == 4).is_some() {
+                    FourOfAKind
+                } else {
+                    FullHouse
+                }
+            }
+            1 => FiveOfAKind,
+            _ => panic!(
+                "Unexpected number of cards in hand '{cards}': {}",
+                map.len()
+            ),
+        };
+
+        Self {
+            hand_type,
+            hand: cards,
+        }
+    }
+
+    fn card_precedence(ch: char) -> usize {
+        if let Some(precedence) = "J23456789TQKA".find(ch) {
+            return precedence;
+        }
+        panic!("Unexpected card letter: {ch}")
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.hand_type != other.hand_type {
+            return self.hand_type.cmp(&other.hand_type);
+        }
+
+        for (ch1, ch2) in self.hand.chars().zip(other.hand.chars()) {
+            if ch1 != ch2 {
+                return Hand::card_precedence(ch1).cmp(&Hand::card_precedence(ch2));
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+
+    #[test]
+    fn test_hand_new() {
+        use HandType::*;
+        assert_eq!(Hand::new("32T3K".to_owned()).hand_type, OnePair);
+        assert_eq!(Hand::new("KK677".to_owned()).hand_type, TwoPair);
+        assert_eq!(Hand::new("T55J5".to_owned()).hand_type, FourOfAKind);
+        assert_eq!(Hand::new("KTJJT".to_owned()).hand_type, FourOfAKind);
+        assert_eq!(Hand::new("QQQJA".to_owned()).hand_type, FourOfAKind);
+    }
+}
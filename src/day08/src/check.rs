@@ -0,0 +1,116 @@
+//! The cycles that part 2 takes a least common multiple of.
+
+use aoc::{parse, Check, ParseError};
+
+use crate::part2::{parse_map, MapType};
+
+pub fn assumptions(input: &str) -> Result<Vec<Check>, ParseError> {
+    let mut lines = parse::lines(input);
+    let line = lines.next_line("the steps")?;
+    line.check_chars(line.text, "LR")?;
+    let steps = line.text.as_bytes();
+    let map = parse_map(lines)?;
+
+    let camel = match map.contains_key("AAA") {
+        true => match walk(&map, steps, "AAA", 0, |node| node == "ZZZ") {
+            Some(_) => Ok(()),
+            None => Err("it loops without reaching it".to_owned()),
+        },
+        false => Err("there is no node 'AAA'".to_owned()),
+    };
+
+    let mut starts: Vec<&str> = map
+        .keys()
+        .copied()
+        .filter(|node| node.ends_with('A'))
+        .collect();
+    starts.sort_unstable();
+    let is_end = |node: &str| node.ends_with('Z');
+    let (mut unreached, mut irregular) = (Vec::new(), Vec::new());
+    for start in starts {
+        let Some((count, end)) = walk(&map, steps, start, 0, is_end) else {
+            unreached.push(format!("'{start}' loops without reaching one"));
+            continue;
+        };
+        match walk(&map, steps, end, count, is_end) {
+            Some((again, next)) if (again, next) == (count, end) => {}
+            Some((again, next)) => irregular.push(format!(
+                "'{start}' reaches '{end}' in {count} steps, then '{next}' in {again} more"
+            )),
+            None => irregular.push(format!(
+                "'{start}' reaches '{end}' in {count} steps, then loops without another end"
+            )),
+        }
+    }
+
+    Ok(vec![
+        Check::new("the walk from 'AAA' reaches 'ZZZ'", camel),
+        Check::from_failures(
+            "the walk from every node ending in 'A' reaches a node ending in 'Z'",
+            unreached,
+        ),
+        Check::from_failures(
+            "every walk then keeps returning to its end in the same number of steps",
+            irregular,
+        ),
+    ])
+}
+
+/// The number of steps from `start` to the first node after it that `is_end`, and that node,
+/// starting at `offset` in the steps. A walk that takes more steps than there are states of a
+/// node and a position in the steps is looping forever.
+fn walk<'a>(
+    map: &MapType<'a>,
+    steps: &[u8],
+    start: &'a str,
+    offset: usize,
+    is_end: impl Fn(&str) -> bool,
+) -> Option<(usize, &'a str)> {
+    let mut node = start;
+    for count in 1..=map.len() * steps.len() {
+        node = match steps[(offset + count - 1) % steps.len()] {
+            b'L' => map[node].0,
+            _ => map[node].1,
+        };
+        if is_end(node) {
+            return Some((count, node));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    #[test]
+    fn test_assumptions() {
+        let checks = assumptions(&crate::gen::input(0, 10)).unwrap();
+        assert!(checks.iter().all(Check::holds));
+
+        let input = "
+            LR
+
+            AAA = (11B, XXX)
+            11B = (XXX, ZZZ)
+            ZZZ = (ZZZ, ZZZ)
+            22A = (22B, XXX)
+            22B = (22B, 22B)
+            XXX = (XXX, XXX)
+        ";
+        let failed: Vec<_> = assumptions(&dedent(input))
+            .unwrap()
+            .into_iter()
+            .filter_map(|check| check.result.err())
+            .collect();
+        assert_eq!(
+            failed,
+            [
+                "'22A' loops without reaching one",
+                "'AAA' reaches 'ZZZ' in 2 steps, then 'ZZZ' in 1 more",
+            ]
+        );
+    }
+}
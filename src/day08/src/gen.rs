@@ -0,0 +1,114 @@
+//! Random networks of the haunted wasteland.
+
+use std::collections::HashSet;
+
+use aoc::random::Rng;
+
+/// A network with up to `size` instructions and one to five ghosts, the first one starting at
+/// `AAA` and ending at `ZZZ`. Like in the puzzle, each ghost walks a loop that passes its only
+/// end node after a multiple of the number of instructions, and starts over from there. Each loop
+/// is up to `size` times longer than the instructions.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    // The names run out at about 40000 nodes.
+    let size = size.clamp(1, 80);
+    let len = rng.range(1..=size);
+    let steps: Vec<char> = rng.string(len, "LR").chars().collect();
+
+    let mut names = HashSet::from(["AAA".to_owned(), "ZZZ".to_owned()]);
+    let mut name = |rng: &mut Rng, last: &str| loop {
+        let name = rng.string(2, "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789") + &rng.string(1, last);
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut lines = Vec::new();
+    for ghost in 0..rng.range(1..=5) {
+        let len = steps.len() * rng.range(1..=size);
+        let mut nodes = vec![match ghost {
+            0 => "AAA".to_owned(),
+            _ => name(&mut rng, "A"),
+        }];
+        nodes.extend((1..len).map(|_| name(&mut rng, "BCDEFGHIJKLMNOPQRSTUVWXY")));
+        nodes.push(match ghost {
+            0 => "ZZZ".to_owned(),
+            _ => name(&mut rng, "Z"),
+        });
+
+        // The way the instructions don't take leads anywhere on the same loop.
+        let mut children: Vec<(String, String)> = (0..len)
+            .map(|i| {
+                let (next, other) = (nodes[i + 1].clone(), rng.pick(&nodes[1..]).clone());
+                match steps[i % steps.len()] {
+                    'L' => (next, other),
+                    _ => (other, next),
+                }
+            })
+            .collect();
+        children.push(children[0].clone());
+
+        for (node, (left, right)) in nodes.iter().zip(children) {
+            lines.push(format!("{node} = ({left}, {right})\n"));
+        }
+    }
+    rng.shuffle(&mut lines);
+
+    let steps: String = steps.into_iter().collect();
+    format!("{steps}\n\n{}", lines.concat())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The number of steps until every ghost stands on an end node at once, taking them one by one.
+    fn walk(input: &str, ghosts: bool) -> i64 {
+        let (steps, network) = input.split_once("\n\n").unwrap();
+        let network: HashMap<&str, (&str, &str)> = network
+            .lines()
+            .map(|line| {
+                let (node, children) = line.split_once(" = ").unwrap();
+                let children = children.trim_matches(['(', ')']).split_once(", ").unwrap();
+                (node, children)
+            })
+            .collect();
+
+        let mut nodes: Vec<&str> = match ghosts {
+            true => network
+                .keys()
+                .copied()
+                .filter(|node| node.ends_with('A'))
+                .collect(),
+            false => vec!["AAA"],
+        };
+        let is_end = |node: &&str| match ghosts {
+            true => node.ends_with('Z'),
+            false => *node == "ZZZ",
+        };
+        for (count, step) in (0..).zip(steps.chars().cycle()) {
+            if nodes.iter().all(is_end) {
+                return count;
+            }
+            for node in nodes.iter_mut() {
+                *node = match step {
+                    'L' => network[node].0,
+                    _ => network[node].1,
+                };
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..50 {
+            let input = input(seed, 6);
+            assert_eq!(part1::process(&input).unwrap(), walk(&input, false));
+            assert_eq!(part2::process(&input).unwrap(), walk(&input, true));
+        }
+    }
+}
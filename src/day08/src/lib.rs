@@ -0,0 +1,30 @@
+pub mod check;
+pub mod gen;
+pub mod part1;
+pub mod part2;
+
+use aoc::{Answer, Check, ParseError, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        part1::process(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        part2::process(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        gen::input(seed, size)
+    }
+
+    fn check(&self, input: &str) -> Result<Vec<Check>, ParseError> {
+        check::assumptions(input)
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use aoc::{parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut lines = parse::lines(input);
+
+    let line = lines.next_line("the steps")?;
+    line.check_chars(line.text, "LR")?;
+
+    let steps = line.text.chars().cycle();
+    let map = parse_map(lines)?;
+    if !map.contains_key("AAA") {
+        return Err(ParseError::at_end(input, "a node named 'AAA'"));
+    }
+
+    let (mut count, mut node) = (0, "AAA");
+    for step in steps {
+        if node == "ZZZ" {
+            return Ok(count);
+        }
+
+        count += 1;
+        node = match step {
+            'L' => map[node].0,
+            _ => map[node].1,
+        };
+    }
+
+    unreachable!()
+}
+
+fn parse_map<'a>(
+    lines: parse::Lines<'a>,
+) -> Result<HashMap<&'a str, (&'a str, &'a str)>, ParseError> {
+    let mut map = HashMap::new();
+    let mut edges = Vec::new();
+
+    for line in lines {
+        let (key, nodes) = line.split_once(line.text, "=")?;
+        let nodes = line.strip_prefix(nodes.trim(), "(")?;
+        let nodes = line.strip_suffix(nodes, ")")?;
+        let (left, right) = line.split_once(nodes, ",")?;
+
+        let (left, right) = (left.trim(), right.trim());
+        map.insert(key.trim(), (left, right));
+        edges.extend([(line, left), (line, right)]);
+    }
+
+    match edges
+        .into_iter()
+        .find(|(_line, node)| !map.contains_key(node))
+    {
+        Some((line, node)) => Err(line.error(node, "a node that has its own line")),
+        None => Ok(map),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example1, example2);
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use aoc::{math, parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut lines = parse::lines(input);
+
+    let line = lines.next_line("the steps")?;
+    line.check_chars(line.text, "LR")?;
+
+    let steps = line.text.chars().cycle();
+    let map = parse_map(lines)?;
+
+    Ok(map
+        .keys()
+        .filter(|key| key.ends_with('A'))
+        .map(|key| steps_to_end(key, &map, steps.clone()))
+        .fold(1, math::lcm))
+}
+
+pub(crate) type MapType<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+pub(crate) fn parse_map<'a>(lines: parse::Lines<'a>) -> Result<MapType<'a>, ParseError> {
+    let mut map = HashMap::new();
+    let mut edges = Vec::new();
+
+    for line in lines {
+        let (key, nodes) = line.split_once(line.text, "=")?;
+        let nodes = line.strip_prefix(nodes.trim(), "(")?;
+        let nodes = line.strip_suffix(nodes, ")")?;
+        let (left, right) = line.split_once(nodes, ",")?;
+
+        let (left, right) = (left.trim(), right.trim());
+        map.insert(key.trim(), (left, right));
+        edges.extend([(line, left), (line, right)]);
+    }
+
+    match edges
+        .into_iter()
+        .find(|(_line, node)| !map.contains_key(node))
+    {
+        Some((line, node)) => Err(line.error(node, "a node that has its own line")),
+        None => Ok(map),
+    }
+}
+
+fn steps_to_end(start: &str, map: &MapType, steps: impl Iterator<Item = char>) -> i64 {
+    let (mut count, mut node) = (0, start);
+    for step in steps {
+        if node.ends_with('Z') {
+            return count;
+        }
+
+        count += 1;
+        node = match step {
+            'L' => map[node].0,
+            _ => map[node].1,
+        };
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    aoc::example_tests!(process, part2: example3);
+
+    #[test]
+    fn test_parse_error() {
+        let input = "
+            LR
+
+            11A = (11B, XXX)
+            11B = (XXX, 11Z)
+        ";
+        let err = process(&dedent(input)).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(3, 13, "a node that has its own line", "'XXX'")
+        );
+
+        let err = process("LRX\n\n11A = (11A, 11A)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected one of 'L', 'R', found 'X'"
+        );
+    }
+}
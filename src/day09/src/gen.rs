@@ -0,0 +1,62 @@
+//! Random histories of the oasis readings.
+
+use aoc::random::Rng;
+
+/// `size` histories of 5 to 21 values each. Every history follows a polynomial of a lower degree
+/// than its length, so that the differences always reach zero, like in the puzzle.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let len: i64 = rng.range(5..=21);
+        let degree = rng.range(0..=(len - 2).min(6));
+        let coefficients: Vec<i64> = (0..=degree).map(|_| rng.range(-5..=5)).collect();
+        let start = rng.range(-5..=5);
+
+        let values: Vec<String> = (start..start + len)
+            .map(|x| {
+                let value = coefficients.iter().rev().fold(0, |acc, c| acc * x + c);
+                value.to_string()
+            })
+            .collect();
+        input.push_str(&values.join(" "));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The value of a history at `x` by Newton's forward differences formula, where the history
+    /// starts at zero.
+    fn newton(values: &[i64], x: i64) -> i64 {
+        let mut diffs = values.to_vec();
+        let (mut value, mut binomial) = (0, 1);
+        for k in 0..values.len() as i64 {
+            value += diffs[0] * binomial;
+            binomial = binomial * (x - k) / (k + 1);
+            diffs = diffs.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        }
+        value
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..50 {
+            let input = input(seed, 20);
+            let histories: Vec<Vec<i64>> = input
+                .lines()
+                .map(|line| line.split(' ').map(|num| num.parse().unwrap()).collect())
+                .collect();
+
+            let next = histories.iter().map(|h| newton(h, h.len() as i64)).sum();
+            assert_eq!(part1::process(&input).unwrap(), next);
+            let previous = histories.iter().map(|h| newton(h, -1)).sum();
+            assert_eq!(part2::process(&input).unwrap(), previous);
+        }
+    }
+}
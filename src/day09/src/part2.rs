@@ -0,0 +1,53 @@
+use std::mem;
+
+use aoc::{parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let nums = line.text.split_whitespace().map(|num| line.number(num));
+            extrapolate(nums.collect::<Result<Vec<_>, _>>()?.into_iter())
+                .ok_or_else(|| line.error(line.text, "a history whose differences reach zero"))
+        })
+        .sum()
+}
+
+/// The previous value of the history, if its differences become all zero before running out.
+fn extrapolate(nums: impl Iterator<Item = i64>) -> Option<i64> {
+    let mut head_diffs = Vec::new();
+    let mut nums = nums.collect::<Vec<_>>();
+
+    while !nums.iter().all(|num| *num == 0) {
+        if nums.len() < 2 {
+            return None;
+        }
+        let mut last = nums.pop().unwrap();
+        head_diffs.push(nums[0]);
+
+        for num in nums.iter_mut().rev() {
+            *num = mem::replace(&mut last, *num) - *num;
+        }
+    }
+    Some(
+        head_diffs.iter().step_by(2).sum::<i64>()
+            - head_diffs.iter().skip(1).step_by(2).sum::<i64>(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+
+    #[test]
+    fn test_parse_error() {
+        let err = process("0 3 6 9\n1 2 4 8").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 1, "a history whose differences reach zero", "'1 2 4 8'")
+        );
+        assert!(process("7").is_err());
+        assert!(process("0 3 x").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+//! Random fields of pipes.
+
+use aoc::{geometry::Direction, random, random::Rng, Grid};
+
+/// A field of `size` by `size` tiles with a loop around a random shape and junk pipes everywhere
+/// else. The start is in the top left corner of the loop, where it always takes the place of an
+/// `F` pipe.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(3);
+    let region = random::region(&mut rng, size - 1, size - 1, (size - 1) * (size - 1) / 3);
+    let (start, steps) = random::outline(&region);
+
+    let mut field = Grid::filled(size, size, '.');
+    for pos in field.positions().collect::<Vec<_>>() {
+        if rng.chance(0.7) {
+            field[pos] = *rng.pick(&['|', '-', 'L', 'J', '7', 'F']);
+        }
+    }
+    // Junk next to the start may not look connected to it.
+    for pos in field.neighbours4(start).collect::<Vec<_>>() {
+        field[pos] = '.';
+    }
+
+    let mut pos = start;
+    for (i, &dir) in steps.iter().enumerate() {
+        let from = steps[(i + steps.len() - 1) % steps.len()].opposite();
+        field[pos] = pipe(from, dir);
+        let offset = dir.offset();
+        pos = field
+            .checked_pos(pos.0 as i64 + offset.x, pos.1 as i64 + offset.y)
+            .unwrap();
+    }
+    field[start] = 'S';
+
+    field
+        .rows()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// The pipe connecting the two directions.
+fn pipe(a: Direction, b: Direction) -> char {
+    use Direction::*;
+    match (a, b) {
+        (Up, Down) | (Down, Up) => '|',
+        (Left, Right) | (Right, Left) => '-',
+        (Up, Right) | (Right, Up) => 'L',
+        (Up, Left) | (Left, Up) => 'J',
+        (Down, Left) | (Left, Down) => '7',
+        (Down, Right) | (Right, Down) => 'F',
+        _ => unreachable!("A loop never turns back"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..30 {
+            let input = input(seed, 15);
+            let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+            let grid =
+                Grid::from_lines(&aoc::parse::grid_lines(&input, part1::TILES).unwrap()).unwrap();
+            let start = grid.position(|&ch| ch == 'S').unwrap();
+            let next = part1::start_neighbour(start, &grid).unwrap();
+            let pipe = part2::get_pipe_tiles(next, &grid);
+            assert_eq!(part1::process(&input).unwrap(), pipe.len() as i64 / 2);
+
+            // A ray to the left of an enclosed tile crosses the loop an odd number of times,
+            // counting only the pipes that lead up.
+            let enclosed = grid
+                .positions()
+                .filter(|&(x, y)| {
+                    !pipe.contains(&(x, y))
+                        && (0..x)
+                            .filter(|&left| pipe.contains(&(left, y)))
+                            .filter(|&left| matches!(rows[y][left], '|' | 'L' | 'J'))
+                            .count()
+                            % 2
+                            == 1
+                })
+                .count();
+            assert_eq!(part2::process(&input).unwrap(), enclosed);
+        }
+    }
+}
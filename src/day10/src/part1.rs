@@ -0,0 +1,51 @@
+use aoc::{graph, grid::Pos, parse, Grid, ParseError};
+
+pub(crate) const TILES: &str = ".S|-LJ7F│─╰╯╭╮";
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let rows = parse::grid_lines(input, TILES)?;
+    let grid = Grid::from_lines(&rows)?;
+
+    let (x, y) = grid
+        .position(|&ch| ch == 'S')
+        .ok_or_else(|| ParseError::at_end(input, "a starting position 'S'"))?;
+    let start = start_neighbour((x, y), &grid)
+        .ok_or_else(|| rows[y].char_error(x, "a start connected to two pipes"))?;
+
+    let pipe = graph::from_fn(|&pos| get_neighbours(pos, &grid).into_iter().flatten());
+    let loop_len = graph::bfs_layers(&pipe, start).flatten().count();
+
+    Ok((loop_len as i64 + 1) / 2)
+}
+
+pub(crate) fn start_neighbour(start: Pos, grid: &Grid<char>) -> Option<Pos> {
+    grid.neighbours4(start).find(|&pos| {
+        get_neighbours(pos, grid).is_some_and(|neighbours| neighbours.contains(&start))
+    })
+}
+
+pub(crate) fn get_neighbours((x, y): Pos, grid: &Grid<char>) -> Option<[Pos; 2]> {
+    let tile = *grid.get((x, y))?;
+
+    let up = (x, y.saturating_sub(1));
+    let down = (x, y.saturating_add(1));
+    let left = (x.saturating_sub(1), y);
+    let right = (x.saturating_add(1), y);
+
+    match tile {
+        '|' | '│' => Some([up, down]),
+        '-' | '─' => Some([left, right]),
+        'L' | '╰' => Some([up, right]),
+        'J' | '╯' => Some([up, left]),
+        '7' | '╮' => Some([down, left]),
+        'F' | '╭' => Some([down, right]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example1, example2, example3, example4);
+}
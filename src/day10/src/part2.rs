@@ -0,0 +1,122 @@
+use std::collections::{BTreeMap, HashSet};
+
+use aoc::{graph, grid::Pos, parse, Grid, ParseError};
+
+const TILES: &str = ".S|-LJ7F│─╰╯╭╮";
+
+pub fn process(input: &str) -> Result<usize, ParseError> {
+    let rows = parse::grid_lines(input, TILES)?;
+    let grid = Grid::from_lines(&rows)?;
+
+    let (x, y) = grid
+        .position(|&ch| ch == 'S')
+        .ok_or_else(|| ParseError::at_end(input, "a starting position 'S'"))?;
+    let start = start_neighbour((x, y), &grid)
+        .ok_or_else(|| rows[y].char_error(x, "a start connected to two pipes"))?;
+
+    let pipe_tiles = get_pipe_tiles(start, &grid);
+    let mut visited = pipe_tiles.clone();
+
+    Ok(grid
+        .positions()
+        .map(|pos| {
+            if !visited.contains(&pos) {
+                let fill_tiles = flood_fill(pos, &pipe_tiles, &grid);
+                visited.extend(fill_tiles.iter().copied());
+                if is_inside(pos, &pipe_tiles, &grid) {
+                    return fill_tiles.len();
+                }
+            }
+            0
+        })
+        .sum())
+}
+
+/// The tiles of the region around `pos` that is enclosed by the loop.
+pub(crate) fn flood_fill(pos: Pos, pipe_tiles: &HashSet<Pos>, grid: &Grid<char>) -> Vec<Pos> {
+    let region = graph::from_fn(|&pos| {
+        grid.neighbours4(pos)
+            .filter(|next| !pipe_tiles.contains(next))
+    });
+    graph::bfs_layers(&region, pos).flatten().collect()
+}
+
+pub(crate) fn to_rounded(ch: char) -> char {
+    if ['.', '│', '─', '╰', '╯', '╭', '╮'].contains(&ch) {
+        return ch;
+    }
+
+    match ch {
+        '|' => '│',
+        '-' => '─',
+        'L' => '╰',
+        'J' => '╯',
+        'F' => '╭',
+        '7' => '╮',
+        'S' => '╭', // HACK:
+        _ => panic!("Unexpected symbol: {ch}"),
+    }
+}
+
+pub(crate) fn is_inside(pos: Pos, pipe_tiles: &HashSet<Pos>, grid: &Grid<char>) -> bool {
+    let ray_symbols = grid
+        .row(pos.1)
+        .iter()
+        .enumerate()
+        .take(pos.0)
+        .map(|(x, &ch)| ((x, pos.1), ch))
+        .filter(|(pos, _ch)| pipe_tiles.contains(pos))
+        .map(|(_pos, ch)| ch)
+        .collect::<Vec<_>>();
+
+    let mut counts = BTreeMap::new();
+    for symbol in ['│', '╰', '╯', '╭', '╮'] {
+        let count = ray_symbols
+            .iter()
+            .filter(|ch| to_rounded(**ch) == symbol)
+            .count();
+        counts.insert(symbol, count);
+    }
+
+    let pair_count1 = counts[&'╭'].min(counts[&'╮']);
+    let pair_count2 = counts[&'╰'].min(counts[&'╯']);
+    let max_count = counts[&'╰'].max(counts[&'╯']);
+    (pair_count1 * 2 + pair_count2 + max_count + counts[&'│']) % 2 == 1
+}
+
+pub(crate) fn get_pipe_tiles(start: Pos, grid: &Grid<char>) -> HashSet<Pos> {
+    let pipe = graph::from_fn(|&pos| get_neighbours(pos, grid).into_iter().flatten());
+    graph::bfs_layers(&pipe, start).flatten().collect()
+}
+
+fn start_neighbour(start: Pos, grid: &Grid<char>) -> Option<Pos> {
+    grid.neighbours4(start).find(|&pos| {
+        get_neighbours(pos, grid).is_some_and(|neighbours| neighbours.contains(&start))
+    })
+}
+
+fn get_neighbours((x, y): Pos, grid: &Grid<char>) -> Option<[Pos; 2]> {
+    let tile = *grid.get((x, y))?;
+
+    let up = (x, y.saturating_sub(1));
+    let down = (x, y.saturating_add(1));
+    let left = (x.saturating_sub(1), y);
+    let right = (x.saturating_add(1), y);
+
+    match to_rounded(tile) {
+        '│' => Some([up, down]),
+        '─' => Some([left, right]),
+        '╰' => Some([up, right]),
+        '╯' => Some([up, left]),
+        '╮' => Some([down, left]),
+        '╭' => Some([down, right]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example5, example6, example7);
+}
@@ -0,0 +1,120 @@
+//! Frames of the walk along the loop and of the tiles it encloses, for `--visualize`.
+
+use std::{collections::HashSet, iter};
+
+use aoc::{
+    graph,
+    grid::Pos,
+    parse,
+    render::{Cell, Color, Frame, Style},
+    Grid, ParseError,
+};
+
+use crate::{
+    part1::{get_neighbours, start_neighbour, TILES},
+    part2::{flood_fill, get_pipe_tiles, is_inside, to_rounded},
+};
+
+const LOOP: Style = Style::fg(Color::Yellow);
+const FRONT: Style = Style::fg(Color::Red).bold();
+const INSIDE: Style = Style::bg(Color::Green);
+const OUTSIDE: Style = Style::fg(Color::Blue);
+
+/// A frame for every step along the loop, walking both ways from the start at once.
+pub fn part1(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let (grid, start) = parse_map(input)?;
+
+    let pipe = graph::from_fn(|&pos: &Pos| match pos == start {
+        true => grid
+            .neighbours4(pos)
+            .filter(|&next| get_neighbours(next, &grid).is_some_and(|pair| pair.contains(&pos)))
+            .collect::<Vec<_>>(),
+        false => get_neighbours(pos, &grid).into_iter().flatten().collect(),
+    });
+    let layers: Vec<Vec<Pos>> = graph::bfs_layers(&pipe, start).collect();
+
+    let mut frame = draw(&grid, start);
+    Ok(layers
+        .into_iter()
+        .enumerate()
+        .map(move |(steps, mut layer)| {
+            layer.retain(|&pos| pos != start);
+            let mut current = frame.clone();
+            current.highlight(layer.iter().copied(), FRONT);
+            frame.highlight(layer, LOOP);
+            current.with_caption(format!("{steps} steps from the start"))
+        }))
+}
+
+/// A frame for every region of tiles outside of the loop, marking whether it is enclosed.
+pub fn part2(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let (grid, start) = parse_map(input)?;
+
+    let neighbour = start_neighbour(start, &grid).unwrap();
+    let pipe_tiles = get_pipe_tiles(neighbour, &grid);
+    let mut frame = draw(&grid, start);
+    frame.highlight(pipe_tiles.iter().copied().filter(|&pos| pos != start), LOOP);
+
+    let mut visited: HashSet<Pos> = pipe_tiles.clone();
+    let mut enclosed = 0;
+    let mut positions = grid.positions().collect::<Vec<_>>().into_iter();
+    Ok(iter::from_fn(move || {
+        let pos = positions.find(|pos| !visited.contains(pos))?;
+        let region = flood_fill(pos, &pipe_tiles, &grid);
+        visited.extend(region.iter().copied());
+
+        match is_inside(pos, &pipe_tiles, &grid) {
+            true => {
+                enclosed += region.len();
+                frame.highlight(region, INSIDE);
+            }
+            false => frame.highlight(region, OUTSIDE),
+        }
+        let caption = format!("{enclosed} tiles enclosed");
+        Some(frame.clone().with_caption(caption))
+    }))
+}
+
+/// The map and the start, after the same checks as the solutions.
+fn parse_map(input: &str) -> Result<(Grid<char>, Pos), ParseError> {
+    let rows = parse::grid_lines(input, TILES)?;
+    let grid = Grid::from_lines(&rows)?;
+
+    let (x, y) = grid
+        .position(|&ch| ch == 'S')
+        .ok_or_else(|| ParseError::at_end(input, "a starting position 'S'"))?;
+    start_neighbour((x, y), &grid)
+        .ok_or_else(|| rows[y].char_error(x, "a start connected to two pipes"))?;
+    Ok((grid, (x, y)))
+}
+
+/// The pipes drawn with box drawing characters, and everything else dimmed.
+fn draw(grid: &Grid<char>, start: Pos) -> Frame {
+    let mut frame = Frame::new(grid, |&ch| match ch {
+        'S' | '.' => Cell::new(ch, Style::fg(Color::Gray)),
+        ch => Cell::new(to_rounded(ch), Style::fg(Color::Gray)),
+    });
+    frame.mark([start], 'S', Style::fg(Color::Green).bold());
+    frame
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::Example;
+
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        let example = Example::load(env!("CARGO_MANIFEST_DIR"), "example2", "part1");
+        let last = part1(&example.input).unwrap().last().unwrap();
+        assert_eq!(
+            last.caption(),
+            format!("{} steps from the start", example.answer)
+        );
+
+        let example = Example::load(env!("CARGO_MANIFEST_DIR"), "example7", "part2");
+        let last = part2(&example.input).unwrap().last().unwrap();
+        assert_eq!(last.caption(), format!("{} tiles enclosed", example.answer));
+    }
+}
@@ -0,0 +1,77 @@
+//! Random images of the universe.
+
+use aoc::random::Rng;
+
+/// An image of `size` by `size` pixels with galaxies on about one in ten of them. Some rows and
+/// columns are left empty on purpose, so that the universe has room to expand.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(2);
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.2)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.chance(0.2)).collect();
+
+    let mut input = String::new();
+    for &empty_row in empty_rows.iter() {
+        for &empty_col in empty_cols.iter() {
+            let galaxy = !empty_row && !empty_col && rng.chance(0.1);
+            input.push(if galaxy { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The sum of the distances between all pairs of galaxies.
+    fn distances(rows: &[Vec<char>]) -> i64 {
+        let galaxies: Vec<(i64, i64)> = (0..rows.len())
+            .flat_map(|y| (0..rows[y].len()).map(move |x| (x, y)))
+            .filter(|&(x, y)| rows[y][x] == '#')
+            .map(|(x, y)| (x as i64, y as i64))
+            .collect();
+        let mut sum = 0;
+        for (i, a) in galaxies.iter().enumerate() {
+            for b in galaxies[..i].iter() {
+                sum += (a.0 - b.0).abs() + (a.1 - b.1).abs();
+            }
+        }
+        sum
+    }
+
+    /// The image with every empty row and column written twice.
+    fn expand(rows: &[Vec<char>]) -> Vec<Vec<char>> {
+        let empty_cols: Vec<bool> = (0..rows[0].len())
+            .map(|x| rows.iter().all(|row| row[x] == '.'))
+            .collect();
+        let mut expanded = Vec::new();
+        for row in rows {
+            let mut line = Vec::new();
+            for (&ch, &empty) in row.iter().zip(empty_cols.iter()) {
+                line.extend(std::iter::repeat_n(ch, 1 + empty as usize));
+            }
+            if row.iter().all(|&ch| ch == '.') {
+                expanded.push(line.clone());
+            }
+            expanded.push(line);
+        }
+        expanded
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..30 {
+            let input = input(seed, 30);
+            let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+            let (before, after) = (distances(&rows), distances(&expand(&rows)));
+
+            assert_eq!(part1::process(&input).unwrap(), after);
+            // The distances grow by the same amount for every extra copy of the empty lines.
+            let million = before + 999_999 * (after - before);
+            assert_eq!(part2::process(&input).unwrap(), million);
+        }
+    }
+}
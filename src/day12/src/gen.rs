@@ -0,0 +1,65 @@
+//! Random condition records of the hot springs.
+
+use aoc::random::Rng;
+
+/// `size` records of up to 15 springs, each with at least one group of damaged springs. The
+/// records are made from a real row of springs, so each has at least one arrangement.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+
+    let mut count = 0;
+    while count < size.max(1) {
+        let len = rng.range(1..=15);
+        let springs = rng.string(len, "#.");
+        let lens: Vec<String> = springs
+            .split('.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        if lens.is_empty() {
+            continue;
+        }
+
+        let row: String = springs
+            .chars()
+            .map(|ch| if rng.chance(0.5) { '?' } else { ch })
+            .collect();
+        input.push_str(&format!("{row} {}\n", lens.join(",")));
+        count += 1;
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = input(seed, 50);
+            let mut total = 0;
+            for line in input.lines() {
+                let (row, groups) = line.split_once(' ').unwrap();
+                let lens: Vec<usize> = groups.split(',').map(|len| len.parse().unwrap()).collect();
+                assert_eq!(
+                    part2::arrangements(row, &lens, 1),
+                    part1::process(line).unwrap()
+                );
+
+                // Trying every way to unfold longer rows takes too long.
+                if row.matches('?').count() <= 6 {
+                    let unfolded = format!("{row}?{row} {groups},{groups}");
+                    assert_eq!(
+                        part2::arrangements(row, &lens, 2),
+                        part1::process(&unfolded).unwrap()
+                    );
+                }
+                total += part2::arrangements(row, &lens, 5);
+            }
+            assert_eq!(part2::process(&input).unwrap(), total);
+        }
+    }
+}
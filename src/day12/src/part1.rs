@@ -0,0 +1,57 @@
+use aoc::{
+    parse::{self, Line},
+    ParseError,
+};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    parse::lines(input)
+        .map(parse_line)
+        .map(|line| line.map(|(row, lens)| dfs(row, &lens, String::new())))
+        .sum()
+}
+
+fn parse_line<'a>(line: Line<'a>) -> Result<(&'a str, Vec<usize>), ParseError> {
+    let (row, lens) = line.split_once(line.text, " ")?;
+    line.check_chars(row, ".#?")?;
+
+    let lens = lens
+        .split(',')
+        .map(|num| line.number(num))
+        .collect::<Result<_, _>>()?;
+
+    Ok((row, lens))
+}
+
+fn eq_perm(perm: &str, lens: &[usize]) -> bool {
+    let perm_lens = perm
+        .split('.')
+        .filter(|split| !split.is_empty())
+        .map(|seq| seq.len());
+
+    lens.iter().copied().eq(perm_lens)
+}
+
+fn dfs(row: &str, lens: &[usize], perm: String) -> i64 {
+    if row.is_empty() {
+        return eq_perm(&perm, lens) as i64;
+    }
+
+    let helper = |ch, mut perm: String| {
+        perm.push(ch);
+        dfs(&row[1..], lens, perm)
+    };
+
+    match row.chars().next().unwrap() {
+        '.' => helper('.', perm),
+        '#' => helper('#', perm),
+        '?' => helper('#', perm.clone()) + helper('.', perm),
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+}
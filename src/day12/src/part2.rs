@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use aoc::{
+    parse::{self, Line},
+    ParseError,
+};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let (row, lens) = parse_line(line)?;
+            Ok(arrangements(row, &lens, 5))
+        })
+        .sum()
+}
+
+/// The number of arrangements of the row unfolded into `copies` copies of itself.
+pub(crate) fn arrangements(row: &str, lens: &[usize], copies: usize) -> i64 {
+    let (row_len, lens_len) = ((row.len() + 1) * copies - 1, lens.len() * copies);
+
+    let lens: Vec<_> = lens.iter().copied().cycle().take(lens_len).collect();
+    let row: String = row.chars().chain(['?']).cycle().take(row_len).collect();
+
+    let mut cache = HashMap::new();
+    dfs(0, 0, &row, Some(&lens), &mut cache)
+}
+
+fn parse_line<'a>(line: Line<'a>) -> Result<(&'a str, Vec<usize>), ParseError> {
+    let (row, lens) = line.split_once(line.text, " ")?;
+    line.check_chars(row, ".#?")?;
+
+    let lens = lens
+        .split(',')
+        .map(|num| line.number(num))
+        .collect::<Result<_, _>>()?;
+
+    Ok((row, lens))
+}
+
+fn dfs(
+    start: usize,
+    len: usize,
+    row: &str,
+    lens: Option<&[usize]>,
+    cache: &mut HashMap<(usize, usize, usize), i64>,
+) -> i64 {
+    let target = *lens.and_then(|lens| lens.first()).unwrap_or(&0);
+    let lens_len = lens.map_or(0, |lens| lens.len());
+    let key = (start, len, lens_len);
+
+    if let Some(val) = cache.get(&key) {
+        return *val;
+    }
+
+    if row.is_empty() {
+        let trailing_dots = len == 0 && lens_len == 0;
+        let last_match = len == target && lens_len == 1;
+        return (trailing_dots || last_match) as i64;
+    }
+
+    let hash = |cache| dfs(start + 1, len + 1, &row[1..], lens, cache);
+    let dot = |cache| match len {
+        len if len == target => {
+            let lens = lens.and_then(|lens| lens.get(1..));
+            dfs(start + 1, 0, &row[1..], lens, cache)
+        }
+        0 => dfs(start + 1, 0, &row[1..], lens, cache),
+        _ => 0,
+    };
+
+    let result = match row.chars().next().unwrap() {
+        '.' => dot(cache),
+        '#' => hash(cache),
+        '?' => dot(cache) + hash(cache),
+        _ => unreachable!(),
+    };
+
+    cache.insert(key, result);
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+
+    #[test]
+    fn test_process() {
+        assert_eq!(process("???.### 1,1,3").unwrap(), 1);
+        assert_eq!(process(".??..??...?##. 1,1,3").unwrap(), 16384);
+        assert_eq!(process("?#?#?#?#?#?#?#? 1,3,1,6").unwrap(), 1);
+        assert_eq!(process("????.#...#... 4,1,1").unwrap(), 16);
+        assert_eq!(process("????.######..#####. 1,6,5").unwrap(), 2500);
+        assert_eq!(process("?###???????? 3,2,1").unwrap(), 506250);
+    }
+}
@@ -0,0 +1,110 @@
+//! Random patterns of ash and rocks.
+
+use aoc::random::Rng;
+
+/// `size` patterns of 5 to 17 by 5 to 17 tiles. Each pattern has a single perfect reflection and
+/// a single other one that is off by exactly one smudge.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let patterns: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let rows: Vec<String> = pattern(&mut rng)
+                .into_iter()
+                .map(String::from_iter)
+                .collect();
+            rows.join("\n") + "\n"
+        })
+        .collect();
+    patterns.join("\n")
+}
+
+/// A pattern with a perfect reflection between rows and a smudged one between columns, turned
+/// and flipped at random. The smudge is in a row that the first reflection doesn't reach, so it
+/// doesn't break it.
+fn pattern(rng: &mut Rng) -> Vec<Vec<char>> {
+    loop {
+        let (width, height) = (rng.range(5..=17), rng.range(5..=17));
+        let mut rows: Vec<Vec<char>> = (0..height)
+            .map(|_| rng.string(width, ".#").chars().collect())
+            .collect();
+
+        let row = rng.range(1..=(height - 1) / 2);
+        for y in 0..row {
+            rows[2 * row - 1 - y] = rows[y].clone();
+        }
+        let column = rng.range(1..width);
+        let reach = column.min(width - column);
+        for tiles in rows.iter_mut() {
+            for x in column..column + reach {
+                tiles[x] = tiles[2 * column - 1 - x];
+            }
+        }
+        let (x, y) = (
+            rng.range(column - reach..column + reach),
+            rng.range(2 * row..height),
+        );
+        rows[y][x] = if rows[y][x] == '#' { '.' } else { '#' };
+
+        if rng.chance(0.5) {
+            rows.reverse();
+        }
+        if rng.chance(0.5) {
+            rows = (0..width)
+                .map(|x| rows.iter().map(|tiles| tiles[x]).collect())
+                .collect();
+        }
+        if reflections(&rows, 0).len() == 1 && reflections(&rows, 1).len() == 1 {
+            return rows;
+        }
+    }
+}
+
+/// The summarized values of the reflections that are off by `smudges` tiles.
+fn reflections(rows: &[Vec<char>], smudges: usize) -> Vec<usize> {
+    let columns: Vec<Vec<char>> = (0..rows[0].len())
+        .map(|x| rows.iter().map(|tiles| tiles[x]).collect())
+        .collect();
+    let lines = |lines: &[Vec<char>], factor: usize| {
+        (1..lines.len())
+            .filter(|&at| {
+                let before = lines[..at].iter().rev();
+                let after = lines[at..].iter();
+                let diff: usize = before
+                    .zip(after)
+                    .map(|(a, b)| a.iter().zip(b).filter(|(a, b)| a != b).count())
+                    .sum();
+                diff == smudges
+            })
+            .map(|at| at * factor)
+            .collect::<Vec<_>>()
+    };
+    [lines(rows, 100), lines(&columns, 1)].concat()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..50 {
+            let input = input(seed, 10);
+            let patterns: Vec<Vec<Vec<char>>> = input
+                .split("\n\n")
+                .map(|block| block.lines().map(|line| line.chars().collect()).collect())
+                .collect();
+
+            let perfect = patterns
+                .iter()
+                .map(|rows| reflections(rows, 0)[0])
+                .sum::<usize>();
+            assert_eq!(part1::process(&input).unwrap(), perfect as i64);
+            let smudged = patterns
+                .iter()
+                .map(|rows| reflections(rows, 1)[0])
+                .sum::<usize>();
+            assert_eq!(part2::process(&input).unwrap(), smudged as i64);
+        }
+    }
+}
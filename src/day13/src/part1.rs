@@ -0,0 +1,49 @@
+use std::cmp;
+
+use aoc::{parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut result = 0;
+
+    for block in parse::blocks(input) {
+        parse::check_grid(&block, ".#")?;
+        let lines: Vec<&str> = block.iter().map(|line| line.text).collect();
+        let row = (1..lines.len()).find(|y| test_row(&lines, *y));
+
+        if let Some(row) = row {
+            result += row * 100;
+        } else {
+            let width = lines.first().map_or(0, |line| line.len());
+            result += (1..width)
+                .find(|x| test_column(&lines, *x))
+                .ok_or_else(|| block[0].error(block[0].text, "a pattern with a reflection"))?;
+        }
+    }
+
+    Ok(result as i64)
+}
+
+fn test_row(lines: &[&str], y: usize) -> bool {
+    let half_count = cmp::min(y, lines.len().saturating_sub(y));
+    let top = &lines[y - half_count..y];
+    let bot = &lines[y..y + half_count];
+
+    top.iter().rev().eq(bot.iter())
+}
+
+fn test_column(lines: &[&str], x: usize) -> bool {
+    lines.iter().all(|line| {
+        let half_count = cmp::min(x, line.len().saturating_sub(x));
+        let left = &line[x - half_count..x];
+        let right = &line[x..x + half_count];
+
+        left.chars().rev().eq(right.chars())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+}
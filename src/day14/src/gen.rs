@@ -0,0 +1,80 @@
+//! Random platforms of rocks.
+
+use aoc::random::Rng;
+
+/// A platform of `size` by `size` tiles, with cube-shaped rocks on a tenth of them and rounded
+/// rocks on a fifth.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        for _ in 0..size.max(1) {
+            input.push(match rng.range(0..10) {
+                0 => '#',
+                1 | 2 => 'O',
+                _ => '.',
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    /// Rolls every rounded rock one tile at a time in the direction of `(dx, dy)` until none
+    /// can move any further.
+    fn tilt(rows: &mut [Vec<char>], (dx, dy): (i64, i64)) {
+        let size = rows.len() as i64;
+        let mut moved = true;
+        while moved {
+            moved = false;
+            for y in 0..size {
+                for x in 0..size {
+                    let (nx, ny) = (x + dx, y + dy);
+                    if !(0..size).contains(&nx) || !(0..size).contains(&ny) {
+                        continue;
+                    }
+                    let (x, y, nx, ny) = (x as usize, y as usize, nx as usize, ny as usize);
+                    if rows[y][x] == 'O' && rows[ny][nx] == '.' {
+                        (rows[y][x], rows[ny][nx]) = ('.', 'O');
+                        moved = true;
+                    }
+                }
+            }
+        }
+    }
+
+    fn load(rows: &[Vec<char>]) -> i64 {
+        (0..rows.len())
+            .map(|y| rows[y].iter().filter(|&&ch| ch == 'O').count() * (rows.len() - y))
+            .sum::<usize>() as i64
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let input = input(seed, 10);
+            let mut rows: Vec<Vec<char>> =
+                input.lines().map(|line| line.chars().collect()).collect();
+
+            let mut north = rows.clone();
+            tilt(&mut north, (0, -1));
+            assert_eq!(part1::process(&input).unwrap(), load(&north));
+
+            let mut history = Vec::new();
+            while !history.contains(&rows) {
+                history.push(rows.clone());
+                for dir in [(0, -1), (-1, 0), (0, 1), (1, 0)] {
+                    tilt(&mut rows, dir);
+                }
+            }
+            let start = history.iter().position(|seen| *seen == rows).unwrap();
+            let last = start + (1_000_000_000 - start) % (history.len() - start);
+            assert_eq!(part2::process(&input).unwrap(), load(&history[last]));
+        }
+    }
+}
@@ -0,0 +1,26 @@
+pub mod gen;
+pub mod part1;
+pub mod part2;
+pub mod visualize;
+
+use aoc::{Answer, ParseError, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn day(&self) -> u32 {
+        14
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        part1::process(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        part2::process(input).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        gen::input(seed, size)
+    }
+}
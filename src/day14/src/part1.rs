@@ -0,0 +1,37 @@
+use aoc::{Grid, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut grid = Grid::parse(input, ".#O")?;
+    while roll_step(&mut grid) > 0 {}
+    Ok(north_load(&grid) as i64)
+}
+
+/// Moves every rounded rock that has an empty tile above it up by one, returning how many moved.
+pub(crate) fn roll_step(grid: &mut Grid<char>) -> usize {
+    let mut moved_count = 0;
+    for y in 1..grid.height() {
+        for x in 0..grid.width() {
+            if grid[(x, y - 1)] == '.' && grid[(x, y)] == 'O' {
+                grid.swap((x, y - 1), (x, y));
+                moved_count += 1;
+            }
+        }
+    }
+    moved_count
+}
+
+/// The load of the rounded rocks on the north support beams.
+pub(crate) fn north_load(grid: &Grid<char>) -> usize {
+    grid.rows()
+        .rev()
+        .enumerate()
+        .map(|(i, row)| row.iter().filter(|ch| **ch == 'O').count() * (i + 1))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+}
@@ -0,0 +1,132 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use aoc::{Grid, ParseError};
+
+use crate::part1::north_load;
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut grid = Grid::parse(input, ".#O")?;
+
+    let mut history = HashMap::new();
+    loop {
+        let hash = hash_grid(&grid);
+
+        if let Some(start) = history.get(&hash) {
+            let repeat_count = (1_000_000_000 - start) % (history.len() - start);
+            for _ in 0..repeat_count {
+                cycle(&mut grid);
+            }
+            break;
+        } else {
+            history.insert(hash, history.len());
+            cycle(&mut grid);
+        }
+    }
+
+    Ok(north_load(&grid) as i64)
+}
+
+/// Tilts the platform north, west, south and east, in that order.
+pub(crate) fn cycle(grid: &mut Grid<char>) {
+    for _ in 0..4 {
+        *grid = grid.rotate_cw();
+        roll_stones(grid);
+    }
+}
+
+fn roll_stones(grid: &mut Grid<char>) {
+    for row in grid.rows_mut() {
+        row.split_mut(|ch| *ch == '#')
+            .filter(|slice| !slice.is_empty())
+            .for_each(|slice| slice.sort_unstable());
+    }
+}
+
+fn hash_grid(grid: &Grid<char>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    grid.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+
+    #[test]
+    fn test_cycle() {
+        let mut grid = Grid::parse(
+            "
+            O....#....
+            O.OO#....#
+            .....##...
+            OO.#O....O
+            .O.....O#.
+            O.#..O.#.#
+            ..O..#O..O
+            .......O..
+            #....###..
+            #OO..#....",
+            ".#O",
+        )
+        .unwrap();
+
+        cycle(&mut grid);
+        assert_eq_grid(
+            &grid,
+            ".....#....
+            ....#...O#
+            ...OO##...
+            .OO#......
+            .....OOO#.
+            .O#...O#.#
+            ....O#....
+            ......OOOO
+            #...O###..
+            #..OO#....",
+        );
+
+        cycle(&mut grid);
+        assert_eq_grid(
+            &grid,
+            ".....#....
+            ....#...O#
+            .....##...
+            ..O#......
+            .....OOO#.
+            .O#...O#.#
+            ....O#...O
+            .......OOO
+            #..OO###..
+            #.OOO#...O",
+        );
+
+        cycle(&mut grid);
+        assert_eq_grid(
+            &grid,
+            ".....#....
+            ....#...O#
+            .....##...
+            ..O#......
+            .....OOO#.
+            .O#...O#.#
+            ....O#...O
+            .......OOO
+            #...O###.O
+            #.OOO#...O",
+        );
+    }
+
+    fn assert_eq_grid(grid: &Grid<char>, expected: &str) {
+        let expected = Grid::parse(expected, ".#O").unwrap();
+        assert_eq!(
+            grid.to_string(),
+            expected.to_string(),
+            "Grids are not equal"
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Frames of the rocks rolling across the platform, for `--visualize`.
+
+use std::{collections::HashSet, iter};
+
+use aoc::{
+    render::{Cell, Color, Frame, Style},
+    Grid, ParseError,
+};
+
+use crate::{
+    part1::{north_load, roll_step},
+    part2::cycle,
+};
+
+/// A frame after every step the rocks roll north.
+pub fn part1(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let mut grid = Grid::parse(input, ".#O")?;
+    let mut moving = true;
+    Ok(iter::from_fn(move || {
+        let frame = moving.then(|| draw(&grid, "tilting north"));
+        moving = moving && roll_step(&mut grid) > 0;
+        frame
+    }))
+}
+
+/// A frame after every spin cycle, until the platform is back in a state it has been in before.
+pub fn part2(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let mut grid = Grid::parse(input, ".#O")?;
+    let mut seen = HashSet::new();
+    let mut count = 0;
+    Ok(iter::from_fn(move || {
+        if !seen.insert(grid.clone()) {
+            return None;
+        }
+        let frame = draw(&grid, &format!("cycle {count}"));
+        cycle(&mut grid);
+        count += 1;
+        Some(frame)
+    }))
+}
+
+fn draw(grid: &Grid<char>, caption: &str) -> Frame {
+    Frame::new(grid, |&ch| match ch {
+        'O' => Cell::new('O', Style::fg(Color::Yellow).bold()),
+        '#' => Cell::new('#', Style::fg(Color::Gray)),
+        ch => Cell::plain(ch),
+    })
+    .with_caption(format!("{caption}, load {}", north_load(grid)))
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::Example;
+
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        let example = Example::load(env!("CARGO_MANIFEST_DIR"), "example", "part1");
+        let last = part1(&example.input).unwrap().last().unwrap();
+        assert_eq!(
+            last.caption(),
+            format!("tilting north, load {}", example.answer)
+        );
+
+        let frames: Vec<Frame> = part2(&example.input).unwrap().collect();
+        // The example repeats every 7 cycles, starting after the third.
+        assert_eq!(frames.len(), 10);
+    }
+}
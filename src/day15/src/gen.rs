@@ -0,0 +1,69 @@
+//! Random initialization sequences.
+
+use aoc::random::Rng;
+
+/// A sequence of `size` steps on a few labels, so that lenses get replaced and removed often. A
+/// third of the steps remove a lens, the others insert one with a focal length from 1 to 9.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let labels: Vec<String> = (0..size / 4 + 1)
+        .map(|_| {
+            let len = rng.range(1..=6);
+            rng.string(len, "abcdefghijklmnopqrstuvwxyz")
+        })
+        .collect();
+
+    let steps: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let label = rng.pick(&labels);
+            match rng.chance(1.0 / 3.0) {
+                true => format!("{label}-"),
+                false => format!("{label}={}", rng.range(1..=9)),
+            }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    fn hash(text: &str) -> usize {
+        text.bytes()
+            .fold(0, |acc, ch| (acc + ch as usize) * 17 % 256)
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..50 {
+            let input = input(seed, 200);
+            let steps: Vec<&str> = input.trim_end().split(',').collect();
+            let hashes = steps.iter().map(|step| hash(step)).sum::<usize>();
+            assert_eq!(part1::process(&input).unwrap(), hashes as i64);
+
+            let mut boxes: Vec<Vec<(&str, usize)>> = vec![Vec::new(); 256];
+            for step in steps {
+                let label = step.trim_end_matches('-').split('=').next().unwrap();
+                let lenses = &mut boxes[hash(label)];
+                let slot = lenses.iter().position(|lens| lens.0 == label);
+                match (step.split_once('='), slot) {
+                    (Some((_, focal)), Some(slot)) => lenses[slot].1 = focal.parse().unwrap(),
+                    (Some((_, focal)), None) => lenses.push((label, focal.parse().unwrap())),
+                    (None, Some(slot)) => _ = lenses.remove(slot),
+                    (None, None) => {}
+                }
+            }
+            let power: usize = (1..)
+                .zip(boxes)
+                .flat_map(|(box_num, lenses)| {
+                    (1..)
+                        .zip(lenses)
+                        .map(move |(slot, (_, focal))| box_num * slot * focal)
+                })
+                .sum();
+            assert_eq!(part2::process(&input).unwrap(), power as i64);
+        }
+    }
+}
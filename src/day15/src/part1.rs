@@ -0,0 +1,24 @@
+use aoc::{parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    Ok(parse::lines(input)
+        .flat_map(|line| {
+            line.text
+                .split(',')
+                .map(|instruction| hash(instruction) as i64)
+        })
+        .sum())
+}
+
+fn hash(input: &str) -> u8 {
+    input
+        .chars()
+        .fold(0, |acc, ch| acc.wrapping_add(ch as u8).wrapping_mul(17))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+}
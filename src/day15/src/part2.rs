@@ -0,0 +1,86 @@
+use aoc::{parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut map = HashMap::new();
+    for line in parse::lines(input) {
+        for instruction in line.text.split(',') {
+            if let Some(key) = instruction.strip_suffix('-') {
+                map.remove(key);
+            } else {
+                let (key, val) = line.split_once(instruction, "=")?;
+                map.insert(key, line.number(val)?);
+            }
+        }
+    }
+
+    let mut sum = 0;
+    for (i, bucket) in map.buckets.iter().enumerate() {
+        let box_num = (i + 1) as i64;
+        for (i, pair) in bucket.iter().enumerate() {
+            let slot_num = (i + 1) as i64;
+            sum += box_num * slot_num * pair.val as i64;
+        }
+    }
+    Ok(sum)
+}
+
+type Bucket<'a> = Vec<KeyVal<'a>>;
+
+#[derive(Default, Debug, Clone)]
+struct KeyVal<'a> {
+    key: &'a str,
+    val: i8,
+}
+
+struct HashMap<'a> {
+    buckets: [Bucket<'a>; 256],
+    hash: Box<dyn Fn(&str) -> u8>,
+}
+
+impl<'a> HashMap<'a> {
+    fn new() -> Self {
+        let hash = |str: &str| -> u8 {
+            str.chars()
+                .fold(0, |acc, ch| acc.wrapping_add(ch as u8).wrapping_mul(17))
+        };
+
+        const ARRAY_REPEAT_VALUE: Vec<KeyVal> = Vec::new();
+        Self {
+            hash: Box::new(hash),
+            buckets: [ARRAY_REPEAT_VALUE; 256],
+        }
+    }
+
+    fn insert(&mut self, key: &'a str, val: i8) -> Option<i8> {
+        let hash = (self.hash)(key) as usize;
+        let bucket = &mut self.buckets[hash];
+
+        if let Some(pair) = bucket.iter_mut().find(|pair| pair.key == key) {
+            let old_val = pair.val;
+            pair.val = val;
+            Some(old_val)
+        } else {
+            bucket.push(KeyVal { key, val });
+            None
+        }
+    }
+
+    fn remove(&mut self, key: &str) -> Option<i8> {
+        let hash = (self.hash)(key) as usize;
+        let bucket = &mut self.buckets[hash];
+
+        bucket
+            .iter()
+            .enumerate()
+            .find(|(_i, pair)| pair.key == key)
+            .map(|(i, _pair)| i)
+            .map(|idx| bucket.remove(idx).val)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+}
@@ -0,0 +1,79 @@
+//! Random contraptions of mirrors and splitters.
+
+use aoc::random::Rng;
+
+/// A contraption of `size` by `size` tiles with a mirror or splitter on about one in five.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        for _ in 0..size.max(1) {
+            input.push(match rng.chance(0.2) {
+                true => *rng.pick(&['|', '-', '/', '\\']),
+                false => '.',
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The number of tiles the beam entering `start` going `(dx, dy)` passes, following it and
+    /// every beam it splits into tile by tile.
+    fn energized(rows: &[Vec<char>], start: (i64, i64), dir: (i64, i64)) -> usize {
+        let size = rows.len() as i64;
+        let mut seen = HashSet::new();
+        let mut beams = vec![(start, dir)];
+        while let Some(((x, y), (dx, dy))) = beams.pop() {
+            if !(0..size).contains(&x) || !(0..size).contains(&y) || !seen.insert((x, y, dx, dy)) {
+                continue;
+            }
+            let dirs = match rows[y as usize][x as usize] {
+                '/' => vec![(-dy, -dx)],
+                '\\' => vec![(dy, dx)],
+                '|' if dx != 0 => vec![(0, -1), (0, 1)],
+                '-' if dy != 0 => vec![(-1, 0), (1, 0)],
+                _ => vec![(dx, dy)],
+            };
+            beams.extend(
+                dirs.into_iter()
+                    .map(|(dx, dy)| ((x + dx, y + dy), (dx, dy))),
+            );
+        }
+        let tiles: HashSet<(i64, i64)> = seen.into_iter().map(|(x, y, _, _)| (x, y)).collect();
+        tiles.len()
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let input = input(seed, 12);
+            let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+            assert_eq!(
+                part1::process(&input).unwrap(),
+                energized(&rows, (0, 0), (1, 0)) as i64
+            );
+
+            let best = (0..12)
+                .flat_map(|i| {
+                    [
+                        ((i, 0), (0, 1)),
+                        ((i, 11), (0, -1)),
+                        ((0, i), (1, 0)),
+                        ((11, i), (-1, 0)),
+                    ]
+                })
+                .map(|(start, dir)| energized(&rows, start, dir))
+                .max()
+                .unwrap();
+            assert_eq!(part2::process(&input).unwrap(), best as i64);
+        }
+    }
+}
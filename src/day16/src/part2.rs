@@ -0,0 +1,89 @@
+use std::collections::VecDeque;
+
+use aoc::{Direction, Grid, ParseError, Vec2};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let grid = Grid::parse(input, ".|-/\\")?;
+
+    Ok(starts(&grid)
+        .map(|start| energization(&grid, start))
+        .max()
+        .unwrap())
+}
+
+/// Every tile on the edge, entered from outside of the grid.
+pub(crate) fn starts(grid: &Grid<char>) -> impl Iterator<Item = Node> {
+    use Direction::*;
+    let height = grid.height() as i64;
+    let width = grid.width() as i64;
+
+    (0..width)
+        .map(|x| Node::new(x, 0, Down))
+        .chain((0..height).map(move |y| Node::new(0, y, Right)))
+        .chain((0..width).map(move |x| Node::new(x, height - 1, Up)))
+        .chain((0..height).map(move |y| Node::new(width - 1, y, Left)))
+}
+
+pub(crate) fn energization(grid: &Grid<char>, start: Node) -> i64 {
+    let mut visited = Grid::filled(grid.width(), grid.height(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let pos = (node.pos.x as usize, node.pos.y as usize);
+        visited[pos] |= node.bit();
+
+        queue.extend(node.neighbours(grid[pos]).into_iter().filter(|node| {
+            visited
+                .checked_pos(node.pos.x, node.pos.y)
+                .is_some_and(|pos| visited[pos] & node.bit() == 0)
+        }));
+    }
+
+    visited.iter().filter(|visited| **visited != 0).count() as i64
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub(crate) struct Node {
+    pub(crate) pos: Vec2,
+    pub(crate) dir: Direction,
+}
+
+impl Node {
+    fn new(x: i64, y: i64, dir: Direction) -> Self {
+        Self {
+            pos: Vec2::new(x, y),
+            dir,
+        }
+    }
+
+    /// The bit of this node's direction in the mask of directions a tile was entered from.
+    fn bit(&self) -> u8 {
+        1 << self.dir as u8
+    }
+
+    fn neighbours(&self, ch: char) -> Vec<Node> {
+        use Direction::*;
+
+        let step = |dir: Direction| Node {
+            pos: self.pos + dir.offset(),
+            dir,
+        };
+
+        match (ch, self.dir) {
+            ('-', dir) if dir.is_vertical() => vec![step(Left), step(Right)],
+            ('|', dir) if !dir.is_vertical() => vec![step(Up), step(Down)],
+            ('/', dir) if dir.is_vertical() => vec![step(dir.turn_right())],
+            ('/', dir) => vec![step(dir.turn_left())],
+            ('\\', dir) if dir.is_vertical() => vec![step(dir.turn_left())],
+            ('\\', dir) => vec![step(dir.turn_right())],
+            (_, dir) => vec![step(dir)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+}
@@ -0,0 +1,91 @@
+//! Frames of the beam spreading through the contraption, for `--visualize`.
+
+use std::iter;
+
+use aoc::{
+    render::{Cell, Color, Frame, Style},
+    Direction, Grid, ParseError,
+};
+
+use crate::{part1::Node, part2};
+
+const ENERGIZED: Style = Style::fg(Color::Black).on(Color::Yellow);
+const BEAM: Style = Style::fg(Color::Red).on(Color::Yellow).bold();
+
+/// The beam entering the top left tile heading right.
+pub fn part1(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let grid = Grid::parse(input, ".|-/\\")?;
+    Ok(propagate(grid, Node::new(0, 0, Direction::Right)))
+}
+
+/// The beam from the edge that energizes the most tiles.
+pub fn part2(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let grid = Grid::parse(input, ".|-/\\")?;
+    let best = part2::starts(&grid)
+        .max_by_key(|start| part2::energization(&grid, start.clone()))
+        .unwrap();
+    Ok(propagate(grid, Node::new(best.pos.x, best.pos.y, best.dir)))
+}
+
+/// A frame for every step the beams take, with the tip of each beam drawn as an arrow.
+fn propagate(grid: Grid<char>, start: Node) -> impl Iterator<Item = Frame> {
+    let mut visited = Grid::filled(grid.width(), grid.height(), 0_u8);
+    let mut frame = Frame::new(&grid, |&ch| match ch {
+        '.' => Cell::new('.', Style::fg(Color::Gray)),
+        ch => Cell::new(ch, Style::fg(Color::Cyan).bold()),
+    });
+    let mut beams = vec![start];
+
+    iter::from_fn(move || {
+        if beams.is_empty() {
+            return None;
+        }
+
+        let mut next = Vec::new();
+        for node in beams.iter() {
+            let pos = (node.pos.x as usize, node.pos.y as usize);
+            visited[pos] |= node.bit();
+            next.extend(node.neighbours(grid[pos]).into_iter().filter(|node| {
+                visited
+                    .checked_pos(node.pos.x, node.pos.y)
+                    .is_some_and(|pos| visited[pos] & node.bit() == 0)
+            }));
+        }
+
+        let tips: Vec<_> = beams
+            .iter()
+            .map(|node| ((node.pos.x as usize, node.pos.y as usize), node.dir))
+            .collect();
+        frame.highlight(tips.iter().map(|&(pos, _)| pos), ENERGIZED);
+        beams = next;
+
+        let energized = visited.iter().filter(|&&mask| mask != 0).count();
+        let mut current = frame.clone();
+        current.beams(tips, BEAM);
+        Some(current.with_caption(format!("{energized} tiles energized")))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::Example;
+
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        let example = Example::load(env!("CARGO_MANIFEST_DIR"), "example", "part1");
+        let last = part1(&example.input).unwrap().last().unwrap();
+        assert_eq!(
+            last.caption(),
+            format!("{} tiles energized", example.answer)
+        );
+
+        let example = Example::load(env!("CARGO_MANIFEST_DIR"), "example", "part2");
+        let last = part2(&example.input).unwrap().last().unwrap();
+        assert_eq!(
+            last.caption(),
+            format!("{} tiles energized", example.answer)
+        );
+    }
+}
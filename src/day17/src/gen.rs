@@ -0,0 +1,68 @@
+//! Random maps of heat loss.
+
+use aoc::random::Rng;
+
+/// A map of `size` by `size` city blocks, at least 5 by 5 so that the ultra crucible can reach
+/// the factory.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(5);
+    (0..size)
+        .map(|_| rng.string(size, "123456789") + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cmp::Reverse, collections::BinaryHeap};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The least heat lost by a crucible that moves between `min` and `max` blocks before it
+    /// turns, by Dijkstra's algorithm on every block, direction and length of the last run.
+    fn least_heat(rows: &[Vec<u64>], min: usize, max: usize) -> u64 {
+        let size = rows.len();
+        let dirs = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+        let mut best = vec![vec![vec![vec![u64::MAX; max + 1]; 4]; size]; size];
+        let mut queue = BinaryHeap::from([Reverse((0, 0, 0, 0, 0)), Reverse((0, 0, 0, 1, 0))]);
+
+        while let Some(Reverse((heat, x, y, dir, run))) = queue.pop() {
+            if (x, y) == (size - 1, size - 1) && run >= min {
+                return heat;
+            }
+            for next in 0..4 {
+                let straight = next == dir;
+                let allowed = match straight {
+                    true => run < max,
+                    false => next != (dir + 2) % 4 && (run >= min || run == 0),
+                };
+                let (nx, ny) = (x as i64 + dirs[next].0, y as i64 + dirs[next].1);
+                if !allowed || !(0..size as i64).contains(&nx) || !(0..size as i64).contains(&ny) {
+                    continue;
+                }
+                let (nx, ny) = (nx as usize, ny as usize);
+                let next_run = if straight { run + 1 } else { 1 };
+                let next_heat = heat + rows[ny][nx];
+                if next_heat < best[ny][nx][next][next_run] {
+                    best[ny][nx][next][next_run] = next_heat;
+                    queue.push(Reverse((next_heat, nx, ny, next, next_run)));
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let input = input(seed, 12);
+            let rows: Vec<Vec<u64>> = input
+                .lines()
+                .map(|line| line.bytes().map(|ch| (ch - b'0') as u64).collect())
+                .collect();
+            assert_eq!(part1::process(&input).unwrap(), least_heat(&rows, 1, 3));
+            assert_eq!(part2::process(&input).unwrap(), least_heat(&rows, 4, 10));
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use aoc::{
+    graph,
+    graph::{Graph, Path},
+    Direction, Grid, ParseError, Vec2,
+};
+
+pub fn process(input: &str) -> Result<u64, ParseError> {
+    let grid = parse_city(input)?;
+    best_path(grid)
+        .map(|path| path.cost)
+        .ok_or_else(|| no_path(input))
+}
+
+/// The heat loss of each city block.
+pub(crate) fn parse_city(input: &str) -> Result<Grid<u64>, ParseError> {
+    Ok(Grid::parse(input, "0123456789")?.map(|ch| ch.to_digit(10).unwrap() as u64))
+}
+
+/// The error for a city where no path reaches the bottom right block.
+pub(crate) fn no_path(input: &str) -> ParseError {
+    ParseError::at_end(input, "a path that reaches the end")
+}
+
+/// The path from the top left to the bottom right block that loses the least heat.
+pub(crate) fn best_path(grid: Grid<u64>) -> Option<Path<Node>> {
+    let end = Vec2::new(grid.width() as i64 - 1, grid.height() as i64 - 1);
+    let start = (Vec2::ZERO, DirRun { dir: None, len: 0 });
+    let heuristic = |&(pos, _): &Node| end.manhattan(pos) as u64;
+    graph::a_star(&City(grid), start, |&(pos, _)| pos == end, heuristic)
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub(crate) struct DirRun {
+    /// The direction of the last steps, which is `None` at the start.
+    dir: Option<Direction>,
+    len: i32,
+}
+
+pub(crate) type Node = (Vec2, DirRun);
+
+/// The city blocks, with the heat loss of entering each one.
+struct City(Grid<u64>);
+
+impl Graph for City {
+    type Node = Node;
+
+    fn neighbours(&self, &(pos, run): &Node) -> impl Iterator<Item = Node> {
+        run.neighbours().filter_map(move |run| {
+            let pos = pos + run.dir?.offset();
+            self.0.checked_pos(pos.x, pos.y)?;
+            Some((pos, run))
+        })
+    }
+
+    fn weight(&self, _from: &Node, &(pos, _): &Node) -> u64 {
+        self.0[(pos.x as usize, pos.y as usize)]
+    }
+}
+
+impl DirRun {
+    fn neighbours(self) -> impl Iterator<Item = Self> {
+        use Direction::*;
+
+        [Up, Down, Left, Right]
+            .into_iter()
+            .filter(move |&dir| self.dir.is_none_or(|prev| dir != prev.opposite()))
+            .map(move |dir| {
+                let same_dir = self.dir.is_none_or(|prev| prev == dir);
+                let len = if same_dir { self.len + 1 } else { 1 };
+                Self {
+                    dir: Some(dir),
+                    len,
+                }
+            })
+            .filter(|run| run.len <= 3)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example1);
+}
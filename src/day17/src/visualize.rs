@@ -0,0 +1,67 @@
+//! Frames of the crucible following the path that loses the least heat, for `--visualize`.
+
+use aoc::{
+    grid::Pos,
+    render::{Cell, Color, Frame, Style},
+    Grid, ParseError, Vec2,
+};
+
+use crate::{part1, part2};
+
+const PATH: Style = Style::fg(Color::White).on(Color::Blue).bold();
+
+pub fn part1(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let grid = part1::parse_city(input)?;
+    let path = part1::best_path(grid.clone()).ok_or_else(|| part1::no_path(input))?;
+    Ok(follow(
+        grid,
+        path.nodes.iter().map(|(pos, _)| to_pos(*pos)).collect(),
+    ))
+}
+
+pub fn part2(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let grid = part2::parse_city(input)?;
+    let path = part2::best_path(grid.clone()).ok_or_else(|| part1::no_path(input))?;
+    Ok(follow(
+        grid,
+        path.nodes.iter().map(|(pos, _)| to_pos(*pos)).collect(),
+    ))
+}
+
+fn to_pos(pos: Vec2) -> Pos {
+    (pos.x as usize, pos.y as usize)
+}
+
+/// A frame for every block along `path`, with the blocks shaded from cool to hot.
+fn follow(grid: Grid<u64>, path: Vec<Pos>) -> impl Iterator<Item = Frame> {
+    let frame = Frame::new(&grid, |&heat| {
+        let shade = (heat * 255 / 9) as u8;
+        let ch = char::from_digit(heat as u32, 10).unwrap();
+        Cell::new(ch, Style::fg(Color::Rgb(shade, 64, 255 - shade)))
+    });
+
+    let mut heat_loss = 0;
+    (1..=path.len()).map(move |len| {
+        // The heat of the first block isn't lost, since the crucible starts there.
+        if len > 1 {
+            heat_loss += grid[path[len - 1]];
+        }
+        let mut current = frame.clone();
+        current.path(&path[..len], PATH);
+        current.with_caption(format!("heat loss {heat_loss}"))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::Example;
+
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        let example = Example::load(env!("CARGO_MANIFEST_DIR"), "example1", "part1");
+        let last = part1(&example.input).unwrap().last().unwrap();
+        assert_eq!(last.caption(), format!("heat loss {}", example.answer));
+    }
+}
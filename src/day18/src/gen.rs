@@ -0,0 +1,129 @@
+//! Random dig plans.
+
+use aoc::{random, random::Rng, Direction};
+
+/// A dig plan around a random shape on a `size` by `size` grid, whose columns and rows are up to
+/// ten meters wide. The colors describe the same shape stretched much wider, so that every plan
+/// stays below a million meters across. Half of the plans run counterclockwise.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+    let region = random::region(&mut rng, size, size, size * size / 3 + 1);
+    let (start, steps) = random::outline(&region);
+
+    let span = 1_000_000 / size as i64;
+    let widths: Vec<(i64, i64)> = (0..2 * size)
+        .map(|_| (rng.range(1..=10), rng.range(1..=span)))
+        .collect();
+
+    // Columns come first in the widths, then rows.
+    let mut corner = start;
+    let mut trenches: Vec<(Direction, i64, i64)> = Vec::new();
+    for dir in steps {
+        let (x, y) = corner;
+        let (gap, next) = match dir {
+            Direction::Up => (size + y - 1, (x, y - 1)),
+            Direction::Right => (x, (x + 1, y)),
+            Direction::Down => (size + y, (x, y + 1)),
+            Direction::Left => (x - 1, (x - 1, y)),
+        };
+        let (len, color) = widths[gap];
+        match trenches.last_mut() {
+            Some(last) if last.0 == dir => (last.1, last.2) = (last.1 + len, last.2 + color),
+            _ => trenches.push((dir, len, color)),
+        }
+        corner = next;
+    }
+
+    if rng.chance(0.5) {
+        trenches.reverse();
+        for trench in trenches.iter_mut() {
+            trench.0 = trench.0.opposite();
+        }
+    }
+    let turn = rng.range(0..trenches.len());
+    trenches.rotate_left(turn);
+
+    trenches
+        .into_iter()
+        .map(|(dir, len, color)| {
+            let (letter, digit) = match dir {
+                Direction::Right => ('R', 0),
+                Direction::Down => ('D', 1),
+                Direction::Left => ('L', 2),
+                Direction::Up => ('U', 3),
+            };
+            format!("{letter} {len} (#{color:05x}{digit})\n")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The number of cubes in the lagoon, digging out the trench and filling in everything the
+    /// outside can't reach.
+    fn lagoon(input: &str) -> usize {
+        let mut trench = vec![(0, 0)];
+        for line in input.lines() {
+            let mut parts = line.split(' ');
+            let dir = Direction::from_char(parts.next().unwrap().parse().unwrap()).unwrap();
+            let len: i64 = parts.next().unwrap().parse().unwrap();
+            for _ in 0..len {
+                let (x, y) = *trench.last().unwrap();
+                trench.push((x + dir.offset().x, y + dir.offset().y));
+            }
+        }
+
+        // Leave a ring of ground around the trench, so the outside is connected.
+        let min_x = trench.iter().map(|pos| pos.0).min().unwrap() - 1;
+        let min_y = trench.iter().map(|pos| pos.1).min().unwrap() - 1;
+        let width = (trench.iter().map(|pos| pos.0).max().unwrap() - min_x + 2) as usize;
+        let height = (trench.iter().map(|pos| pos.1).max().unwrap() - min_y + 2) as usize;
+        let mut ground = vec![vec![true; width]; height];
+        for (x, y) in trench {
+            ground[(y - min_y) as usize][(x - min_x) as usize] = false;
+        }
+
+        let mut outside = 0;
+        let mut stack = vec![(0, 0)];
+        while let Some((x, y)) = stack.pop() {
+            if !std::mem::replace(&mut ground[y][x], false) {
+                continue;
+            }
+            outside += 1;
+            let next = [
+                (x + 1, y),
+                (x, y + 1),
+                (x.wrapping_sub(1), y),
+                (x, y.wrapping_sub(1)),
+            ];
+            stack.extend(next.into_iter().filter(|&(x, y)| x < width && y < height));
+        }
+        width * height - outside
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..30 {
+            let input = input(seed, 10);
+            assert_eq!(part1::process(&input).unwrap(), lagoon(&input) as i64);
+
+            let decoded: String = input
+                .lines()
+                .map(|line| {
+                    let hex = &line[line.find('#').unwrap() + 1..line.len() - 1];
+                    let len = i64::from_str_radix(&hex[..5], 16).unwrap();
+                    let dir = ['R', 'D', 'L', 'U'][hex[5..].parse::<usize>().unwrap()];
+                    format!("{dir} {len} (#000000)\n")
+                })
+                .collect();
+            assert_eq!(
+                part2::process(&input).unwrap(),
+                part1::process(&decoded).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use aoc::{parse, Direction, ParseError, Vec2};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let lines = parse::lines(input)
+        .map(|line| {
+            let (dir, len) = line.split_once(line.text, " ")?;
+            let (len, _color) = line.split_once(len, " ")?;
+            let dir = dir
+                .parse()
+                .ok()
+                .filter(|ch| "UDLR".contains(*ch))
+                .and_then(Direction::from_char)
+                .ok_or_else(|| line.error(dir, parse::one_of("UDLR")))?;
+            Ok((dir, line.number::<i64>(len)?))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    let vertices: Vec<Vec2> = lines
+        .iter()
+        .scan(Vec2::ZERO, |pos, &(dir, len)| {
+            *pos += dir.offset() * len;
+            Some(*pos)
+        })
+        .collect();
+
+    // Shoelace area formula and Pick's theorem, the area is negative for counterclockwise plans
+    let perimeter: i64 = lines.iter().map(|(_dir, len)| len).sum();
+    let area: i64 = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(pos, next)| (pos.y + next.y) * (pos.x - next.x))
+        .sum();
+
+    Ok((area.abs() + perimeter) / 2 + 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+}
@@ -0,0 +1,50 @@
+use aoc::{parse, Direction, ParseError, Vec2};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let lines = parse::lines(input)
+        .map(|line| {
+            let (_dir_len, color) = line.split_once(line.text, "(")?;
+            let hex = line.strip_prefix(color, "#")?;
+            let hex = line.strip_suffix(hex, ")")?;
+            line.check_chars(hex, "0123456789abcdef")?;
+            if hex.len() != 6 {
+                return Err(line.error(hex, "6 hex digits"));
+            }
+
+            let len = i64::from_str_radix(&hex[0..5], 16).unwrap();
+            let dir = match &hex[5..] {
+                "0" => Direction::Right,
+                "1" => Direction::Down,
+                "2" => Direction::Left,
+                "3" => Direction::Up,
+                digit => return Err(line.error(digit, parse::one_of("0123"))),
+            };
+            Ok((dir, len))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    let vertices: Vec<Vec2> = lines
+        .iter()
+        .scan(Vec2::ZERO, |pos, &(dir, len)| {
+            *pos += dir.offset() * len;
+            Some(*pos)
+        })
+        .collect();
+
+    // Shoelace area formula and Pick's theorem, the area is negative for counterclockwise plans
+    let perimeter: i64 = lines.iter().map(|(_dir, len)| len).sum();
+    let area: i64 = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(pos, next)| (pos.y + next.y) * (pos.x - next.x))
+        .sum();
+
+    Ok((area.abs() + perimeter) / 2 + 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+}
@@ -0,0 +1,144 @@
+//! Random workflows and parts.
+
+use std::collections::HashSet;
+
+use aoc::random::Rng;
+
+/// About `size` workflows with up to three conditions each, and `size` parts. The workflows form
+/// a tree below `in`, so every part ends up accepted or rejected.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    let mut names = HashSet::from(["in".to_owned(), "A".to_owned(), "R".to_owned()]);
+    let mut pending = vec!["in".to_owned()];
+    let mut workflows = Vec::new();
+    while let Some(name) = pending.pop() {
+        let mut next =
+            |rng: &mut Rng| match workflows.len() + pending.len() < size && rng.chance(0.5) {
+                true => loop {
+                    let len = rng.range(2..=3);
+                    let name = rng.string(len, "abcdefghijklmnopqrstuvwxyz");
+                    if names.insert(name.clone()) {
+                        pending.push(name.clone());
+                        break name;
+                    }
+                },
+                false => rng.pick(&["A", "R"]).to_string(),
+            };
+
+        let mut rules: Vec<String> = (0..rng.range(1..=3))
+            .map(|_| {
+                let axis = *rng.pick(&['x', 'm', 'a', 's']);
+                let comparison = *rng.pick(&['<', '>']);
+                let value = rng.range(1..=4000);
+                format!("{axis}{comparison}{value}:{}", next(&mut rng))
+            })
+            .collect();
+        rules.push(next(&mut rng));
+        workflows.push(format!("{name}{{{}}}\n", rules.join(",")));
+    }
+    rng.shuffle(&mut workflows);
+
+    let parts: Vec<String> = (0..size)
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|_| rng.range(1..=4000));
+            format!("{{x={x},m={m},a={a},s={s}}}\n")
+        })
+        .collect();
+    format!("{}\n{}", workflows.concat(), parts.concat())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{part1, part2};
+
+    /// Whether the workflows accept the part with the ratings `xmas`.
+    fn accepts(workflows: &HashMap<&str, Vec<&str>>, xmas: [i64; 4]) -> bool {
+        let mut name = "in";
+        while name != "A" && name != "R" {
+            name = workflows[name]
+                .iter()
+                .find_map(|rule| match rule.split_once(':') {
+                    Some((cond, next)) => {
+                        let rating = xmas["xmas".find(&cond[..1]).unwrap()];
+                        let value: i64 = cond[2..].parse().unwrap();
+                        let holds = match &cond[1..2] {
+                            "<" => rating < value,
+                            _ => rating > value,
+                        };
+                        holds.then_some(next)
+                    }
+                    None => Some(rule),
+                })
+                .unwrap();
+        }
+        name == "A"
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let input = input(seed, 6);
+            let (workflows, parts) = input.split_once("\n\n").unwrap();
+            let workflows: HashMap<&str, Vec<&str>> = workflows
+                .lines()
+                .map(|line| {
+                    let (name, rules) = line.trim_end_matches('}').split_once('{').unwrap();
+                    (name, rules.split(',').collect())
+                })
+                .collect();
+
+            let ratings: i64 = parts
+                .lines()
+                .map(|line| {
+                    let ratings = line.trim_matches(['{', '}']).split(',');
+                    let mut ratings = ratings.map(|rating| rating[2..].parse().unwrap());
+                    [(); 4].map(|_| ratings.next().unwrap())
+                })
+                .filter(|&xmas| accepts(&workflows, xmas))
+                .map(|xmas| xmas.iter().sum::<i64>())
+                .sum();
+            assert_eq!(part1::process(&input).unwrap(), ratings);
+
+            // All parts whose ratings fall between the same values of the conditions take the
+            // same way, so it is enough to sort one of them and count the rest along.
+            let mut bounds: [Vec<i64>; 4] = [(); 4].map(|_| vec![1, 4001]);
+            for cond in workflows
+                .values()
+                .flatten()
+                .filter_map(|rule| rule.split_once(':'))
+            {
+                let axis = "xmas".find(&cond.0[..1]).unwrap();
+                let value: i64 = cond.0[2..].parse().unwrap();
+                bounds[axis].push(if &cond.0[1..2] == "<" {
+                    value
+                } else {
+                    value + 1
+                });
+            }
+            for axis in bounds.iter_mut() {
+                axis.sort_unstable();
+                axis.dedup();
+            }
+
+            let mut accepted = 0;
+            for x in bounds[0].windows(2) {
+                for m in bounds[1].windows(2) {
+                    for a in bounds[2].windows(2) {
+                        for s in bounds[3].windows(2) {
+                            if accepts(&workflows, [x[0], m[0], a[0], s[0]]) {
+                                let sizes = [x, m, a, s].map(|bounds| bounds[1] - bounds[0]);
+                                accepted += sizes.iter().product::<i64>();
+                            }
+                        }
+                    }
+                }
+            }
+            assert_eq!(part2::process(&input).unwrap(), accepted);
+        }
+    }
+}
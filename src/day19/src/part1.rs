@@ -0,0 +1,222 @@
+use std::{collections::HashMap, iter};
+
+use aoc::{
+    parse::{self, Line},
+    ParseError,
+};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let blocks = parse::blocks(input);
+    let (workflows, parts) = match blocks.as_slice() {
+        [workflows, parts] => (parse_workflows(workflows)?, parse_parts(parts)?),
+        [_, _, extra, ..] => return Err(extra[0].error(extra[0].text, "the end of the input")),
+        _ => return Err(ParseError::at_end(input, "the workflows and the parts")),
+    };
+
+    Ok(parts
+        .into_iter()
+        .map(|part| {
+            let last = iter::successors(Some("in"), |workflow_name| {
+                workflows
+                    .get(workflow_name)
+                    .unwrap()
+                    .iter()
+                    .find_map(|rule| rule.next_workflow(&part))
+            })
+            .last();
+
+            match last {
+                Some("R") => 0,
+                Some("A") => (part.x + part.m + part.a + part.s) as i64,
+                _ => unreachable!(),
+            }
+        })
+        .sum())
+}
+
+fn parse_workflows<'a>(lines: &[Line<'a>]) -> Result<HashMap<&'a str, Vec<Rule<'a>>>, ParseError> {
+    let mut map = HashMap::from([("A", Vec::new()), ("R", Vec::new())]);
+    let mut labels = Vec::new();
+
+    for line in lines {
+        let (name, rules) = line.split_once(line.text, "{")?;
+        let rules = line.strip_suffix(rules, "}")?;
+
+        let fallback = rules.rsplit_once(',').map_or(rules, |(_rules, last)| last);
+        if fallback.contains(':') {
+            return Err(line.error(fallback, "a rule without a condition"));
+        }
+
+        let rules: Vec<Rule> = rules
+            .split(',')
+            .map(|rule| {
+                let rule = if let Some((cond, dest)) = rule.split_once(':') {
+                    Rule {
+                        cond: Some(Condition::parse(line, cond)?),
+                        next: dest,
+                    }
+                } else {
+                    Rule {
+                        cond: None,
+                        next: rule,
+                    }
+                };
+                labels.push((line, rule.next));
+                Ok(rule)
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        map.insert(name, rules);
+    }
+
+    if let Some((line, label)) = labels
+        .iter()
+        .find(|(_line, label)| !map.contains_key(label))
+    {
+        return Err(line.error(label, "the name of a workflow"));
+    }
+    match lines.last() {
+        Some(last) if !map.contains_key("in") => Err(ParseError::new(
+            last.num + 1,
+            1,
+            "a workflow named 'in'",
+            "the end of the workflows",
+        )),
+        _ => Ok(map),
+    }
+}
+
+fn parse_parts(lines: &[Line]) -> Result<Vec<Part>, ParseError> {
+    let mut parts = Vec::new();
+
+    for line in lines {
+        let attribs = line.strip_prefix(line.text, "{")?;
+        let attribs = line.strip_suffix(attribs, "}")?;
+
+        let mut part = Part::default();
+        for attrib in attribs.split(',') {
+            let (attrib, value) = line.split_once(attrib, "=")?;
+            let value: i32 = line.number(value)?;
+
+            match attrib {
+                "x" => part.x = value,
+                "m" => part.m = value,
+                "a" => part.a = value,
+                "s" => part.s = value,
+                _ => return Err(line.error(attrib, parse::one_of("xmas"))),
+            }
+        }
+
+        parts.push(part);
+    }
+    Ok(parts)
+}
+
+#[derive(Default, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Part {
+    x: i32,
+    m: i32,
+    a: i32,
+    s: i32,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+enum Condition {
+    XLess(i32),
+    XGrater(i32),
+    MLess(i32),
+    MGrater(i32),
+    ALess(i32),
+    AGrater(i32),
+    SLess(i32),
+    SGrater(i32),
+}
+
+impl Condition {
+    fn parse(line: &Line, cond: &str) -> Result<Condition, ParseError> {
+        let (attrib, rest) = cond
+            .split_at_checked(1)
+            .ok_or_else(|| line.error(cond, "a condition"))?;
+        let (operator, value) = rest
+            .split_at_checked(1)
+            .ok_or_else(|| line.error(rest, parse::one_of("<>")))?;
+        let value: i32 = line.number(value)?;
+        use Condition::*;
+
+        Ok(match (attrib, operator) {
+            ("x", ">") => XGrater(value),
+            ("x", "<") => XLess(value),
+            ("m", ">") => MGrater(value),
+            ("m", "<") => MLess(value),
+            ("a", ">") => AGrater(value),
+            ("a", "<") => ALess(value),
+            ("s", ">") => SGrater(value),
+            ("s", "<") => SLess(value),
+            (_, ">" | "<") => return Err(line.error(attrib, parse::one_of("xmas"))),
+            _ => return Err(line.error(operator, parse::one_of("<>"))),
+        })
+    }
+}
+
+#[derive(Default, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Rule<'a> {
+    cond: Option<Condition>,
+    next: &'a str,
+}
+
+impl<'a> Rule<'a> {
+    fn next_workflow(&self, part: &Part) -> Option<&'a str> {
+        use Condition::*;
+
+        match self.cond {
+            None => Some(self.next),
+            Some(XLess(val)) => (part.x < val).then_some(self.next),
+            Some(MLess(val)) => (part.m < val).then_some(self.next),
+            Some(ALess(val)) => (part.a < val).then_some(self.next),
+            Some(SLess(val)) => (part.s < val).then_some(self.next),
+            Some(XGrater(val)) => (part.x > val).then_some(self.next),
+            Some(MGrater(val)) => (part.m > val).then_some(self.next),
+            Some(AGrater(val)) => (part.a > val).then_some(self.next),
+            Some(SGrater(val)) => (part.s > val).then_some(self.next),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+
+    #[test]
+    fn test_parse_error() {
+        let input = "
+            px{a<2006:qkq,m>2090:A,rfg}
+            in{s<1351:px,R}
+
+            {x=787,m=2655,a=1222,s=2876}
+        ";
+        let err = process(&dedent(input)).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(1, 11, "the name of a workflow", "'qkq'")
+        );
+
+        let input = "
+            in{s=1351:A,R}
+
+            {x=787,m=2655,a=1222,s=2876}
+        ";
+        let err = process(&dedent(input)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected one of '<', '>', found '='"
+        );
+
+        assert!(process("in{s<1351:A,R}").is_err());
+        assert!(process("in{s<1351:A,R}\n\n{x=787,m=2655}\n\n{}").is_err());
+        assert!(process("in{s<1351:A,x>1:R}\n\n{x=787}").is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use std::{collections::HashMap, ops};
+
+use aoc::{
+    parse::{self, Line},
+    ParseError,
+};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let blocks = parse::blocks(input);
+    let workflows = blocks
+        .first()
+        .ok_or_else(|| ParseError::at_end(input, "the workflows"))?;
+    let rules = parse_workflows(workflows)?;
+
+    let mut accepted_count = 0;
+    let mut stack = vec![Region {
+        axes: [1..4001, 1..4001, 1..4001, 1..4001],
+        label: "in",
+    }];
+
+    while let Some(mut region) = stack.pop() {
+        if region.label == "A" {
+            accepted_count += region.volume();
+        }
+
+        for rule in rules.get(&region.label).unwrap() {
+            let (split, remaining) = rule.split_region(region);
+            if let Some(split) = split {
+                stack.push(split);
+            }
+            match remaining {
+                Some(remaining) => region = remaining,
+                None => break,
+            }
+        }
+    }
+    Ok(accepted_count)
+}
+
+fn parse_workflows<'a>(lines: &[Line<'a>]) -> Result<HashMap<&'a str, Vec<Rule<'a>>>, ParseError> {
+    let mut map = HashMap::from([("A", Vec::new()), ("R", Vec::new())]);
+    let mut labels = Vec::new();
+
+    for line in lines {
+        let (name, rules) = line.split_once(line.text, "{")?;
+        let rules = line.strip_suffix(rules, "}")?;
+
+        let fallback = rules.rsplit_once(',').map_or(rules, |(_rules, last)| last);
+        if fallback.contains(':') {
+            return Err(line.error(fallback, "a rule without a condition"));
+        }
+
+        let rules: Vec<Rule> = rules
+            .split(',')
+            .map(|rule| {
+                let rule = if let Some((cond, dest)) = rule.split_once(':') {
+                    Rule {
+                        cond: Some(Condition::parse(line, cond)?),
+                        next: dest,
+                    }
+                } else {
+                    Rule {
+                        cond: None,
+                        next: rule,
+                    }
+                };
+                labels.push((line, rule.next));
+                Ok(rule)
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        map.insert(name, rules);
+    }
+
+    if let Some((line, label)) = labels
+        .iter()
+        .find(|(_line, label)| !map.contains_key(label))
+    {
+        return Err(line.error(label, "the name of a workflow"));
+    }
+    match lines.last() {
+        Some(last) if !map.contains_key("in") => Err(ParseError::new(
+            last.num + 1,
+            1,
+            "a workflow named 'in'",
+            "the end of the workflows",
+        )),
+        _ => Ok(map),
+    }
+}
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Axis {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug, Hash, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Comparison {
+    Less,
+    Greater,
+}
+
+#[derive(Debug, Hash, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Condition {
+    axis: Axis,
+    comp: Comparison,
+    value: i32,
+}
+
+impl Condition {
+    fn parse(line: &Line, cond: &str) -> Result<Condition, ParseError> {
+        let (axis, rest) = cond
+            .split_at_checked(1)
+            .ok_or_else(|| line.error(cond, "a condition"))?;
+        let (comp, value) = rest
+            .split_at_checked(1)
+            .ok_or_else(|| line.error(rest, parse::one_of("<>")))?;
+
+        let axis = match axis {
+            "x" => Axis::X,
+            "m" => Axis::M,
+            "a" => Axis::A,
+            "s" => Axis::S,
+            _ => return Err(line.error(axis, parse::one_of("xmas"))),
+        };
+
+        let comp = match comp {
+            "<" => Comparison::Less,
+            ">" => Comparison::Greater,
+            _ => return Err(line.error(comp, parse::one_of("<>"))),
+        };
+
+        let value: i32 = line.number(value)?;
+        Ok(Condition { axis, comp, value })
+    }
+}
+
+#[derive(Default, Clone, Debug, Hash, PartialEq, Eq)]
+struct Region<'a> {
+    axes: [ops::Range<i32>; 4],
+    label: &'a str,
+}
+
+impl<'a> Region<'a> {
+    fn volume(&self) -> i64 {
+        self.axes.iter().map(|axis| axis.len() as i64).product()
+    }
+}
+
+#[derive(Default, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Rule<'a> {
+    cond: Option<Condition>,
+    next: &'a str,
+}
+
+impl<'a> Rule<'a> {
+    fn split_region(&self, mut region: Region<'a>) -> (Option<Region<'a>>, Option<Region<'a>>) {
+        match &self.cond {
+            None => {
+                region.label = self.next;
+                (Some(region), None)
+            }
+            Some(cond) => {
+                let range = region.axes[cond.axis as usize].clone();
+
+                match cond.comp {
+                    Comparison::Less => {
+                        if cond.value <= range.start {
+                            (None, Some(region))
+                        } else if cond.value >= range.end {
+                            region.label = self.next;
+                            (Some(region), None)
+                        } else {
+                            let mut contained = region.clone();
+                            contained.axes[cond.axis as usize] = range.start..cond.value;
+                            region.axes[cond.axis as usize] = cond.value..range.end;
+
+                            contained.label = self.next;
+                            (Some(contained), Some(region))
+                        }
+                    }
+                    Comparison::Greater => {
+                        if cond.value < range.start {
+                            region.label = self.next;
+                            (Some(region), None)
+                        } else if cond.value > range.end + 1 {
+                            (None, Some(region))
+                        } else {
+                            let mut excluded = region.clone();
+                            region.axes[cond.axis as usize] = (cond.value + 1)..range.end;
+                            excluded.axes[cond.axis as usize] = range.start..(cond.value + 1);
+
+                            region.label = self.next;
+                            (Some(region), Some(excluded))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+}
@@ -0,0 +1,118 @@
+//! The counters that part 2 takes a least common multiple of.
+
+use std::collections::BTreeMap;
+
+use aoc::{Check, ParseError};
+
+use crate::part2::{parse_input, press_button, Module, Pulse, PRESSES};
+
+pub fn assumptions(input: &str) -> Result<Vec<Check>, ParseError> {
+    let mut modules = parse_input(input)?;
+    let feeders: Vec<&str> = modules
+        .iter()
+        .filter(|(_name, module)| module.outputs().contains(&"rx"))
+        .map(|(name, _module)| *name)
+        .collect();
+
+    let single = match feeders[..] {
+        [_] => Ok(()),
+        [] => Err("none does".to_owned()),
+        _ => Err(format!("'{}' do", feeders.join("', '"))),
+    };
+    let Some(&last) = feeders.first().filter(|_| feeders.len() == 1) else {
+        let skipped = Err("there is no single module sending to 'rx'".to_owned());
+        return Ok(vec![
+            Check::new("a single module sends to 'rx'", single),
+            Check::new(
+                "the module sending to 'rx' is a conjunction",
+                skipped.clone(),
+            ),
+            Check::new("its inputs each send it a high pulse periodically", skipped),
+        ]);
+    };
+    let conjunction = match &modules[last] {
+        Module::Conjunction(_) => Ok(()),
+        Module::FlipFlop(_) => Err(format!("'{last}' is a flip-flop")),
+        Module::Broadcaster(_) => Err(format!("'{last}' is the broadcaster")),
+    };
+
+    let mut highs: BTreeMap<&str, Vec<usize>> = modules
+        .iter()
+        .filter(|(_name, module)| module.outputs().contains(&last))
+        .map(|(name, _module)| (*name, Vec::new()))
+        .collect();
+    for press in 1..=PRESSES {
+        press_button(&mut modules, |msg| {
+            if msg.pulse == Pulse::High && msg.dest == last {
+                let presses = highs.get_mut(msg.src).unwrap();
+                if presses.last() != Some(&press) {
+                    presses.push(press);
+                }
+            }
+        });
+    }
+    let irregular = highs
+        .into_iter()
+        .filter_map(|(name, presses)| match presses[..] {
+            [] => Some(format!("'{name}' sends none in {PRESSES} presses")),
+            _ if periodic(&presses) => None,
+            _ => {
+                let first: Vec<_> = presses.iter().take(3).map(usize::to_string).collect();
+                Some(format!(
+                    "'{name}' sends them at presses {}",
+                    first.join(", ")
+                ))
+            }
+        })
+        .collect();
+
+    Ok(vec![
+        Check::new("a single module sends to 'rx'", single),
+        Check::new("the module sending to 'rx' is a conjunction", conjunction),
+        Check::from_failures(
+            "its inputs each send it a high pulse periodically",
+            irregular,
+        ),
+    ])
+}
+
+/// Whether the presses are the multiples of the first one, which repeats at least once.
+fn periodic(presses: &[usize]) -> bool {
+    presses.len() > 1
+        && (1..)
+            .zip(presses)
+            .all(|(i, &press)| press == i * presses[0])
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    #[test]
+    fn test_assumptions() {
+        let checks = assumptions(&crate::gen::input(0, 4)).unwrap();
+        assert!(checks.iter().all(Check::holds));
+
+        let input = "
+            broadcaster -> a, b
+            %a -> c
+            %b -> b2
+            %b2 -> c
+            &c -> rx
+        ";
+        let failed: Vec<_> = assumptions(&dedent(input))
+            .unwrap()
+            .into_iter()
+            .filter_map(|check| check.result.err())
+            .collect();
+        assert_eq!(
+            failed,
+            ["'a' sends them at presses 1, 3, 5; 'b2' sends them at presses 2, 6, 10"]
+        );
+
+        let checks = assumptions("broadcaster -> a\n%a -> rx\n%b -> rx").unwrap();
+        assert_eq!(checks[0].result, Err("'a', 'b' do".to_owned()));
+    }
+}
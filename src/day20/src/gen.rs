@@ -0,0 +1,132 @@
+//! Random configurations of modules.
+
+use std::collections::HashSet;
+
+use aoc::random::Rng;
+
+/// Two to four binary counters of `size` flip-flops each, at most 12, which the broadcaster all
+/// steps at once. Like in the puzzle, a counter's conjunction resets it when it reaches a random
+/// odd number of presses, and `rx` gets a low pulse once all of them do so together.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let bits = size.clamp(2, 12);
+
+    let mut names = HashSet::from(["rx".to_owned()]);
+    let mut name = |rng: &mut Rng| loop {
+        let name = rng.string(2, "abcdefghijklmnopqrstuvwxyz");
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let last = name(&mut rng);
+    let mut lines = vec![format!("&{last} -> rx\n")];
+    let mut starts = Vec::new();
+    for _ in 0..rng.range(2..=4) {
+        // The lowest and highest bits are always set.
+        let period = rng.range(1u64 << (bits - 1)..1 << bits) | 1;
+        let flip_flops: Vec<String> = (0..bits).map(|_| name(&mut rng)).collect();
+        let (conjunction, inverter) = (name(&mut rng), name(&mut rng));
+
+        let mut resets = vec![inverter.clone(), flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = Vec::new();
+            if let Some(next) = flip_flops.get(bit + 1) {
+                outputs.push(next.clone());
+            }
+            match period >> bit & 1 {
+                1 => outputs.push(conjunction.clone()),
+                _ => resets.push(flip_flop.clone()),
+            }
+            rng.shuffle(&mut outputs);
+            lines.push(format!("%{flip_flop} -> {}\n", outputs.join(", ")));
+        }
+        rng.shuffle(&mut resets);
+        lines.push(format!("&{conjunction} -> {}\n", resets.join(", ")));
+        lines.push(format!("&{inverter} -> {last}\n"));
+        starts.push(flip_flops[0].clone());
+    }
+    lines.push(format!("broadcaster -> {}\n", starts.join(", ")));
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{HashMap, VecDeque};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    /// Presses the button until `rx` gets a low pulse, returning the number of presses and the
+    /// product of the low and high pulses sent in the first thousand.
+    fn press(input: &str) -> (i64, i64) {
+        let modules: HashMap<&str, (char, Vec<&str>)> = input
+            .lines()
+            .map(|line| {
+                let (module, outputs) = line.split_once(" -> ").unwrap();
+                let kind = module.chars().next().unwrap();
+                (
+                    module.trim_start_matches(['%', '&']),
+                    (kind, outputs.split(", ").collect()),
+                )
+            })
+            .collect();
+        let mut on: HashSet<&str> = HashSet::new();
+        let mut high_inputs: HashMap<&str, HashSet<&str>> = HashMap::new();
+        let input_counts: HashMap<&str, usize> = modules
+            .values()
+            .flat_map(|(_, outputs)| outputs.iter().copied())
+            .fold(HashMap::new(), |mut counts, output| {
+                *counts.entry(output).or_default() += 1;
+                counts
+            });
+
+        let (mut counts, mut rx) = ([0, 0], None);
+        for presses in 1.. {
+            if presses > 1000 && rx.is_some() {
+                break;
+            }
+            let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+            while let Some((src, dest, high)) = queue.pop_front() {
+                if presses <= 1000 {
+                    counts[high as usize] += 1;
+                }
+                if dest == "rx" && !high {
+                    rx = rx.or(Some(presses));
+                }
+                let Some((kind, outputs)) = modules.get(dest) else {
+                    continue;
+                };
+                let pulse = match kind {
+                    '%' if high => continue,
+                    '%' => match on.contains(dest) {
+                        true => !on.remove(dest),
+                        false => on.insert(dest),
+                    },
+                    '&' => {
+                        let inputs = high_inputs.entry(dest).or_default();
+                        match high {
+                            true => inputs.insert(src),
+                            false => inputs.remove(src),
+                        };
+                        inputs.len() < input_counts[dest]
+                    }
+                    _ => high,
+                };
+                queue.extend(outputs.iter().map(|&output| (dest, output, pulse)));
+            }
+        }
+        (rx.unwrap(), counts[0] * counts[1])
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let input = input(seed, 4);
+            let (presses, pulses) = press(&input);
+            assert_eq!(part1::process(&input).unwrap(), pulses);
+            assert_eq!(part2::process(&input).unwrap(), presses);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use aoc::{math, parse, ParseError};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub(crate) enum Pulse {
+    Low,
+    High,
+}
+
+impl Pulse {
+    fn new(pulse: bool) -> Self {
+        match pulse {
+            true => Pulse::High,
+            false => Pulse::Low,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
+pub(crate) struct Message<'a> {
+    pub pulse: Pulse,
+    pub src: &'a str,
+    pub dest: &'a str,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Default, Debug)]
+pub(crate) struct Broadcaster<'a> {
+    outputs: Vec<&'a str>,
+}
+
+impl<'a> Broadcaster<'a> {
+    fn update(&self, self_name: &'a str, msg: Message<'a>) -> Vec<Message<'a>> {
+        self.outputs
+            .iter()
+            .map(|output| Message {
+                pulse: msg.pulse,
+                src: self_name,
+                dest: output,
+            })
+            .collect()
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Default, Debug)]
+pub(crate) struct FlipFlop<'a> {
+    state: bool,
+    outputs: Vec<&'a str>,
+}
+
+impl<'a> FlipFlop<'a> {
+    fn update(&mut self, self_name: &'a str, msg: Message<'a>) -> Vec<Message<'a>> {
+        match msg.pulse {
+            Pulse::Low => {
+                self.state = !self.state;
+                self.outputs
+                    .iter()
+                    .map(|output| Message {
+                        pulse: Pulse::new(self.state),
+                        src: self_name,
+                        dest: output,
+                    })
+                    .collect()
+            }
+            Pulse::High => vec![],
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Default, Debug)]
+pub(crate) struct Conjunction<'a> {
+    inputs: BTreeMap<&'a str, Pulse>,
+    outputs: Vec<&'a str>,
+}
+
+impl<'a> Conjunction<'a> {
+    fn update(&mut self, self_name: &'a str, msg: Message<'a>) -> Vec<Message<'a>> {
+        *self.inputs.get_mut(msg.src).unwrap() = msg.pulse;
+
+        let pulse = match self.inputs.values().all(|input| *input == Pulse::High) {
+            true => Pulse::Low,
+            false => Pulse::High,
+        };
+
+        self.outputs
+            .iter()
+            .map(|output| Message {
+                pulse,
+                src: self_name,
+                dest: output,
+            })
+            .collect()
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
+pub(crate) enum Module<'a> {
+    FlipFlop(FlipFlop<'a>),
+    Conjunction(Conjunction<'a>),
+    Broadcaster(Broadcaster<'a>),
+}
+
+impl<'a> Module<'a> {
+    pub fn outputs(&self) -> &[&'a str] {
+        match self {
+            Module::FlipFlop(module) => &module.outputs,
+            Module::Conjunction(module) => &module.outputs,
+            Module::Broadcaster(module) => &module.outputs,
+        }
+    }
+}
+
+/// How many times the button is pressed to find the periods of the counters. The counters of the
+/// real inputs have twelve bits, so they all go around twice within this many presses.
+pub(crate) const PRESSES: usize = 10_000;
+
+/// Assumes that `rx` is fed by a single conjunction, whose inputs each send it a high pulse
+/// periodically, starting with the first one.
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut modules = parse_input(input)?;
+    let last = modules
+        .iter()
+        .find(|(_name, module)| module.outputs().contains(&"rx"))
+        .map(|(name, _module)| *name)
+        .ok_or_else(|| ParseError::at_end(input, "a module sending to 'rx'"))?;
+    let inputs: Vec<&str> = modules
+        .iter()
+        .filter(|(_name, module)| module.outputs().contains(&last))
+        .map(|(name, _module)| *name)
+        .collect();
+
+    let mut factors = BTreeMap::new();
+    for i in 1..=PRESSES as i64 {
+        if factors.len() == inputs.len() {
+            break;
+        }
+        press_button(&mut modules, |msg| {
+            if msg.pulse == Pulse::High && msg.dest == last {
+                factors.entry(msg.src).or_insert(i);
+            }
+        });
+    }
+    if let Some(silent) = inputs.iter().find(|name| !factors.contains_key(*name)) {
+        return Err(ParseError::unmet(
+            format!("the inputs of '{last}' each send it a high pulse periodically"),
+            format!("'{silent}' sends none in {PRESSES} presses"),
+        ));
+    }
+    Ok(factors.into_values().fold(1, math::lcm))
+}
+
+/// Sends a low pulse to the broadcaster and passes every pulse it causes to `on_message`, in the
+/// order they are sent.
+pub(crate) fn press_button<'a>(
+    modules: &mut BTreeMap<&'a str, Module<'a>>,
+    mut on_message: impl FnMut(&Message<'a>),
+) {
+    let mut queue = VecDeque::from([Message {
+        pulse: Pulse::Low,
+        src: "button",
+        dest: "broadcaster",
+    }]);
+
+    while let Some(msg) = queue.pop_front() {
+        let module = match modules.get_mut(msg.dest) {
+            Some(module) => module,
+            None => continue,
+        };
+
+        let messages = match module {
+            Module::FlipFlop(module) => module.update(msg.dest, msg),
+            Module::Conjunction(module) => module.update(msg.dest, msg),
+            Module::Broadcaster(module) => module.update(msg.dest, msg),
+        };
+
+        messages.iter().for_each(&mut on_message);
+        queue.extend(messages);
+    }
+}
+
+pub(crate) fn parse_input<'a>(input: &'a str) -> Result<BTreeMap<&'a str, Module<'a>>, ParseError> {
+    let mut modules = BTreeMap::new();
+    for line in parse::lines(input) {
+        let (module, outputs) = line.split_once(line.text, " -> ")?;
+        let outputs: Vec<&str> = outputs.split(", ").collect();
+
+        match module.chars().next() {
+            Some('%') => {
+                let name = &module[1..];
+                let module = Module::FlipFlop(FlipFlop {
+                    state: false,
+                    outputs,
+                });
+                modules.insert(name, module);
+            }
+            Some('&') => {
+                let name = &module[1..];
+                let module = Module::Conjunction(Conjunction {
+                    inputs: BTreeMap::new(),
+                    outputs,
+                });
+                modules.insert(name, module);
+            }
+            _ if module == "broadcaster" => {
+                let module = Module::Broadcaster(Broadcaster { outputs });
+                modules.insert("broadcaster", module);
+            }
+            _ => return Err(line.error(module, "'%', '&' or 'broadcaster'")),
+        }
+    }
+
+    // PERF: I'm forced by the borrow checker to clone the map. This could maybe be fixed by adding a
+    // bunch of RefCells, but that sounds really annoying.
+    for (name, module) in modules.clone().iter() {
+        for output in module.outputs() {
+            if let Some(Module::Conjunction(conjunction)) = modules.get_mut(output) {
+                conjunction.inputs.insert(name, Pulse::Low);
+            }
+        }
+    }
+    Ok(modules)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = process("broadcaster -> a\n%a -> b").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a module sending to 'rx', found end of input"
+        );
+
+        // `b` only ever sends low pulses to `c`, as its one input is always low.
+        let err = process("broadcaster -> a\n%a -> c\n&b -> c\n&c -> rx").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unmet assumption: the inputs of 'c' each send it a high pulse periodically: 'b' sends \
+             none in 10000 presses"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! The shape of the garden that part 2 counts on.
+
+use aoc::{grid::Pos, Check, Grid, ParseError};
+
+use crate::part2::STEPS;
+
+pub fn assumptions(input: &str) -> Result<Vec<Check>, ParseError> {
+    let grid = Grid::parse(input, ".#S")?;
+    let (width, height) = (grid.width(), grid.height());
+    let middle = (width / 2, height / 2);
+
+    let square = match width == height {
+        true => Ok(()),
+        false => Err(format!("it is {width} by {height} plots")),
+    };
+    let odd = match width % 2 == 1 && height % 2 == 1 {
+        true => Ok(()),
+        false => Err(format!("it is {width} by {height} plots")),
+    };
+    let starts: Vec<_> = grid.enumerate().filter(|&(_, &ch)| ch == 'S').collect();
+    let start = match starts[..] {
+        [(pos, _)] if pos == middle => Ok(()),
+        [((x, y), _)] => Err(format!(
+            "it is at ({x}, {y}) instead of ({}, {})",
+            middle.0, middle.1
+        )),
+        _ => Err(format!("there are {} starts", starts.len())),
+    };
+    let lines = free_of_rocks(
+        &grid,
+        (0..width)
+            .map(|x| (x, middle.1))
+            .chain((0..height).map(|y| (middle.0, y))),
+    );
+    let border = free_of_rocks(
+        &grid,
+        grid.positions()
+            .filter(|&(x, y)| x == 0 || y == 0 || x == width - 1 || y == height - 1),
+    );
+    let side = width as i64;
+    let steps = match STEPS % side == side / 2 {
+        true => Ok(()),
+        false => Err(format!(
+            "{STEPS} % {side} is {} instead of {}",
+            STEPS % side,
+            side / 2
+        )),
+    };
+
+    Ok(vec![
+        Check::new("the garden is square", square),
+        Check::new("the side of the garden is odd", odd),
+        Check::new("there is a single start in the middle", start),
+        Check::new("the row and column of the start are free of rocks", lines),
+        Check::new("the border of the garden is free of rocks", border),
+        Check::new("the steps of part 2 end at the edge of a garden", steps),
+    ])
+}
+
+fn free_of_rocks(
+    grid: &Grid<char>,
+    mut positions: impl Iterator<Item = Pos>,
+) -> Result<(), String> {
+    match positions.find(|&pos| grid[pos] == '#') {
+        Some((x, y)) => Err(format!("there is a rock at ({x}, {y})")),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    #[test]
+    fn test_assumptions() {
+        let checks = assumptions(&crate::gen::input(0, 0)).unwrap();
+        assert!(checks.iter().all(Check::holds));
+
+        let input = "
+            ......
+            ..#...
+            ......
+            .#.S..
+            ......
+            ......
+        ";
+        let failed: Vec<_> = assumptions(&dedent(input))
+            .unwrap()
+            .into_iter()
+            .filter_map(|check| check.result.err())
+            .collect();
+        assert_eq!(
+            failed,
+            [
+                "it is 6 by 6 plots",
+                "there is a rock at (1, 3)",
+                "26501365 % 6 is 1 instead of 3",
+            ]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Random maps of the garden.
+
+use aoc::random::Rng;
+
+use crate::part2::STEPS;
+
+/// A garden of at least `size` by `size` plots with the start in the middle. The steps of part 2
+/// only end right at the edge of a garden that is 131 or 393 plots wide, so the size picks one of
+/// those.
+pub fn input(seed: u64, size: usize) -> String {
+    let side = (size.min(393)..)
+        .find(|side| side % 2 == 1 && STEPS as usize % side == side / 2)
+        .unwrap();
+    garden(&mut Rng::new(seed), side)
+}
+
+/// A garden of `side` by `side` plots. Like in the puzzle, the rocks are sparse and leave the
+/// border and the row and column of the start clear.
+fn garden(rng: &mut Rng, side: usize) -> String {
+    let middle = side / 2;
+
+    let mut input = String::new();
+    for y in 0..side {
+        for x in 0..side {
+            let clear = [0, middle, side - 1].contains(&x) || [0, middle, side - 1].contains(&y);
+            input.push(match (x, y) {
+                _ if (x, y) == (middle, middle) => 'S',
+                _ if !clear && rng.chance(0.15) => '#',
+                _ => '.',
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let side = 11;
+            let input = garden(&mut Rng::new(seed), side);
+            for repeats in [2, 3] {
+                // The garden repeated in every direction, as far as the steps can reach.
+                let tiles = 2 * repeats + 1;
+                let tiled: String = (0..tiles)
+                    .flat_map(|tile_y| input.lines().map(move |line| (tile_y, line)))
+                    .map(|(tile_y, line)| {
+                        let row: String = (0..tiles)
+                            .map(|tile_x| match (tile_x, tile_y) == (repeats, repeats) {
+                                true => line.to_owned(),
+                                false => line.replace('S', "."),
+                            })
+                            .collect();
+                        row + "\n"
+                    })
+                    .collect();
+
+                let steps = (repeats * side + side / 2) as i64;
+                assert_eq!(
+                    part2::process(&input, steps).unwrap(),
+                    part1::process(&tiled, steps).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_side() {
+        assert_eq!(input(0, 100).lines().count(), 131);
+        assert_eq!(input(0, 200).lines().count(), 393);
+        assert_eq!(input(0, 1000).lines().count(), 393);
+    }
+}
@@ -0,0 +1,31 @@
+pub mod check;
+pub mod gen;
+pub mod part1;
+pub mod part2;
+pub mod visualize;
+
+use aoc::{Answer, Check, ParseError, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn day(&self) -> u32 {
+        21
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        part1::process(input, part1::STEPS).map(Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        part2::process(input, part2::STEPS).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        gen::input(seed, size)
+    }
+
+    fn check(&self, input: &str) -> Result<Vec<Check>, ParseError> {
+        check::assumptions(input)
+    }
+}
@@ -0,0 +1,27 @@
+use aoc::{graph, grid::Pos, Grid, ParseError};
+
+pub const STEPS: i64 = 64;
+
+pub fn process(input: &str, steps: i64) -> Result<i64, ParseError> {
+    let grid = Grid::parse(input, ".#S")?;
+
+    let start = grid
+        .position(|&ch| ch == 'S')
+        .ok_or_else(|| ParseError::at_end(input, "a starting position 'S'"))?;
+
+    let garden =
+        graph::from_fn(|&pos: &Pos| grid.neighbours4(pos).filter(|&next| grid[next] != '#'));
+    Ok(graph::bfs_layers(&garden, start)
+        .zip(0..)
+        .take_while(|&(_, depth)| depth <= steps)
+        .filter(|&(_, depth)| depth % 2 == steps % 2)
+        .map(|(layer, _)| layer.len() as i64)
+        .sum())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(|input| process(input, 6), part1: example1);
+}
@@ -0,0 +1,150 @@
+use aoc::{graph, grid::Pos, Grid, ParseError};
+
+pub const STEPS: i64 = 26_501_365;
+
+pub fn process(input: &str, steps: i64) -> Result<i64, ParseError> {
+    let grid = Grid::parse(input, ".#S")?;
+
+    let side = check_garden(&grid)? as i64;
+
+    let whole_radius = steps / side - 1;
+    let start = Node {
+        x: side / 2,
+        y: side / 2,
+        depth: 0,
+    };
+
+    let full_even = {
+        let radius = whole_radius - (whole_radius % 2 == 1) as i64;
+        let even_rings_area = radius * (radius + 2) + 1;
+        count_reachable(start, steps, &grid) * even_rings_area
+    };
+
+    let full_odd = {
+        let radius = whole_radius - (whole_radius % 2 == 0) as i64;
+        let odd_rings_area = (radius + 1).pow(2);
+        count_reachable(start, steps + 1, &grid) * odd_rings_area
+    };
+
+    let remaining_steps = steps - whole_radius * side - side / 2;
+    let corners: i64 = [
+        Node::new(0, side / 2, 1),        // Left
+        Node::new(side / 2, 0, 1),        // Up
+        Node::new(side - 1, side / 2, 1), // Right
+        Node::new(side / 2, side - 1, 1), // Down
+    ]
+    .into_iter()
+    .map(|start| count_reachable(start, remaining_steps, &grid))
+    .sum();
+
+    let deep_edges: i64 = [
+        Node::new(0, 0, 1),               // Upper-Left
+        Node::new(side - 1, 0, 1),        // Upper-Right
+        Node::new(0, side - 1, 1),        // Lower-Left
+        Node::new(side - 1, side - 1, 1), // Lower-Right
+    ]
+    .into_iter()
+    .map(|start| {
+        let steps = remaining_steps + side / 2;
+        count_reachable(start, steps, &grid) * whole_radius
+    })
+    .sum();
+
+    let shallow_edges: i64 = [
+        Node::new(0, 0, 1),               // Upper-Left
+        Node::new(side - 1, 0, 1),        // Upper-Right
+        Node::new(0, side - 1, 1),        // Lower-Left
+        Node::new(side - 1, side - 1, 1), // Lower-Right
+    ]
+    .into_iter()
+    .map(|start| {
+        let steps = remaining_steps - (side / 2 + 1);
+        count_reachable(start, steps, &grid) * (whole_radius + 1)
+    })
+    .sum();
+
+    Ok(full_even + full_odd + corners + deep_edges + shallow_edges)
+}
+
+/// Checks that the garden is square with an odd side and the start in the middle, which the
+/// counting relies on, and returns its side.
+fn check_garden(grid: &Grid<char>) -> Result<usize, ParseError> {
+    let (width, height) = (grid.width(), grid.height());
+    if width != height {
+        return Err(ParseError::new(
+            1,
+            1,
+            "a square garden",
+            format!("{width} by {height} plots"),
+        ));
+    }
+    if width % 2 == 0 {
+        return Err(ParseError::new(
+            1,
+            1,
+            "a garden with an odd side",
+            format!("{width} by {height} plots"),
+        ));
+    }
+
+    let middle = (width / 2, height / 2);
+    match grid[middle] {
+        'S' => Ok(width),
+        ch => Err(ParseError::new(
+            middle.1 + 1,
+            middle.0 + 1,
+            "the start in the middle of the garden",
+            format!("'{ch}'"),
+        )),
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Default, Debug)]
+struct Node {
+    x: i64,
+    y: i64,
+    depth: i64,
+}
+
+impl Node {
+    fn new(x: i64, y: i64, depth: i64) -> Self {
+        Self { x, y, depth }
+    }
+}
+
+/// The number of tiles reachable in exactly `steps` steps, from a start that is already
+/// `start.depth` steps away.
+fn count_reachable(start: Node, steps: i64, grid: &Grid<char>) -> i64 {
+    let garden =
+        graph::from_fn(|&pos: &Pos| grid.neighbours4(pos).filter(|&next| grid[next] != '#'));
+    let pos = (start.x as usize, start.y as usize);
+    graph::bfs_layers(&garden, pos)
+        .zip(start.depth..)
+        .take_while(|&(_, depth)| depth <= steps.max(start.depth))
+        .filter(|&(_, depth)| depth % 2 == steps % 2)
+        .map(|(layer, _)| layer.len() as i64)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(|input| process(input, 29), part2: example2);
+
+    #[test]
+    fn test_parse_error() {
+        let err = process("...\n.S.", STEPS).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(1, 1, "a square garden", "3 by 2 plots")
+        );
+        let err = process("S.\n..", STEPS).unwrap_err();
+        assert_eq!(err.expected, "a garden with an odd side");
+        let err = process("S..\n...\n...", STEPS).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 2, "the start in the middle of the garden", "'.'")
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Frames of the plots the elf can reach after each step, for `--visualize`.
+
+use aoc::{
+    graph,
+    grid::Pos,
+    render::{Cell, Color, Frame, Style},
+    Grid, ParseError,
+};
+
+const REACHABLE: Style = Style::fg(Color::Green).bold();
+const FRONTIER: Style = Style::fg(Color::Black).on(Color::Green);
+
+/// How many copies of the map are shown across and down for part 2.
+const TILES: usize = 5;
+
+pub fn part1(input: &str, steps: i64) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let (grid, start) = parse_garden(input)?;
+    Ok(spread(grid, start, steps))
+}
+
+/// The garden repeats forever in part 2, so the steps are shown on a few copies of the map, up
+/// to where they reach the edge of the outer ones.
+pub fn part2(input: &str, steps: i64) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let (grid, start) = parse_garden(input)?;
+    let (width, height) = (grid.width(), grid.height());
+
+    let center = TILES / 2;
+    let tiled = Grid::from_fn(width * TILES, height * TILES, |(x, y)| {
+        let in_center = x / width == center && y / height == center;
+        match grid[(x % width, y % height)] {
+            'S' if !in_center => '.',
+            ch => ch,
+        }
+    });
+    let start = (start.0 + center * width, start.1 + center * height);
+    let max_steps = (center * width.min(height) + width.min(height) / 2) as i64;
+    Ok(spread(tiled, start, steps.min(max_steps)))
+}
+
+fn parse_garden(input: &str) -> Result<(Grid<char>, Pos), ParseError> {
+    let grid = Grid::parse(input, ".#S")?;
+    let start = grid
+        .position(|&ch| ch == 'S')
+        .ok_or_else(|| ParseError::at_end(input, "a starting position 'S'"))?;
+    Ok((grid, start))
+}
+
+/// A frame for every step up to `steps`, marking the plots that can be reached in exactly that
+/// many steps.
+fn spread(grid: Grid<char>, start: Pos, steps: i64) -> impl Iterator<Item = Frame> {
+    let garden =
+        graph::from_fn(|&pos: &Pos| grid.neighbours4(pos).filter(|&next| grid[next] != '#'));
+    let layers: Vec<Vec<Pos>> = graph::bfs_layers(&garden, start)
+        .take(steps as usize + 1)
+        .collect();
+
+    let frame = Frame::new(&grid, |&ch| match ch {
+        '#' => Cell::new('#', Style::fg(Color::Gray)),
+        ch => Cell::plain(ch),
+    });
+    (0..=steps as usize).map(move |step| {
+        // A plot reached after `depth` steps can be reached again after any more even number.
+        let reachable = layers
+            .iter()
+            .take(step + 1)
+            .skip(step % 2)
+            .step_by(2)
+            .flatten()
+            .copied();
+        let mut current = frame.clone();
+        current.mark(reachable.clone(), 'O', REACHABLE);
+        if let Some(layer) = layers.get(step) {
+            current.mark(layer.iter().copied(), 'O', FRONTIER);
+        }
+        let count = reachable.count();
+        current.with_caption(format!("step {step}, {count} plots reachable"))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::Example;
+
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        let example = Example::load(env!("CARGO_MANIFEST_DIR"), "example1", "part1");
+        let last = part1(&example.input, 6).unwrap().last().unwrap();
+        assert_eq!(
+            last.caption(),
+            format!("step 6, {} plots reachable", example.answer)
+        );
+
+        // The example reaches 50 plots after 10 steps on the infinite map.
+        let last = part2(&example.input, 10).unwrap().last().unwrap();
+        assert_eq!(last.caption(), "step 10, 50 plots reachable");
+    }
+}
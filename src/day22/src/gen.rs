@@ -0,0 +1,115 @@
+//! Random snapshots of falling bricks.
+
+use std::collections::HashSet;
+
+use aoc::random::Rng;
+
+/// A snapshot of `size` bricks that don't overlap, up to four cubes long. They fall onto an area
+/// between 3 and `size + 3` cubes wide and deep, and float up to `size` cubes high.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+    let width = rng.range(3..=size + 3);
+    let depth = rng.range(3..=size + 3);
+    let mut occupied = HashSet::new();
+    let mut input = String::new();
+
+    let mut count = 0;
+    while count < size {
+        let start = [
+            rng.range(0..width),
+            rng.range(0..depth),
+            rng.range(1..=size),
+        ];
+        let mut end = start;
+        let axis = rng.range(0..3);
+        end[axis] += rng.range(0..4);
+        if end[0] >= width || end[1] >= depth {
+            continue;
+        }
+
+        let cubes: Vec<[usize; 3]> = (start[0]..=end[0])
+            .flat_map(|x| (start[1]..=end[1]).map(move |y| (x, y)))
+            .flat_map(|(x, y)| (start[2]..=end[2]).map(move |z| [x, y, z]))
+            .collect();
+        if cubes.iter().any(|cube| occupied.contains(cube)) {
+            continue;
+        }
+        occupied.extend(cubes);
+
+        let [x, y, z] = start;
+        let [x2, y2, z2] = end;
+        input.push_str(&format!("{x},{y},{z}~{x2},{y2},{z2}\n"));
+        count += 1;
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    type Brick = Vec<[usize; 3]>;
+
+    /// Lets the bricks fall one cube at a time until none can, returning how many moved.
+    fn settle(bricks: &mut [Brick]) -> usize {
+        let mut occupied: HashSet<[usize; 3]> = bricks.iter().flatten().copied().collect();
+        let mut moved = HashSet::new();
+        let mut falling = true;
+        while falling {
+            falling = false;
+            for (i, brick) in bricks.iter_mut().enumerate() {
+                let can_fall = brick.iter().all(|&[x, y, z]| {
+                    z > 1 && (brick.contains(&[x, y, z - 1]) || !occupied.contains(&[x, y, z - 1]))
+                });
+                if can_fall {
+                    for cube in brick.iter_mut() {
+                        occupied.remove(cube);
+                        cube[2] -= 1;
+                    }
+                    occupied.extend(brick.iter().copied());
+                    moved.insert(i);
+                    falling = true;
+                }
+            }
+        }
+        moved.len()
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let input = input(seed, 30);
+            let mut bricks: Vec<Brick> = input
+                .lines()
+                .map(|line| {
+                    let (start, end) = line.split_once('~').unwrap();
+                    let point = |text: &str| -> Vec<usize> {
+                        text.split(',').map(|num| num.parse().unwrap()).collect()
+                    };
+                    let (start, end) = (point(start), point(end));
+                    (start[0]..=end[0])
+                        .flat_map(|x| (start[1]..=end[1]).map(move |y| (x, y)))
+                        .flat_map(|(x, y)| (start[2]..=end[2]).map(move |z| [x, y, z]))
+                        .collect()
+                })
+                .collect();
+            settle(&mut bricks);
+
+            let falls: Vec<usize> = (0..bricks.len())
+                .map(|i| {
+                    let mut rest = bricks.clone();
+                    rest.remove(i);
+                    settle(&mut rest)
+                })
+                .collect();
+            let safe = falls.iter().filter(|&&count| count == 0).count();
+            assert_eq!(part1::process(&input).unwrap(), safe as i64);
+            assert_eq!(
+                part2::process(&input).unwrap(),
+                falls.iter().sum::<usize>() as i64
+            );
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    mem,
+};
+
+use aoc::{
+    parse::{self, Line},
+    ParseError,
+};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut bricks = parse_bricks(input)?;
+    let tower = get_tower(&mut bricks);
+
+    let nodes: Vec<Node> = bricks
+        .iter()
+        .map(|(start, end)| {
+            let mut node = Node::default();
+
+            for x in start[0]..=end[0] {
+                for y in start[1]..=end[1] {
+                    if let Some(&parent) = tower.get(&[x, y, start[2] - 1]) {
+                        node.parents.insert(parent);
+                    }
+                    if let Some(&child) = tower.get(&[x, y, end[2] + 1]) {
+                        node.children.insert(child);
+                    }
+                }
+            }
+            node
+        })
+        .collect();
+
+    Ok(nodes
+        .iter()
+        .filter(|node| {
+            node.children
+                .iter()
+                .map(|child| nodes[*child].parents.len())
+                .all(|parents_len| parents_len > 1)
+        })
+        .count() as i64)
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Default, Debug)]
+struct Node {
+    parents: BTreeSet<usize>,
+    children: BTreeSet<usize>,
+}
+
+pub(crate) type Brick = (Vec<usize>, Vec<usize>);
+
+/// Parses the bricks, sorted from the lowest to the highest.
+pub(crate) fn parse_bricks(input: &str) -> Result<Vec<Brick>, ParseError> {
+    let mut bricks = Vec::new();
+    // The line of the brick filling each cube, as the bricks settle in order of height, which
+    // needs them to be apart.
+    let mut filled: HashMap<[usize; 3], usize> = HashMap::new();
+
+    for line in parse::lines(input) {
+        let (start, end) = line.split_once(line.text, "~")?;
+        let (mut start, mut end) = (parse_point(line, start)?, parse_point(line, end)?);
+
+        let sort = |a: &mut usize, b: &mut usize| {
+            if a > b {
+                mem::swap(a, b)
+            }
+        };
+        sort(&mut start[0], &mut end[0]);
+        sort(&mut start[1], &mut end[1]);
+        sort(&mut start[2], &mut end[2]);
+
+        for cube in cubes(&start, &end) {
+            if let Some(other) = filled.insert(cube, line.num) {
+                return Err(line.error(
+                    line.text,
+                    format!("a brick apart from the one on line {other}"),
+                ));
+            }
+        }
+        bricks.push((start, end));
+    }
+
+    if bricks.is_empty() {
+        return Err(ParseError::at_end(input, "a brick"));
+    }
+    bricks.sort_unstable_by_key(|(start, _end)| start[2]);
+    Ok(bricks)
+}
+
+fn parse_point(line: Line, point: &str) -> Result<Vec<usize>, ParseError> {
+    let parts: Vec<&str> = point.split(',').collect();
+    if parts.len() != 3 {
+        return Err(line.error(point, "3 coordinates"));
+    }
+    let coords = parts
+        .iter()
+        .map(|num| line.number(num))
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    match coords[2] {
+        0 => Err(line.error(parts[2], "a height of at least 1")),
+        _ => Ok(coords),
+    }
+}
+
+fn cubes<'a>(start: &'a [usize], end: &'a [usize]) -> impl Iterator<Item = [usize; 3]> + 'a {
+    (start[0]..=end[0]).flat_map(move |x| {
+        (start[1]..=end[1]).flat_map(move |y| (start[2]..=end[2]).map(move |z| [x, y, z]))
+    })
+}
+
+/// Lets the bricks fall, returning which brick fills each cube they settle in.
+fn get_tower(bricks: &mut [Brick]) -> HashMap<[usize; 3], usize> {
+    let mut max_heights: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut tower = HashMap::new();
+
+    for (id, (start, end)) in bricks.iter_mut().enumerate() {
+        let mut min_z = 0;
+        for x in start[0]..=end[0] {
+            for y in start[1]..=end[1] {
+                min_z = min_z.max(max_heights.get(&(x, y)).copied().unwrap_or(0));
+            }
+        }
+        let dz = start[2] - (min_z + 1);
+        start[2] -= dz;
+        end[2] -= dz;
+
+        for cube in cubes(start, end) {
+            tower.insert(cube, id);
+            max_heights.insert((cube[0], cube[1]), end[2]);
+        }
+    }
+    tower
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+
+    #[test]
+    fn test_parse_error() {
+        let input = "
+            1,0,1~1,2,1
+            0,0,2~2,0
+        ";
+        let err = process(&dedent(input)).unwrap_err();
+        assert_eq!(err, ParseError::new(2, 7, "3 coordinates", "'2,0'"));
+
+        let err = process("1,0,1~1,2,1\n0,0,2-2,0,2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected '~', found '0,0,2-2,0,2'"
+        );
+        assert!(process("1,0,1~1,x,1").is_err());
+
+        let err = process("1,0,1~1,2,1\n0,0,0~0,0,3").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 5, "a height of at least 1", "'0'"));
+        let err = process("1,0,1~1,2,1\n0,1,1~2,1,1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a brick apart from the one on line 1, found '0,1,1~2,1,1'"
+        );
+    }
+
+    #[test]
+    fn test_wide_tower() {
+        // Bricks outside the 10 by 10 area of the real input.
+        let input = "
+            20,0,1~20,30,1
+            20,15,2~25,15,2
+            24,15,5~24,15,9
+            0,0,1~0,0,1
+        ";
+        assert_eq!(process(&dedent(input)).unwrap(), 2);
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use aoc::ParseError;
+
+use crate::part1::{parse_bricks, Brick};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let mut bricks = parse_bricks(input)?;
+    let mut children = vec![BTreeSet::new(); bricks.len()];
+    let mut parents = vec![BTreeSet::new(); bricks.len()];
+
+    for i in 0..bricks.len() {
+        let min_z = bricks
+            .iter()
+            .take(i)
+            .filter(|lower| overlaps(&bricks[i], lower))
+            .map(|(_start, end)| end[2])
+            .max()
+            .unwrap_or(0);
+
+        let (start, end) = &mut bricks[i];
+        end[2] -= start[2] - (min_z + 1);
+        start[2] = min_z + 1;
+    }
+
+    bricks.sort_unstable_by_key(|(start, _end)| start[2]);
+    for (upper_idx, upper) in bricks.iter().enumerate() {
+        for (lower_idx, lower) in bricks.iter().enumerate().take(upper_idx) {
+            if overlaps(upper, lower) && upper.0[2] == lower.1[2] + 1 {
+                children[lower_idx].insert(upper_idx);
+                parents[upper_idx].insert(lower_idx);
+            }
+        }
+    }
+
+    let mut count = 0;
+    for i in 0..bricks.len() {
+        let mut queue = VecDeque::<usize>::new();
+        let mut falling = vec![false; parents.len()];
+
+        falling[i] = true;
+        queue.extend(children[i].iter());
+
+        while let Some(node) = queue.pop_front() {
+            if !falling[node] && parents[node].iter().all(|parent| falling[*parent]) {
+                count += 1;
+                falling[node] = true;
+                queue.extend(children[node].iter());
+            }
+        }
+    }
+    Ok(count)
+}
+
+fn overlaps(a: &Brick, b: &Brick) -> bool {
+    a.0[0] <= b.1[0] && b.0[0] <= a.1[0] && a.0[1] <= b.1[1] && b.0[1] <= a.1[1]
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+
+    #[test]
+    fn test_wide_tower() {
+        // Bricks outside the 10 by 10 area of the real input, which `aoc check` has nothing to
+        // say about.
+        let input = "
+            20,0,1~20,30,1
+            20,15,2~25,15,2
+            24,15,5~24,15,9
+            0,0,1~0,0,1
+        ";
+        assert_eq!(process(&dedent(input)).unwrap(), 3);
+        assert!(aoc::Solution::check(&crate::Day22, &dedent(input))
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+//! Random maps of hiking trails.
+
+use aoc::random::Rng;
+
+/// A map whose trails meet at the crossings of a `size` by `size` lattice, which are two to six
+/// tiles apart. The start leads to the top left crossing and the bottom right one leads to the
+/// end. Slopes right after each crossing point right and down, so that the icy trails never loop.
+/// The lattice is at most 6 by 6 like in the puzzle, as the longest dry hike takes exponential time.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.clamp(2, 6);
+    let lines = |rng: &mut Rng| {
+        let mut lines = vec![1];
+        for _ in 1..size {
+            lines.push(lines.last().unwrap() + rng.range(2..=6));
+        }
+        lines
+    };
+    let (columns, rows) = (lines(&mut rng), lines(&mut rng));
+    let (width, height) = (columns[size - 1] + 2, rows[size - 1] + 2);
+
+    let mut map = vec![vec!['#'; width]; height];
+    for &y in rows.iter() {
+        for tile in map[y][columns[0]..=columns[size - 1]].iter_mut() {
+            *tile = '.';
+        }
+        for &x in columns[..size - 1].iter() {
+            map[y][x + 1] = '>';
+        }
+    }
+    for &x in columns.iter() {
+        for row in map[rows[0]..=rows[size - 1]].iter_mut() {
+            row[x] = '.';
+        }
+        for &y in rows[..size - 1].iter() {
+            map[y + 1][x] = 'v';
+        }
+    }
+    map[0][columns[0]] = '.';
+    map[height - 1][columns[size - 1]] = '.';
+
+    map.into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{part1, part2};
+
+    /// The longest hike to the bottom row that doesn't step on a tile twice, trying every way tile
+    /// by tile.
+    fn longest(map: &mut [Vec<char>], (x, y): (usize, usize), icy: bool) -> Option<usize> {
+        if y == map.len() - 1 {
+            return Some(0);
+        }
+        let tile = std::mem::replace(&mut map[y][x], '#');
+        let up = y.wrapping_sub(1);
+        let next: Vec<(usize, usize)> = [
+            ('<', x - 1, y),
+            ('>', x + 1, y),
+            ('^', x, up),
+            ('v', x, y + 1),
+        ]
+        .into_iter()
+        .filter(|&(dir, _, _)| !icy || tile == '.' || tile == dir)
+        .filter(|&(_, x, y)| map.get(y).is_some_and(|row| row[x] != '#'))
+        .map(|(_, x, y)| (x, y))
+        .collect();
+        let steps = next
+            .into_iter()
+            .filter_map(|pos| longest(map, pos, icy))
+            .max()
+            .map(|steps| steps + 1);
+        map[y][x] = tile;
+        steps
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = input(seed, 4);
+            let mut map: Vec<Vec<char>> =
+                input.lines().map(|line| line.chars().collect()).collect();
+            let start = (map[0].iter().position(|&ch| ch == '.').unwrap(), 0);
+            let (icy, dry) = (
+                longest(&mut map, start, true),
+                longest(&mut map, start, false),
+            );
+            assert_eq!(part1::process(&input).unwrap(), icy.unwrap() as i64);
+            assert_eq!(part2::process(&input).unwrap(), dry.unwrap() as i64);
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use aoc::{graph, grid::Pos, Direction, Grid, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let grid = Grid::parse(input, "#.<>^v")?;
+
+    let nodes = get_collapsed_nodes(&grid)?;
+    let adjacency = get_adjacency(&nodes, &grid);
+    let order = topological_order(&adjacency, &nodes)?;
+
+    let (dists, _) = longest_hikes(&adjacency, &order);
+    match dists[nodes.len() - 1] {
+        Some(dist) => Ok(dist as i64),
+        None => Err(no_path(input)),
+    }
+}
+
+pub(crate) fn no_path(input: &str) -> ParseError {
+    ParseError::at_end(input, "a path from the start to the end")
+}
+
+/// The length of the longest hike from the start to each node, which is `None` for the nodes it
+/// can't reach, together with the node before it on that hike. `order` has to have every node
+/// before the nodes it leads to.
+pub(crate) fn longest_hikes(
+    adjacency: &[Vec<Option<i32>>],
+    order: &[usize],
+) -> (Vec<Option<i32>>, Vec<Option<usize>>) {
+    let mut dists = vec![None; adjacency.len()];
+    let mut prev = vec![None; adjacency.len()];
+    dists[0] = Some(0);
+    for &curr in order {
+        let Some(curr_dist) = dists[curr] else {
+            continue;
+        };
+        for (next, dist) in trails(adjacency, curr) {
+            if dists[next].is_none_or(|next_dist| curr_dist + dist > next_dist) {
+                dists[next] = Some(curr_dist + dist);
+                prev[next] = Some(curr);
+            }
+        }
+    }
+    (dists, prev)
+}
+
+/// The trails a hike can take from `node`, with the nodes they lead to and their lengths. The
+/// slopes make the trails between junctions one way, but the trails to the start and from the end
+/// have none, so those are left out: no hike goes back to the start or on from the end.
+fn trails(adjacency: &[Vec<Option<i32>>], node: usize) -> impl Iterator<Item = (usize, i32)> + '_ {
+    let is_end = node == adjacency.len() - 1;
+    adjacency[node]
+        .iter()
+        .enumerate()
+        .filter(move |&(next, _)| next != 0 && !is_end)
+        .filter_map(|(next, dist)| dist.map(|dist| (next, dist)))
+}
+
+/// The nodes reachable from the start, each before the nodes its trails lead to.
+///
+/// Returns an error at a junction that the hikes can go around in circles through.
+pub(crate) fn topological_order(
+    adjacency: &[Vec<Option<i32>>],
+    nodes: &[Pos],
+) -> Result<Vec<usize>, ParseError> {
+    let graph = graph::from_fn(|&node: &usize| trails(adjacency, node).map(|(next, _)| next));
+
+    let mut order = Vec::new();
+    for component in graph::tarjan_scc(&graph, [0]) {
+        match component[..] {
+            [node] => order.push(node),
+            _ => {
+                let (x, y) = component
+                    .iter()
+                    .map(|&node| nodes[node])
+                    .min_by_key(|&(x, y)| (y, x))
+                    .unwrap();
+                return Err(ParseError::new(
+                    y + 1,
+                    x + 1,
+                    "slopes that keep the hikes from going in circles",
+                    "a loop through this junction",
+                ));
+            }
+        }
+    }
+    order.reverse();
+    Ok(order)
+}
+
+pub(crate) fn get_collapsed_nodes(grid: &Grid<char>) -> Result<Vec<Pos>, ParseError> {
+    let find_path = |y: usize| match grid.row(y).iter().position(|ch| *ch == '.') {
+        Some(x) => Ok((x, y)),
+        None => Err(ParseError::new(
+            y + 1,
+            1,
+            "a row with a path tile '.'",
+            format!("'{}'", grid.row(y).iter().collect::<String>()),
+        )),
+    };
+    let (start, end) = (find_path(0)?, find_path(grid.height() - 1)?);
+
+    let junctions = grid.positions().filter(|&pos| {
+        grid[pos] != '#'
+            && grid
+                .neighbours4(pos)
+                .filter(|&pos| grid[pos] != '#')
+                .count()
+                > 2
+    });
+
+    Ok([start].into_iter().chain(junctions).chain([end]).collect())
+}
+
+pub(crate) fn get_adjacency(nodes: &[Pos], grid: &Grid<char>) -> Vec<Vec<Option<i32>>> {
+    let nodes: HashMap<Pos, usize> = nodes.iter().cloned().zip(0..).collect();
+    let mut adjacency = vec![vec![None; nodes.len()]; nodes.len()];
+
+    for (start, start_idx) in nodes.iter() {
+        let mut stack = vec![(0, *start)];
+        let visited = &mut Grid::filled(grid.width(), grid.height(), false);
+        visited[*start] = true;
+
+        while let Some((dist, node)) = stack.pop() {
+            if node != *start && nodes.contains_key(&node) {
+                let end_idx = nodes.get(&node).unwrap();
+                adjacency[*start_idx][*end_idx] = Some(dist);
+                continue;
+            }
+
+            for next in neighbours(node, grid) {
+                if !visited[next] {
+                    visited[node] = true;
+                    stack.push((dist + 1, next));
+                }
+            }
+        }
+    }
+    adjacency
+}
+
+pub(crate) fn neighbours(pos: Pos, grid: &Grid<char>) -> Vec<Pos> {
+    use Direction::*;
+
+    let dirs = match grid[pos] {
+        '.' => vec![Left, Right, Up, Down],
+        slope => Direction::from_char(slope).into_iter().collect(),
+    };
+    dirs.into_iter()
+        .filter_map(|dir| grid.neighbour(pos, dir))
+        .filter(|&pos| grid[pos] != '#')
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::dedent;
+
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+
+    #[test]
+    fn test_parse_error() {
+        let err = process("#.#\n#.#\n###").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(3, 1, "a row with a path tile '.'", "'###'")
+        );
+    }
+
+    #[test]
+    fn test_no_path() {
+        let err = process("#.#\n###\n#.#").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected a path from the start to the end, found end of input"
+        );
+    }
+
+    #[test]
+    fn test_topological_order() {
+        // The trails to the start and from the end go both ways, and the longest hike takes the
+        // junction before the end first, so the order of those loops matters.
+        let adjacency = [
+            vec![None, Some(1), None, None],
+            vec![Some(1), None, Some(5), Some(2)],
+            vec![None, None, None, Some(5)],
+            vec![None, None, Some(5), None],
+        ];
+        let nodes = [(1, 0), (1, 1), (3, 3), (5, 4)];
+        let order = topological_order(&adjacency, &nodes).unwrap();
+        assert_eq!(order, [0, 1, 2, 3]);
+        let (dists, prev) = longest_hikes(&adjacency, &order);
+        assert_eq!(dists, [Some(0), Some(1), Some(6), Some(11)]);
+        assert_eq!(prev, [None, Some(0), Some(1), Some(2)]);
+
+        let input = "
+            #.#####
+            #.>...#
+            #v###v#
+            #...>.#
+            #####.#
+        ";
+        assert_eq!(process(&dedent(input)).unwrap(), 8);
+
+        // Without the slopes, the hikes can go around the block in the middle.
+        let input = input.replace(['>', 'v'], ".");
+        let err = process(&dedent(&input)).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(
+                2,
+                2,
+                "slopes that keep the hikes from going in circles",
+                "a loop through this junction"
+            )
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use aoc::{grid::Pos, Grid, ParseError};
+
+use crate::part1::{self, get_collapsed_nodes};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let grid = Grid::parse(input, "#.<>^v")?;
+
+    let nodes = get_collapsed_nodes(&grid)?;
+    let adjacency = get_adjacency(&nodes, &grid);
+
+    let mut visited = vec![false; adjacency.len()];
+    dfs(0, &adjacency, &mut visited)
+        .map(|dist| dist as i64)
+        .ok_or_else(|| part1::no_path(input))
+}
+
+/// The longest path from `node` to the end that avoids the `visited` nodes, if there is one.
+fn dfs(node: usize, adjacency: &[Vec<Option<i32>>], visited: &mut [bool]) -> Option<i32> {
+    if node == adjacency.len() - 1 {
+        return Some(0);
+    }
+
+    visited[node] = true;
+    let max = adjacency[node]
+        .iter()
+        .enumerate()
+        .filter_map(|(next, dist)| dist.map(|dist| (next, dist)))
+        .filter_map(|(next, dist)| match visited[next] {
+            true => None,
+            false => dfs(next, adjacency, visited).map(|len| len + dist),
+        })
+        .max();
+
+    visited[node] = false;
+    max
+}
+
+pub(crate) fn get_adjacency(nodes: &[Pos], grid: &Grid<char>) -> Vec<Vec<Option<i32>>> {
+    let nodes: HashMap<Pos, usize> = nodes.iter().cloned().zip(0..).collect();
+    let mut adjacency = vec![vec![None; nodes.len()]; nodes.len()];
+
+    let visited = &mut Grid::filled(grid.width(), grid.height(), false);
+
+    for (start, start_idx) in nodes.iter() {
+        let mut stack = vec![(0, *start)];
+        visited[*start] = true;
+
+        while let Some((dist, node)) = stack.pop() {
+            if node != *start && nodes.contains_key(&node) {
+                let end_idx = nodes.get(&node).unwrap();
+                adjacency[*start_idx][*end_idx] = Some(dist);
+                adjacency[*end_idx][*start_idx] = Some(dist);
+                continue;
+            }
+
+            for next in neighbours(node, grid) {
+                if !visited[next] {
+                    visited[node] = true;
+                    stack.push((dist + 1, next));
+                }
+            }
+        }
+    }
+    adjacency
+}
+
+pub(crate) fn neighbours(pos: Pos, grid: &Grid<char>) -> Vec<Pos> {
+    grid.neighbours4(pos)
+        .filter(|&pos| grid[pos] != '#')
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+
+    #[test]
+    fn test_no_path() {
+        let err = process("#.#\n###\n#.#").unwrap_err();
+        assert_eq!(err.expected, "a path from the start to the end");
+    }
+}
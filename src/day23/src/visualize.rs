@@ -0,0 +1,151 @@
+//! Frames of the longest hike being walked, one trail between junctions at a time, for
+//! `--visualize`.
+
+use aoc::{
+    grid::Pos,
+    render::{Cell, Color, Frame, Style},
+    Grid, ParseError,
+};
+
+use crate::{part1, part2};
+
+const HIKE: Style = Style::fg(Color::Yellow).bold();
+const JUNCTION: Style = Style::fg(Color::Black).on(Color::Magenta);
+
+type Adjacency = Vec<Vec<Option<i32>>>;
+
+/// The longest hike that only goes down the slopes.
+pub fn part1(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let grid = Grid::parse(input, "#.<>^v")?;
+    let nodes = part1::get_collapsed_nodes(&grid)?;
+    let adjacency = part1::get_adjacency(&nodes, &grid);
+
+    let order = part1::topological_order(&adjacency, &nodes)?;
+    let (dists, prev) = part1::longest_hikes(&adjacency, &order);
+    if dists[nodes.len() - 1].is_none() {
+        return Err(part1::no_path(input));
+    }
+
+    let mut route = vec![nodes.len() - 1];
+    while let Some(node) = prev[*route.last().unwrap()] {
+        route.push(node);
+    }
+    route.reverse();
+    Ok(walk(grid, nodes, adjacency, route, part1::neighbours))
+}
+
+/// The longest hike when the slopes can be climbed too.
+pub fn part2(input: &str) -> Result<impl Iterator<Item = Frame>, ParseError> {
+    let grid = Grid::parse(input, "#.<>^v")?;
+    let nodes = part1::get_collapsed_nodes(&grid)?;
+    let adjacency = part2::get_adjacency(&nodes, &grid);
+
+    let mut visited = vec![false; nodes.len()];
+    let (_, route) =
+        longest_route(0, &adjacency, &mut visited).ok_or_else(|| part1::no_path(input))?;
+    Ok(walk(grid, nodes, adjacency, route, part2::neighbours))
+}
+
+/// The length and the junctions of the longest route from `node` to the last junction, like the
+/// search of part 2 but keeping the route.
+fn longest_route(
+    node: usize,
+    adjacency: &Adjacency,
+    visited: &mut [bool],
+) -> Option<(i32, Vec<usize>)> {
+    if node == adjacency.len() - 1 {
+        return Some((0, vec![node]));
+    }
+
+    visited[node] = true;
+    let best = adjacency[node]
+        .iter()
+        .enumerate()
+        .filter_map(|(next, dist)| dist.map(|dist| (next, dist)))
+        .filter_map(|(next, dist)| match visited[next] {
+            true => None,
+            false => {
+                longest_route(next, adjacency, visited).map(|(len, route)| (len + dist, route))
+            }
+        })
+        .max_by_key(|(len, _)| *len);
+
+    visited[node] = false;
+    best.map(|(len, mut route)| {
+        route.insert(0, node);
+        (len, route)
+    })
+}
+
+/// A frame for every trail of `route`, drawn as arrows on top of the map.
+fn walk(
+    grid: Grid<char>,
+    nodes: Vec<Pos>,
+    adjacency: Adjacency,
+    route: Vec<usize>,
+    neighbours: fn(Pos, &Grid<char>) -> Vec<Pos>,
+) -> impl Iterator<Item = Frame> {
+    let mut frame = Frame::new(&grid, |&ch| match ch {
+        '#' => Cell::new('#', Style::fg(Color::Gray)),
+        ch => Cell::plain(ch),
+    });
+    frame.highlight(nodes.iter().copied(), JUNCTION);
+
+    let mut path = vec![nodes[route[0]]];
+    (1..route.len()).map(move |i| {
+        let (from, to) = (route[i - 1], route[i]);
+        let dist = adjacency[from][to].unwrap() as usize;
+        let trail = trail(&grid, &nodes, nodes[from], nodes[to], dist, neighbours);
+        path.extend(trail.into_iter().skip(1));
+
+        let mut current = frame.clone();
+        current.path(&path, HIKE);
+        current.with_caption(format!("{} steps", path.len() - 1))
+    })
+}
+
+/// The tiles of the trail of length `dist` from the junction `from` to the junction `to`.
+fn trail(
+    grid: &Grid<char>,
+    nodes: &[Pos],
+    from: Pos,
+    to: Pos,
+    dist: usize,
+    neighbours: fn(Pos, &Grid<char>) -> Vec<Pos>,
+) -> Vec<Pos> {
+    for first in neighbours(from, grid) {
+        let mut trail = vec![from, first];
+        while !nodes.contains(trail.last().unwrap()) {
+            let (prev, curr) = (trail[trail.len() - 2], trail[trail.len() - 1]);
+            match neighbours(curr, grid)
+                .into_iter()
+                .find(|&next| next != prev)
+            {
+                Some(next) => trail.push(next),
+                None => break,
+            }
+        }
+        if *trail.last().unwrap() == to && trail.len() == dist + 1 {
+            return trail;
+        }
+    }
+    panic!("No trail of length {dist} from {from:?} to {to:?}");
+}
+
+#[cfg(test)]
+mod test {
+    use aoc::fixture::Example;
+
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        let example = Example::load(env!("CARGO_MANIFEST_DIR"), "example", "part1");
+        let last = part1(&example.input).unwrap().last().unwrap();
+        assert_eq!(last.caption(), format!("{} steps", example.answer));
+
+        let example = Example::load(env!("CARGO_MANIFEST_DIR"), "example", "part2");
+        let last = part2(&example.input).unwrap().last().unwrap();
+        assert_eq!(last.caption(), format!("{} steps", example.answer));
+    }
+}
@@ -0,0 +1,116 @@
+//! Random wiring diagrams.
+
+use std::collections::{BTreeMap, HashSet};
+
+use aoc::random::Rng;
+
+/// A diagram of `size` components, at least ten, in two groups of at least five joined by three
+/// wires. Each group is wired in a ring to the next two components on it, and then some, so that
+/// cutting three wires is only ever enough between the groups.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(10);
+
+    let mut names = Vec::new();
+    let mut seen = HashSet::new();
+    while names.len() < size {
+        let name = rng.string(3, "abcdefghijklmnopqrstuvwxyz");
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    let split = rng.range(5..=size - 5);
+    let (left, right) = names.split_at(split);
+    let mut wires = HashSet::new();
+    for group in [left, right] {
+        let len = group.len();
+        for i in 0..len {
+            wires.insert(wire(&group[i], &group[(i + 1) % len]));
+            wires.insert(wire(&group[i], &group[(i + 2) % len]));
+        }
+        for _ in 0..len / 2 {
+            let (a, b) = (rng.pick(group), rng.pick(group));
+            if a != b {
+                wires.insert(wire(a, b));
+            }
+        }
+    }
+    let mut ends = (left.to_vec(), right.to_vec());
+    rng.shuffle(&mut ends.0);
+    rng.shuffle(&mut ends.1);
+    for i in 0..3 {
+        wires.insert(wire(&ends.0[i], &ends.1[i]));
+    }
+
+    // List each wire on the line of one of its ends.
+    let mut wires: Vec<(String, String)> = wires.into_iter().collect();
+    wires.sort_unstable();
+    let mut lines: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (a, b) in wires {
+        let (from, to) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        lines.entry(from).or_default().push(to);
+    }
+    let mut lines: Vec<String> = lines
+        .into_iter()
+        .map(|(from, to)| format!("{from}: {}\n", to.join(" ")))
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+fn wire(a: &str, b: &str) -> (String, String) {
+    (a.min(b).to_owned(), a.max(b).to_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::part1;
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = input(seed, 12);
+            let wires: Vec<(&str, &str)> = input
+                .lines()
+                .flat_map(|line| {
+                    let (from, to) = line.split_once(": ").unwrap();
+                    to.split(' ').map(move |to| (from, to))
+                })
+                .collect();
+
+            // Cut every three wires and see whether the diagram falls apart.
+            let mut products = Vec::new();
+            for i in 0..wires.len() {
+                for j in i + 1..wires.len() {
+                    for k in j + 1..wires.len() {
+                        let mut next: HashMap<&str, Vec<&str>> = HashMap::new();
+                        for (n, &(a, b)) in wires.iter().enumerate() {
+                            if ![i, j, k].contains(&n) {
+                                next.entry(a).or_default().push(b);
+                                next.entry(b).or_default().push(a);
+                            }
+                        }
+                        let mut reached = HashSet::from([wires[0].0]);
+                        let mut stack = vec![wires[0].0];
+                        while let Some(node) = stack.pop() {
+                            for &to in next.get(node).into_iter().flatten() {
+                                if reached.insert(to) {
+                                    stack.push(to);
+                                }
+                            }
+                        }
+                        if reached.len() < 12 {
+                            products.push(reached.len() * (12 - reached.len()));
+                        }
+                    }
+                }
+            }
+            assert_eq!(products.len(), 1);
+            assert_eq!(part1::process(&input).unwrap(), products[0] as i64);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+
+use aoc::{graph, parse, ParseError};
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    let graph = parse_graph(input)?;
+    let cut = graph::stoer_wagner(&graph, graph.keys().copied()).unwrap();
+    let partition_len = cut.side.len();
+    Ok(((graph.len() - partition_len) * partition_len) as i64)
+}
+
+fn parse_graph(input: &str) -> Result<HashMap<&str, Vec<&str>>, ParseError> {
+    let mut graph: HashMap<&str, Vec<&str>> = HashMap::new();
+    for line in parse::lines(input) {
+        let (node, neighbours) = line.split_once(line.text, ":")?;
+        for next in neighbours.split_whitespace() {
+            graph.entry(node).or_default().push(next);
+            graph.entry(next).or_default().push(node);
+        }
+    }
+
+    if graph.len() < 2 {
+        return Err(ParseError::at_end(input, "a component"));
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part1: example);
+}
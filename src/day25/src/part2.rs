@@ -0,0 +1,15 @@
+use aoc::ParseError;
+
+// There is only one puzzle on the last day. This used to be a second Stoer Wagner implementation,
+// keyed by the names of the components instead of indices, before both moved to `aoc::graph`.
+
+pub fn process(input: &str) -> Result<i64, ParseError> {
+    crate::part1::process(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    aoc::example_tests!(process, part2: example);
+}
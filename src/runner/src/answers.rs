@@ -0,0 +1,367 @@
+//! The file of accepted answers, `answers.toml` in the root of the repository.
+//!
+//! Only the subset of TOML the file needs is understood: a `[dayNN.partN]` table per puzzle with
+//! an integer or string `answer` and an optional `time_ms`, plus `#` comments. `aoc submit` also
+//! records what it learns from rejected answers, as `too_low`, `too_high` and a `wrong` array.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc::{
+    parse::{self, Line},
+    Answer, ParseError,
+};
+
+use crate::Error;
+
+/// The default location of the answers file.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../answers.toml")
+}
+
+/// What is known about one part of a puzzle.
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct Entry {
+    pub answer: Option<Answer>,
+    /// How long the solution took when the answer was recorded.
+    pub time: Option<Duration>,
+    /// The highest answer that was rejected as too low.
+    pub too_low: Option<Answer>,
+    /// The lowest answer that was rejected as too high.
+    pub too_high: Option<Answer>,
+    /// Other answers that were rejected.
+    pub wrong: Vec<Answer>,
+}
+
+impl Entry {
+    /// Why `answer` can't be right, if what is known about the puzzle rules it out.
+    pub fn rejects(&self, answer: &Answer) -> Option<String> {
+        if self.wrong.contains(answer) {
+            return Some(format!("{answer} was already rejected"));
+        }
+        match (&self.too_low, &self.too_high) {
+            (Some(low), _) if answer <= low => {
+                Some(format!("{answer} is not above {low}, which is too low"))
+            }
+            (_, Some(high)) if answer >= high => {
+                Some(format!("{answer} is not below {high}, which is too high"))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Default, Debug)]
+pub struct Answers {
+    entries: BTreeMap<(u32, u32), Entry>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = std::fs::read_to_string(path).map_err(|err| Error::Read(path.into(), err))?;
+        Self::parse(&text).map_err(|err| Error::Parse(path.into(), err))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut entries = BTreeMap::new();
+        let mut current = None;
+
+        for line in parse::lines(text).filter(|line| !line.text.starts_with('#')) {
+            if let Some(header) = line.text.strip_prefix('[') {
+                let key = parse_header(&line, header)?;
+                if entries.insert(key, Entry::default()).is_some() {
+                    return Err(line.error(line.text, "each puzzle to have a single table"));
+                }
+                current = Some(key);
+                continue;
+            }
+
+            let entry = current
+                .and_then(|key| entries.get_mut(&key))
+                .ok_or_else(|| line.error(line.text, "a '[dayNN.partN]' header"))?;
+            let (key, value) = line.split_once(line.text, "=")?;
+            let value = strip_comment(&line, value.trim())?;
+            match key.trim() {
+                "answer" => entry.answer = Some(parse_answer(&line, value)?),
+                "time_ms" => {
+                    let ms: f64 = line.number(value)?;
+                    let time = Duration::try_from_secs_f64(ms / 1e3)
+                        .map_err(|_| line.error(value, "a duration in milliseconds"))?;
+                    entry.time = Some(time);
+                }
+                "too_low" => entry.too_low = Some(parse_answer(&line, value)?),
+                "too_high" => entry.too_high = Some(parse_answer(&line, value)?),
+                "wrong" => {
+                    let list = line.strip_prefix(value, "[")?;
+                    let list = line.strip_suffix(list, "]")?;
+                    entry.wrong = list
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|value| !value.is_empty())
+                        .map(|value| parse_answer(&line, value))
+                        .collect::<Result<_, _>>()?;
+                }
+                key => {
+                    return Err(
+                        line.error(key, "'answer', 'time_ms', 'too_low', 'too_high' or 'wrong'")
+                    )
+                }
+            }
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&Entry> {
+        self.entries.get(&(day, part))
+    }
+}
+
+/// `text` with `key` set to `value` in the table of the puzzle, which is added if it's missing.
+/// Everything else in the file, comments included, is kept as it is.
+pub fn set_value(text: &str, puzzle: (u32, u32), key: &str, value: &str) -> String {
+    let text = add_table(text, puzzle);
+    let new_line = format!("{key} = {value}");
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let start = lines
+        .iter()
+        .position(|line| table_key(line) == Some(puzzle))
+        .unwrap();
+    let end = (start + 1..lines.len())
+        .find(|&i| lines[i].trim_start().starts_with('['))
+        .unwrap_or(lines.len());
+    let existing = (start + 1..end).find(|&i| {
+        let line = lines[i].split('#').next().unwrap();
+        line.split_once('=')
+            .is_some_and(|(name, _)| name.trim() == key)
+    });
+    match existing {
+        Some(i) => lines[i] = &new_line,
+        None => {
+            let last = (start..end)
+                .rfind(|&i| !lines[i].trim().is_empty())
+                .unwrap();
+            lines.insert(last + 1, &new_line);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// `text` with an empty table for the puzzle, unless it has one already.
+pub fn add_table(text: &str, (day, part): (u32, u32)) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines
+        .iter()
+        .any(|line| table_key(line) == Some((day, part)))
+    {
+        return text.to_owned();
+    }
+
+    // Tables are kept in the order of the puzzles.
+    let header = format!("[day{day:02}.part{part}]");
+    let next = lines
+        .iter()
+        .position(|line| table_key(line).is_some_and(|other| other > (day, part)));
+    match next {
+        Some(i) => {
+            lines.splice(i..i, [header.as_str(), ""]);
+        }
+        None => {
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                lines.push("");
+            }
+            lines.push(&header);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// The puzzle of a `[dayNN.partN]` line.
+fn table_key(line: &str) -> Option<(u32, u32)> {
+    let header = line.trim().strip_prefix("[day")?.strip_suffix(']')?;
+    let (day, part) = header.split_once(".part")?;
+    Some((day.parse().ok()?, part.parse().ok()?))
+}
+
+/// An answer written the way the file expects it.
+pub fn format_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Str(str) => format!("\"{str}\""),
+        answer => answer.to_string(),
+    }
+}
+
+/// A `time_ms` value, rounded to two significant digits.
+pub fn format_time(time: Duration) -> String {
+    let ms = time.as_secs_f64() * 1e3;
+    if ms == 0.0 {
+        return "0".to_owned();
+    }
+    let decimals = 1 - ms.log10().floor() as i32;
+    match decimals {
+        0.. => format!("{ms:.*}", decimals as usize),
+        _ => {
+            let scale = 10_f64.powi(-decimals);
+            format!("{}", (ms / scale).round() * scale)
+        }
+    }
+}
+
+fn parse_header(line: &Line, header: &str) -> Result<(u32, u32), ParseError> {
+    let header = line.strip_suffix(header, "]")?;
+    let (day, part) = line.split_once(header, ".")?;
+    let day = line.strip_prefix(day, "day")?;
+    let part = line.strip_prefix(part, "part")?;
+
+    let (day, part) = (line.number(day)?, line.number(part)?);
+    if !(1..=25).contains(&day) {
+        return Err(line.error(header, "a day from 1 to 25"));
+    }
+    if !(1..=2).contains(&part) {
+        return Err(line.error(header, "part 1 or 2"));
+    }
+    Ok((day, part))
+}
+
+/// The value without a trailing comment.
+pub fn strip_comment<'a>(line: &Line<'a>, value: &'a str) -> Result<&'a str, ParseError> {
+    let closing = |open, close| match value.strip_prefix(open) {
+        Some(rest) => match rest.find(close) {
+            Some(end) => Ok(Some(end + 2)),
+            None => Err(line.error(&value[value.len()..], format!("'{close}'"))),
+        },
+        None => Ok(None),
+    };
+    let end = match (closing('"', '"')?, closing('[', ']')?) {
+        (Some(end), _) | (_, Some(end)) => end,
+        _ => value.find('#').unwrap_or(value.len()),
+    };
+    let (value, rest) = value.split_at(end);
+    let rest = rest.trim_start();
+    match rest.is_empty() || rest.starts_with('#') {
+        true => Ok(value.trim_end()),
+        false => Err(line.error(rest, "the end of the line")),
+    }
+}
+
+fn parse_answer(line: &Line, value: &str) -> Result<Answer, ParseError> {
+    if let Some(str) = value.strip_prefix('"') {
+        return line.strip_suffix(str, "\"").map(Answer::from);
+    }
+    match value.parse::<i64>() {
+        Ok(num) => Ok(Answer::from(num)),
+        Err(_) => line.number::<u128>(value).map(Answer::from),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            r#"
+            # comment
+            [day01.part1]
+            answer = 56506 # comment
+            time_ms = 0.5
+
+            [day19.part2]
+            answer = 132380153677887
+            [day21.part2]
+            answer = "a # b"
+            [day24.part1]
+            too_low = 12 # comment
+            too_high = 100
+            wrong = [17, "x # y", 30]
+            [day25.part1]
+            "#,
+        )
+        .unwrap();
+
+        let entry = answers.get(1, 1).unwrap();
+        assert_eq!(entry.answer, Some(Answer::from(56506)));
+        assert_eq!(entry.time, Some(Duration::from_micros(500)));
+        assert_eq!(
+            answers.get(19, 2).unwrap().answer,
+            Some(Answer::from(132380153677887_u64))
+        );
+        assert_eq!(answers.get(21, 2).unwrap().answer, Some("a # b".into()));
+        let entry = answers.get(24, 1).unwrap();
+        assert_eq!(
+            (entry.too_low.clone(), entry.too_high.clone()),
+            (Some(12.into()), Some(100.into()))
+        );
+        assert_eq!(entry.wrong, [Answer::from(17), "x # y".into(), 30.into()]);
+        assert_eq!(
+            entry.rejects(&12.into()),
+            Some("12 is not above 12, which is too low".into())
+        );
+        assert_eq!(
+            entry.rejects(&30.into()),
+            Some("30 was already rejected".into())
+        );
+        assert_eq!(entry.rejects(&31.into()), None);
+        assert_eq!(answers.get(25, 1), Some(&Entry::default()));
+        assert_eq!(answers.get(25, 2), None);
+    }
+
+    #[test]
+    fn test_set_value() {
+        let text =
+            "# answers\n\n[day01.part1]\nanswer = 1 # comment\n\n[day03.part1]\nanswer = 3\n";
+        let text = set_value(text, (1, 1), "time_ms", "0.5");
+        let text = set_value(&text, (1, 1), "answer", "2");
+        let text = set_value(&text, (2, 1), "answer", "\"b\"");
+        let text = set_value(&text, (4, 2), "wrong", "[1, 2]");
+        assert_eq!(
+            text,
+            "# answers\n\n[day01.part1]\nanswer = 2\ntime_ms = 0.5\n\n[day02.part1]\nanswer = \"b\"\n\n\
+             [day03.part1]\nanswer = 3\n\n[day04.part2]\nwrong = [1, 2]\n"
+        );
+        assert_eq!(
+            set_value("", (1, 2), "answer", "1"),
+            "[day01.part2]\nanswer = 1\n"
+        );
+        let text = add_table("[day03.part1]\nanswer = 3\n", (2, 2));
+        assert_eq!(text, "[day02.part2]\n\n[day03.part1]\nanswer = 3\n");
+        assert_eq!(add_table(&text, (2, 2)), text);
+
+        let ms = |ms: f64| format_time(Duration::from_secs_f64(ms / 1e3));
+        assert_eq!(
+            [ms(0.0657), ms(2.23), ms(10.0), ms(157.0), ms(680.4)],
+            ["0.066", "2.2", "10", "160", "680"]
+        );
+        assert_eq!(format_answer(&Answer::from("abc")), "\"abc\"");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = |text| Answers::parse(text).unwrap_err().to_string();
+        assert_eq!(
+            error("answer = 1"),
+            "line 1, column 1: expected a '[dayNN.partN]' header, found 'answer = 1'"
+        );
+        assert_eq!(
+            error("[day1.part3]"),
+            "line 1, column 2: expected part 1 or 2, found 'day1.part3'"
+        );
+        assert_eq!(
+            error("[day1.part1]\nanswer = 1x"),
+            "line 2, column 10: expected a number, found '1x'"
+        );
+        assert_eq!(
+            error("[day1.part1]\nanswer = \"1\" 2"),
+            "line 2, column 14: expected the end of the line, found '2'"
+        );
+        assert_eq!(
+            error("[day1.part1]\ntime = 1"),
+            "line 2, column 1: expected 'answer', 'time_ms', 'too_low', 'too_high' or 'wrong', found 'time'"
+        );
+        assert!(Answers::parse("[day1.part1]\n[day1.part1]").is_err());
+    }
+}
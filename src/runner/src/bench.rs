@@ -0,0 +1,334 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    input::Source,
+    json::{self, Value},
+};
+
+use crate::{
+    parse_number,
+    registry::Puzzle,
+    run::{self, Selection},
+    table::{format_duration, Table},
+    Error,
+};
+
+const MAX_RUNS: u32 = 100_000;
+const MAX_SIZE: u32 = 1_000_000;
+
+#[derive(PartialEq, Debug)]
+struct Args {
+    selection: Selection,
+    input: Option<Source>,
+    /// The seed to generate a random input from instead of reading one.
+    generate: Option<u64>,
+    size: usize,
+    runs: usize,
+    warmup: usize,
+    /// Where to save the timings as the new baseline.
+    save: Option<PathBuf>,
+    /// The timings to compare against.
+    baseline: Option<PathBuf>,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, Error> {
+    let mut all = false;
+    let mut numbers = Vec::new();
+    let mut input = None;
+    let (mut generate, mut size) = (None, 100);
+    let (mut runs, mut warmup) = (10, 2);
+    let (mut save, mut baseline) = (None, None);
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::Usage(format!("missing value for '{arg}'")))
+        };
+        match arg.as_str() {
+            "--all" => all = true,
+            "-i" | "--input" => input = Some(Source::from_arg(value()?)),
+            "--generate" => generate = Some(parse_number(value()?, "seed", 0..=u32::MAX)?),
+            "--size" => size = parse_number(value()?, "input size", 1..=MAX_SIZE)?,
+            "-n" | "--runs" => runs = parse_number(value()?, "number of runs", 1..=MAX_RUNS)?,
+            "--warmup" => warmup = parse_number(value()?, "number of warm-up runs", 0..=MAX_RUNS)?,
+            "--save" => save = Some(value()?.into()),
+            "--baseline" => baseline = Some(value()?.into()),
+            _ if arg.starts_with('-') => {
+                return Err(Error::Usage(format!("unexpected argument '{arg}'")))
+            }
+            _ => numbers.push(arg),
+        }
+    }
+
+    if all && input.is_some() {
+        return Err(Error::Usage("'--input' can't be used with '--all'".into()));
+    }
+    if generate.is_some() && input.is_some() {
+        return Err(Error::Usage(
+            "'--input' can't be used with '--generate'".into(),
+        ));
+    }
+    Ok(Args {
+        selection: run::parse_selection(all, &numbers)?,
+        input,
+        generate: generate.map(u64::from),
+        size: size as usize,
+        runs: runs as usize,
+        warmup: warmup as usize,
+        save,
+        baseline,
+    })
+}
+
+/// The timings of a number of runs of one solution.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+struct Stats {
+    runs: usize,
+    min: Duration,
+    median: Duration,
+    p95: Duration,
+}
+
+impl Stats {
+    fn new(mut times: Vec<Duration>) -> Self {
+        times.sort();
+        let runs = times.len();
+        // The nearest-rank percentile.
+        let percentile = |p: usize| times[(runs * p).div_ceil(100).max(1) - 1];
+        Self {
+            runs,
+            min: times[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+
+    fn to_json(self, puzzle: (u32, u32)) -> Value {
+        let nanos = |time: Duration| Value::from(time.as_nanos() as u64);
+        Value::object([
+            ("day", Value::from(puzzle.0 as u64)),
+            ("part", Value::from(puzzle.1 as u64)),
+            ("runs", Value::from(self.runs as u64)),
+            ("min_ns", nanos(self.min)),
+            ("median_ns", nanos(self.median)),
+            ("p95_ns", nanos(self.p95)),
+        ])
+    }
+
+    fn from_json(value: &Value) -> Option<((u32, u32), Self)> {
+        let field = |key| value.get(key).and_then(Value::as_u64);
+        let time = |key| field(key).map(Duration::from_nanos);
+        let puzzle = (field("day")? as u32, field("part")? as u32);
+        let stats = Self {
+            runs: field("runs")? as usize,
+            min: time("min_ns")?,
+            median: time("median_ns")?,
+            p95: time("p95_ns")?,
+        };
+        Some((puzzle, stats))
+    }
+}
+
+type Baseline = BTreeMap<(u32, u32), Stats>;
+
+fn load_baseline(path: &Path) -> Result<Baseline, Error> {
+    let text = fs::read_to_string(path).map_err(|err| Error::Read(path.into(), err))?;
+    let value = json::parse(&text).map_err(|err| Error::Parse(path.into(), err))?;
+    value
+        .as_array()
+        .and_then(|entries| entries.iter().map(Stats::from_json).collect())
+        .ok_or_else(|| Error::Invalid(path.into(), "expected an array of timings".into()))
+}
+
+fn save_baseline(path: &Path, baseline: &Baseline) -> Result<(), Error> {
+    let entries: Vec<String> = baseline
+        .iter()
+        .map(|(&puzzle, stats)| format!("  {}", stats.to_json(puzzle)))
+        .collect();
+    let text = format!("[\n{}\n]\n", entries.join(",\n"));
+    fs::write(path, text).map_err(|err| Error::Write(path.into(), err))
+}
+
+/// Runs the solution `warmup` times without timing it, then `runs` times with.
+fn bench(puzzle: &Puzzle, input: &str, runs: usize, warmup: usize) -> Result<Stats, String> {
+    for _ in 0..warmup {
+        puzzle.solve(input).map_err(|err| err.to_string())?;
+    }
+
+    let mut times = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let answer = puzzle.solve(input);
+        times.push(start.elapsed());
+        answer.map_err(|err| err.to_string())?;
+    }
+    Ok(Stats::new(times))
+}
+
+/// The change from `old` to `new` in percent.
+fn format_change(old: Duration, new: Duration) -> String {
+    let change = (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+    format!("{change:+.1}%")
+}
+
+pub fn run(args: &[String]) -> Result<ExitCode, Error> {
+    let args = parse_args(args)?;
+    let puzzles = run::select(&args.selection)?;
+    let baseline = match &args.baseline {
+        Some(path) => Some(load_baseline(path)?),
+        None => None,
+    };
+
+    let mut headers = vec!["day", "part", "runs", "min", "median", "p95"];
+    if baseline.is_some() {
+        headers.extend(["baseline", "change"]);
+    }
+    let mut table = Table::new(&headers);
+    let mut results = Baseline::new();
+    let mut inputs: HashMap<Source, Result<String, String>> = HashMap::new();
+    let mut generated: HashMap<u32, Result<String, String>> = HashMap::new();
+    let mut failed = false;
+
+    for puzzle in puzzles.iter() {
+        let input = match args.generate {
+            Some(seed) => generated
+                .entry(puzzle.day())
+                .or_insert_with(|| Ok(puzzle.solution.generate(seed, args.size))),
+            None => {
+                let source = match &args.input {
+                    Some(source) => source.clone(),
+                    None => Source::File(puzzle.default_input()),
+                };
+                inputs
+                    .entry(source.clone())
+                    .or_insert_with(|| source.read().map_err(|err| err.to_string()))
+            }
+        };
+
+        let key = (puzzle.day(), puzzle.part);
+        let mut row = vec![key.0.to_string(), key.1.to_string()];
+        let stats = match input {
+            Ok(input) => bench(puzzle, input, args.runs, args.warmup),
+            Err(err) => Err(err.clone()),
+        };
+        match stats {
+            Ok(stats) => {
+                row.extend([
+                    stats.runs.to_string(),
+                    format_duration(stats.min),
+                    format_duration(stats.median),
+                    format_duration(stats.p95),
+                ]);
+                if let Some(baseline) = &baseline {
+                    row.extend(match baseline.get(&key) {
+                        Some(old) => [
+                            format_duration(old.median),
+                            format_change(old.median, stats.median),
+                        ],
+                        None => ["-".to_owned(), "-".to_owned()],
+                    });
+                }
+                results.insert(key, stats);
+            }
+            Err(err) => {
+                failed = true;
+                row.push(format!("error: {err}"));
+            }
+        }
+        table.push(row);
+    }
+    print!("{table}");
+
+    if let Some(path) = &args.save {
+        let mut saved = match path.exists() {
+            true => load_baseline(path)?,
+            false => Baseline::new(),
+        };
+        saved.extend(results);
+        save_baseline(path, &saved)?;
+        println!("\nsaved the timings to '{}'", path.display());
+    }
+
+    Ok(match failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+            parse_args(&args)
+        };
+
+        let args = parse(&["17", "2", "-n", "50", "--save", "new.json"]).unwrap();
+        assert_eq!(args.selection, Selection::Day(17, Some(2)));
+        assert_eq!((args.runs, args.warmup), (50, 2));
+        assert_eq!(args.save, Some(PathBuf::from("new.json")));
+        assert_eq!(args.baseline, None);
+
+        let args = parse(&["--all", "--warmup", "0", "--baseline", "old.json"]).unwrap();
+        assert_eq!(args.selection, Selection::All);
+        assert_eq!(args.warmup, 0);
+        assert_eq!((args.generate, args.size), (None, 100));
+
+        let args = parse(&["--all", "--generate", "7", "--size", "40"]).unwrap();
+        assert_eq!((args.generate, args.size), (Some(7), 40));
+
+        assert!(parse(&["17", "-n", "0"]).is_err());
+        assert!(parse(&["17", "--save"]).is_err());
+        assert!(parse(&["--all", "--input", "-"]).is_err());
+        assert!(parse(&["17", "--generate", "7", "--input", "-"]).is_err());
+        assert!(parse(&["17", "--generate", "7", "--size", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_stats() {
+        let times = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::new(times);
+        assert_eq!(stats.runs, 20);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+
+        let stats = Stats::new(vec![Duration::from_micros(5)]);
+        let time = Duration::from_micros(5);
+        assert_eq!((stats.min, stats.median, stats.p95), (time, time, time));
+
+        let json = stats.to_json((17, 2));
+        assert_eq!(
+            json.to_string(),
+            r#"{"day": 17, "part": 2, "runs": 1, "min_ns": 5000, "median_ns": 5000, "p95_ns": 5000}"#
+        );
+        assert_eq!(Stats::from_json(&json), Some(((17, 2), stats)));
+    }
+
+    #[test]
+    fn test_baseline() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        let stats = Stats::new(vec![Duration::from_millis(3)]);
+        let baseline = Baseline::from([((16, 2), stats), ((23, 1), stats)]);
+
+        save_baseline(&path, &baseline).unwrap();
+        let loaded = load_baseline(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), baseline);
+
+        assert_eq!(
+            format_change(Duration::from_millis(10), Duration::from_millis(8)),
+            "-20.0%"
+        );
+    }
+}
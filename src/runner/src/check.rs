@@ -0,0 +1,91 @@
+//! Checks the assumptions that the solutions of a day make about its input.
+
+use std::process::ExitCode;
+
+use aoc::input::Source;
+
+use crate::{parse_number, registry, Error};
+
+#[derive(PartialEq, Eq, Debug)]
+struct Args {
+    day: u32,
+    input: Option<Source>,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, Error> {
+    let mut numbers = Vec::new();
+    let mut input = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-i" | "--input" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| Error::Usage(format!("missing value for '{arg}'")))?;
+                input = Some(Source::from_arg(value));
+            }
+            _ if arg.starts_with('-') => {
+                return Err(Error::Usage(format!("unexpected argument '{arg}'")))
+            }
+            _ => numbers.push(arg),
+        }
+    }
+
+    let day = match numbers[..] {
+        [day] => parse_number(day, "day", 1..=25)?,
+        _ => return Err(Error::Usage("expected a day".into())),
+    };
+    Ok(Args { day, input })
+}
+
+pub fn check(args: &[String]) -> Result<ExitCode, Error> {
+    let args = parse_args(args)?;
+    let puzzle = registry::find(args.day, None)
+        .next()
+        .ok_or_else(|| Error::Usage(format!("there is no solution for day {}", args.day)))?;
+    let source = match args.input {
+        Some(source) => source,
+        None => Source::File(puzzle.default_input()),
+    };
+
+    let input = source.read()?;
+    let checks = puzzle
+        .solution
+        .check(&input)
+        .map_err(|err| Error::Parse(source.to_string().into(), err))?;
+    if checks.is_empty() {
+        println!("day {} makes no assumptions about its input", args.day);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    for check in checks.iter() {
+        println!("{check}");
+    }
+    Ok(match checks.iter().all(|check| check.holds()) {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, Error> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        parse_args(&args)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse(&["21", "--input", "-"]).unwrap();
+        assert_eq!(args.day, 21);
+        assert_eq!(args.input, Some(Source::Stdin));
+
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["21", "2"]).is_err());
+        assert!(parse(&["26"]).is_err());
+        assert!(parse(&["--all"]).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+//! Requests to the Advent of Code website, sent no more often than every few seconds.
+
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use ureq::{http::Response, Agent, Body};
+
+use crate::{config::Config, Error};
+
+/// The least time between two requests, across every run of the runner.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "github.com/raugl/advent-of-code-2023 (aoc runner)";
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    /// The file holding the time of the last request.
+    last_request: PathBuf,
+    pub min_interval: Duration,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Ok(Self {
+            agent,
+            base_url: config.base_url.trim_end_matches('/').to_owned(),
+            session: config.session()?.to_owned(),
+            last_request: config.cache_dir.join("last-request"),
+            min_interval: MIN_INTERVAL,
+        })
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        self.wait()?;
+        let url = format!("{}{path}", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call();
+        read_response(&url, response)
+    }
+
+    /// Sends `form` the way a browser submits an HTML form.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        self.wait()?;
+        let url = format!("{}{path}", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form(form.iter().copied());
+        read_response(&url, response)
+    }
+
+    /// Sleeps until enough time has passed since the last request, then records the next one.
+    fn wait(&self) -> Result<(), Error> {
+        let last = fs::read_to_string(&self.last_request)
+            .ok()
+            .and_then(|text| text.trim().parse().ok())
+            .map(|ms| UNIX_EPOCH + Duration::from_millis(ms));
+        let elapsed = last.and_then(|last| last.elapsed().ok());
+
+        if let Some(remaining) = elapsed.and_then(|elapsed| self.min_interval.checked_sub(elapsed))
+        {
+            eprintln!(
+                "waiting {:.1}s before the next request",
+                remaining.as_secs_f64()
+            );
+            thread::sleep(remaining);
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let write_error = |err| Error::Write(self.last_request.clone(), err);
+        if let Some(dir) = self.last_request.parent() {
+            fs::create_dir_all(dir).map_err(write_error)?;
+        }
+        fs::write(&self.last_request, now.as_millis().to_string()).map_err(write_error)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<Response<Body>, ureq::Error>,
+) -> Result<String, Error> {
+    let failed = |err| Error::Http(format!("request to '{url}' failed: {err}"));
+    let mut response = response.map_err(failed)?;
+    let body = response.body_mut().read_to_string().map_err(failed)?;
+
+    match response.status().as_u16() {
+        200 => Ok(body),
+        404 => Err(Error::Http(format!(
+            "'{url}' was not found, the puzzle may not be unlocked yet"
+        ))),
+        // The site answers a missing or expired session with one of these.
+        status @ (400 | 500) => Err(Error::Http(format!(
+            "'{url}' answered with status {status}, check that the session token is still valid"
+        ))),
+        status => {
+            let reason = body.lines().next().unwrap_or_default();
+            Err(Error::Http(format!(
+                "'{url}' answered with status {status}: {reason}"
+            )))
+        }
+    }
+}
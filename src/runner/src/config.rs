@@ -0,0 +1,153 @@
+//! Settings for talking to the Advent of Code website.
+//!
+//! They are read from `~/.config/aoc/config.toml`, or the file named by `AOC_CONFIG`, which holds
+//! `key = "value"` lines for `session`, `base_url` and `cache_dir`. The environment variables
+//! `AOC_SESSION`, `AOC_BASE_URL` and `AOC_CACHE_DIR` take precedence over the file.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use aoc::{parse, ParseError};
+
+use crate::{answers::strip_comment, Error};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Config {
+    /// The config file, which doesn't have to exist.
+    pub path: PathBuf,
+    /// The value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Where downloaded inputs are kept, in a directory per year.
+    pub cache_dir: PathBuf,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, Error> {
+        let path = match env::var_os("AOC_CONFIG") {
+            Some(path) => PathBuf::from(path),
+            None => home_dir("XDG_CONFIG_HOME", ".config")?.join("aoc/config.toml"),
+        };
+        let mut config = match path.exists() {
+            true => {
+                let text =
+                    std::fs::read_to_string(&path).map_err(|err| Error::Read(path.clone(), err))?;
+                Self::parse(&path, &text).map_err(|err| Error::Parse(path.clone(), err))?
+            }
+            false => Self::new(&path, home_dir("XDG_CACHE_HOME", ".cache")?.join("aoc")),
+        };
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        if let Some(cache_dir) = env::var_os("AOC_CACHE_DIR") {
+            config.cache_dir = cache_dir.into();
+        }
+        Ok(config)
+    }
+
+    /// The default settings, for a config file at `path`.
+    pub fn new(path: &Path, cache_dir: PathBuf) -> Self {
+        Self {
+            path: path.into(),
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            cache_dir,
+        }
+    }
+
+    pub fn parse(path: &Path, text: &str) -> Result<Self, ParseError> {
+        let mut cache_dir = None;
+        let mut config = Self::new(path, PathBuf::new());
+
+        for line in parse::lines(text).filter(|line| !line.text.starts_with('#')) {
+            let (key, value) = line.split_once(line.text, "=")?;
+            let value = strip_comment(&line, value.trim())?;
+            let value = line.strip_prefix(value, "\"")?;
+            let value = line.strip_suffix(value, "\"")?.to_owned();
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                "cache_dir" => cache_dir = Some(PathBuf::from(value)),
+                key => return Err(line.error(key, "'session', 'base_url' or 'cache_dir'")),
+            }
+        }
+
+        config.cache_dir = match cache_dir {
+            Some(dir) => dir,
+            None => home_dir("XDG_CACHE_HOME", ".cache")
+                .map_err(|_| ParseError::at_end(text, "a 'cache_dir', since $HOME is not set"))?
+                .join("aoc"),
+        };
+        Ok(config)
+    }
+
+    /// The session token, which every request needs.
+    pub fn session(&self) -> Result<&str, Error> {
+        self.session.as_deref().ok_or_else(|| {
+            let msg = "no session token, set 'session' here or the AOC_SESSION variable";
+            Error::Invalid(self.path.clone(), msg.into())
+        })
+    }
+}
+
+/// The directory named by the variable `var`, or else `fallback` inside the home directory.
+fn home_dir(var: &str, fallback: &str) -> Result<PathBuf, Error> {
+    if let Some(dir) = env::var_os(var).filter(|dir| !dir.is_empty()) {
+        return Ok(dir.into());
+    }
+    match env::var_os("HOME") {
+        Some(home) => Ok(PathBuf::from(home).join(fallback)),
+        None => Err(Error::Usage(format!("neither ${var} nor $HOME is set"))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let path = Path::new("config.toml");
+        let config = Config::parse(
+            path,
+            r#"
+            # comment
+            session = "53616c74" # comment
+            base_url = "http://localhost:8080"
+            cache_dir = "/tmp/aoc"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.session().unwrap(), "53616c74");
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.cache_dir, Path::new("/tmp/aoc"));
+
+        let config = Config::parse(path, "cache_dir = \"/tmp/aoc\"").unwrap();
+        assert_eq!(config, Config::new(path, "/tmp/aoc".into()));
+        assert_eq!(
+            config.session().unwrap_err().to_string(),
+            "config.toml: no session token, set 'session' here or the AOC_SESSION variable"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = |text| Config::parse(Path::new(""), text).unwrap_err().to_string();
+        assert_eq!(
+            error("session = 53616c74"),
+            "line 1, column 11: expected '\"', found '53616c74'"
+        );
+        assert_eq!(
+            error("token = \"53616c74\""),
+            "line 1, column 1: expected 'session', 'base_url' or 'cache_dir', found 'token'"
+        );
+    }
+}
@@ -0,0 +1,325 @@
+//! Runs the Python solutions and the Rust ones on the same input and compares their answers, so
+//! that a port can be shown to agree with the original before the Python is retired.
+
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode, Stdio},
+};
+
+use aoc::input::Source;
+
+use crate::{
+    parse_number,
+    registry::{self, day_dir},
+    table::Table,
+    Error,
+};
+
+/// How the Python scripts are run.
+#[derive(PartialEq, Eq, Debug)]
+enum Interpreter {
+    /// The `python3` of the development shell in the `flake.nix` next to the scripts.
+    Flake,
+    /// A given interpreter, for machines without nix.
+    Command(String),
+}
+
+impl Interpreter {
+    fn command(&self, day_dir: &Path, script: &Path) -> Command {
+        let mut command = match self {
+            Interpreter::Flake => {
+                let mut command = Command::new("nix");
+                command
+                    .arg("develop")
+                    .arg(day_dir)
+                    .args(["--command", "python3"]);
+                command
+            }
+            Interpreter::Command(program) => Command::new(program),
+        };
+        command.arg(script);
+        command
+    }
+
+    fn program(&self) -> &str {
+        match self {
+            Interpreter::Flake => "nix",
+            Interpreter::Command(program) => program,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+struct Args {
+    day: Option<u32>,
+    input: Option<Source>,
+    interpreter: Interpreter,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, Error> {
+    let mut numbers = Vec::new();
+    let mut input = None;
+    let mut interpreter = Interpreter::Flake;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::Usage(format!("missing value for '{arg}'")))
+        };
+        match arg.as_str() {
+            "-i" | "--input" => input = Some(Source::from_arg(value()?)),
+            "--python" => interpreter = Interpreter::Command(value()?.clone()),
+            _ if arg.starts_with('-') => {
+                return Err(Error::Usage(format!("unexpected argument '{arg}'")))
+            }
+            _ => numbers.push(arg),
+        }
+    }
+
+    let day = match numbers[..] {
+        [] => None,
+        [day] => Some(parse_number(day, "day", 1..=25)?),
+        _ => return Err(Error::Usage("expected at most a day".into())),
+    };
+    if day.is_none() && input.is_some() {
+        return Err(Error::Usage("'--input' needs a day".into()));
+    }
+    Ok(Args {
+        day,
+        input,
+        interpreter,
+    })
+}
+
+/// The `part1.py` and `part2.py` scripts in the directory of `day`, by part.
+fn scripts(day: u32) -> Vec<(u32, PathBuf)> {
+    (1..=2)
+        .map(|part| (part, day_dir(day).join(format!("part{part}.py"))))
+        .filter(|(_, path)| path.is_file())
+        .collect()
+}
+
+/// The answer printed by a script, on its last line of the form `Result: <answer>`.
+fn parse_output(stdout: &str) -> Option<String> {
+    stdout.lines().rev().find_map(|line| {
+        let answer = line.trim().strip_prefix("Result")?;
+        let answer = answer.strip_prefix(':').unwrap_or(answer).trim();
+        (!answer.is_empty()).then(|| answer.to_owned())
+    })
+}
+
+/// Runs `script` in `dir`, where the scripts expect their input as `input.txt`.
+fn run_script(
+    interpreter: &Interpreter,
+    day: u32,
+    script: &Path,
+    dir: &Path,
+) -> Result<String, String> {
+    let output = interpreter
+        .command(&day_dir(day), script)
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|err| format!("failed to run '{}': {err}", interpreter.program()))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let last = stderr.lines().rev().find(|line| !line.trim().is_empty());
+        return Err(last.map_or_else(|| output.status.to_string(), str::to_owned));
+    }
+    parse_output(&stdout).ok_or_else(|| "printed no result".to_owned())
+}
+
+/// How the answers of the two solutions of a part compare.
+#[derive(PartialEq, Eq, Debug)]
+enum Status {
+    Agree,
+    Disagree,
+    /// The part has only been solved in Python so far.
+    PythonOnly,
+    PythonFailed(String),
+    RustFailed(String),
+    /// The input couldn't be read, so neither solution ran.
+    Input(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Agree => write!(f, "agree"),
+            Status::Disagree => write!(f, "disagree"),
+            Status::PythonOnly => write!(f, "no rust solution"),
+            Status::PythonFailed(err) => write!(f, "python failed: {err}"),
+            Status::RustFailed(err) => write!(f, "rust failed: {err}"),
+            Status::Input(err) => write!(f, "error: {err}"),
+        }
+    }
+}
+
+impl Status {
+    fn is_failure(&self) -> bool {
+        !matches!(self, Status::Agree | Status::PythonOnly)
+    }
+}
+
+fn compare(python: &Result<String, String>, rust: Option<&Result<String, String>>) -> Status {
+    match (python, rust) {
+        (Err(err), _) => Status::PythonFailed(err.clone()),
+        (Ok(_), None) => Status::PythonOnly,
+        (Ok(_), Some(Err(err))) => Status::RustFailed(err.clone()),
+        (Ok(python), Some(Ok(rust))) if python == rust => Status::Agree,
+        (Ok(_), Some(Ok(_))) => Status::Disagree,
+    }
+}
+
+pub fn crosscheck(args: &[String]) -> Result<ExitCode, Error> {
+    let args = parse_args(args)?;
+    let days: Vec<u32> = match args.day {
+        Some(day) if scripts(day).is_empty() => {
+            return Err(Error::Usage(format!(
+                "there is no Python solution for day {day}"
+            )))
+        }
+        Some(day) => vec![day],
+        None => (1..=25).filter(|&day| !scripts(day).is_empty()).collect(),
+    };
+
+    let dir = env::temp_dir().join(format!("aoc-crosscheck-{}", std::process::id()));
+    fs::create_dir_all(&dir).map_err(|err| Error::Write(dir.clone(), err))?;
+
+    let mut table = Table::new(&["day", "part", "python", "rust", "status"]);
+    let mut count = 0;
+    let mut failed = 0;
+
+    for day in days {
+        let source = match &args.input {
+            Some(source) => source.clone(),
+            None => Source::File(aoc::input::default_path(day_dir(day))),
+        };
+        let input = source
+            .read()
+            .map_err(|err| err.to_string())
+            .and_then(|input| {
+                let path = dir.join("input.txt");
+                fs::write(&path, &input).map_err(|err| Error::Write(path, err).to_string())?;
+                Ok(input)
+            });
+
+        for (part, script) in scripts(day) {
+            let (python, rust) = match &input {
+                Ok(input) => (
+                    run_script(&args.interpreter, day, &script, &dir),
+                    registry::find(day, Some(part)).next().map(|puzzle| {
+                        puzzle
+                            .solve(input)
+                            .map(|answer| answer.to_string())
+                            .map_err(|err| err.to_string())
+                    }),
+                ),
+                Err(err) => (Err(err.clone()), None),
+            };
+            let status = match &input {
+                Ok(_) => compare(&python, rust.as_ref()),
+                Err(err) => Status::Input(err.clone()),
+            };
+
+            count += 1;
+            if status.is_failure() {
+                failed += 1;
+            }
+            let answer = |result: Option<&Result<String, String>>| match result {
+                Some(Ok(answer)) => answer.clone(),
+                _ => "-".to_owned(),
+            };
+            table.push(vec![
+                day.to_string(),
+                part.to_string(),
+                answer(Some(&python)),
+                answer(rust.as_ref()),
+                status.to_string(),
+            ]);
+        }
+    }
+    // Losing the temporary input isn't worth failing over.
+    let _ = fs::remove_dir_all(&dir);
+
+    print!("{table}");
+    println!(
+        "\n{} of {count} parts without disagreements",
+        count - failed
+    );
+
+    Ok(match failed {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, Error> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        parse_args(&args)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse(&["4", "--input", "-", "--python", "python3"]).unwrap();
+        assert_eq!(args.day, Some(4));
+        assert_eq!(args.input, Some(Source::Stdin));
+        assert_eq!(args.interpreter, Interpreter::Command("python3".into()));
+
+        let args = parse(&[]).unwrap();
+        assert_eq!((args.day, args.interpreter), (None, Interpreter::Flake));
+
+        assert!(parse(&["26"]).is_err());
+        assert!(parse(&["4", "1"]).is_err());
+        assert!(parse(&["--input", "in.txt"]).is_err());
+        assert!(parse(&["4", "--python"]).is_err());
+    }
+
+    #[test]
+    fn test_scripts() {
+        let parts = |day| -> Vec<u32> { scripts(day).iter().map(|(part, _)| *part).collect() };
+        assert_eq!(parts(4), [1, 2]);
+        assert_eq!(parts(5), [1, 2]);
+        assert_eq!(parts(1), []);
+    }
+
+    #[test]
+    fn test_parse_output() {
+        assert_eq!(parse_output("Result: 13\n").as_deref(), Some("13"));
+        assert_eq!(parse_output("Result 21\n").as_deref(), Some("21"));
+        assert_eq!(
+            parse_output("Result: 1\ndebug\nResult: 2\n").as_deref(),
+            Some("2")
+        );
+        assert_eq!(parse_output("42\n"), None);
+        assert_eq!(parse_output("Result:\n"), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let ok = |answer: &str| Ok(answer.to_owned());
+        let err = || Err("oops".to_owned());
+
+        assert_eq!(compare(&ok("13"), Some(&ok("13"))), Status::Agree);
+        assert_eq!(compare(&ok("13"), Some(&ok("14"))), Status::Disagree);
+        assert_eq!(compare(&ok("13"), None), Status::PythonOnly);
+        assert_eq!(
+            compare(&err(), Some(&ok("13"))),
+            Status::PythonFailed("oops".into())
+        );
+        assert_eq!(
+            compare(&ok("13"), Some(&err())),
+            Status::RustFailed("oops".into())
+        );
+        assert!(!Status::PythonOnly.is_failure());
+        assert!(Status::Disagree.is_failure());
+    }
+}
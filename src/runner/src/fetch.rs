@@ -0,0 +1,206 @@
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use crate::{
+    client::Client,
+    config::Config,
+    parse_number,
+    registry::{self, YEAR},
+    Error,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+struct Args {
+    year: u32,
+    day: u32,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, Error> {
+    let mut year = YEAR;
+    let mut day = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| Error::Usage(format!("missing value for '{arg}'")))?;
+                year = parse_number(value, "year", 2015..=9999)?;
+            }
+            _ if arg.starts_with('-') || day.is_some() => {
+                return Err(Error::Usage(format!("unexpected argument '{arg}'")))
+            }
+            _ => day = Some(parse_number(arg, "day", 1..=25)?),
+        }
+    }
+
+    let day = day.ok_or_else(|| Error::Usage("missing day".into()))?;
+    Ok(Args { year, day })
+}
+
+/// Where the input of `day` in `year` is kept once downloaded.
+pub fn cache_path(config: &Config, year: u32, day: u32) -> PathBuf {
+    config
+        .cache_dir
+        .join(year.to_string())
+        .join(format!("day{day:02}.txt"))
+}
+
+/// Downloads the input into the cache, unless it is there already. Returns whether it was
+/// downloaded.
+fn fetch_input(config: &Config, year: u32, day: u32) -> Result<bool, Error> {
+    let path = cache_path(config, year, day);
+    if path.exists() {
+        return Ok(false);
+    }
+
+    let input = Client::new(config)?.get(&format!("/{year}/day/{day}/input"))?;
+    let write_error = |err| Error::Write(path.clone(), err);
+    fs::create_dir_all(path.parent().unwrap()).map_err(write_error)?;
+    // Moved into place only once complete, so an interrupted download is never taken for a
+    // cached input.
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, input).map_err(|err| Error::Write(partial.clone(), err))?;
+    fs::rename(&partial, &path).map_err(write_error)?;
+    Ok(true)
+}
+
+pub fn fetch(args: &[String]) -> Result<ExitCode, Error> {
+    let Args { year, day } = parse_args(args)?;
+    let config = Config::load()?;
+    let cached = cache_path(&config, year, day);
+
+    match fetch_input(&config, year, day)? {
+        true => println!("downloaded the input to '{}'", cached.display()),
+        false => println!("using the cached input '{}'", cached.display()),
+    }
+    if year != YEAR {
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let dir = registry::day_dir(day);
+    let target = aoc::input::default_path(&dir);
+    if !dir.exists() {
+        println!("there is no crate for day {day} yet, so the input wasn't copied");
+    } else if target.exists() {
+        let read = |path: &PathBuf| fs::read(path).map_err(|err| Error::Read(path.clone(), err));
+        if read(&target)? != read(&cached)? {
+            println!(
+                "'{}' differs from the download, leaving it alone",
+                target.display()
+            );
+        }
+    } else {
+        fs::copy(&cached, &target).map_err(|err| Error::Write(target.clone(), err))?;
+        println!("copied it to '{}'", target.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env,
+        time::{Duration, Instant},
+    };
+
+    use super::*;
+    use crate::mock::Server;
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+            parse_args(&args)
+        };
+
+        assert_eq!(parse(&["5"]).unwrap(), Args { year: YEAR, day: 5 });
+        assert_eq!(
+            parse(&["--year", "2022", "25"]).unwrap(),
+            Args {
+                year: 2022,
+                day: 25
+            }
+        );
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["26"]).is_err());
+        assert!(parse(&["5", "6"]).is_err());
+        assert!(parse(&["5", "--year"]).is_err());
+    }
+
+    fn test_config(name: &str, server: &Server) -> Config {
+        let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Config {
+            session: Some("53616c74".into()),
+            base_url: server.url.clone(),
+            ..Config::new(&dir.join("config.toml"), dir.clone())
+        }
+    }
+
+    #[test]
+    fn test_fetch() {
+        let server = Server::start(|request| match request.path.as_str() {
+            "/2023/day/5/input" if request.header("Cookie") == Some("session=53616c74") => {
+                (200, "seeds: 79 14 55 13\n".into())
+            }
+            "/2023/day/5/input" => (400, "Puzzle inputs differ by user.".into()),
+            _ => (404, "Not Found".into()),
+        });
+
+        let config = test_config("fetch", &server);
+        assert!(fetch_input(&config, 2023, 5).unwrap());
+        let cached = cache_path(&config, 2023, 5);
+        assert!(cached.ends_with("2023/day05.txt"));
+        assert_eq!(fs::read_to_string(&cached).unwrap(), "seeds: 79 14 55 13\n");
+        let files = fs::read_dir(cached.parent().unwrap()).unwrap().count();
+        assert_eq!(files, 1);
+
+        // Cached inputs are never downloaded again.
+        assert!(!fetch_input(&config, 2023, 5).unwrap());
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert!(requests[0]
+            .header("User-Agent")
+            .unwrap()
+            .contains("github.com"));
+        fs::remove_dir_all(&config.cache_dir).unwrap();
+
+        let config = test_config("fetch-locked", &server);
+        let error = fetch_input(&config, 2023, 6).unwrap_err().to_string();
+        assert!(error.ends_with("was not found, the puzzle may not be unlocked yet"));
+        assert!(!cache_path(&config, 2023, 6).exists());
+        fs::remove_dir_all(&config.cache_dir).unwrap();
+
+        let config = Config {
+            session: Some("expired".into()),
+            ..test_config("fetch-expired", &server)
+        };
+        let error = fetch_input(&config, 2023, 5).unwrap_err().to_string();
+        assert!(error.contains("check that the session token is still valid"));
+        fs::remove_dir_all(&config.cache_dir).unwrap();
+
+        let config = Config {
+            session: None,
+            ..test_config("fetch-no-session", &server)
+        };
+        assert!(fetch_input(&config, 2023, 5).is_err());
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let server = Server::start(|_| (200, "input".into()));
+        let config = test_config("rate-limit", &server);
+        let mut client = Client::new(&config).unwrap();
+        client.min_interval = Duration::from_millis(200);
+
+        let start = Instant::now();
+        client.get("/2023/day/1/input").unwrap();
+        assert!(start.elapsed() < Duration::from_millis(200));
+        client.get("/2023/day/2/input").unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(server.requests().len(), 2);
+        fs::remove_dir_all(&config.cache_dir).unwrap();
+    }
+}
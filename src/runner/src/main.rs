@@ -0,0 +1,114 @@
+mod answers;
+mod bench;
+mod check;
+mod client;
+mod config;
+mod crosscheck;
+mod fetch;
+#[cfg(test)]
+mod mock;
+mod registry;
+mod run;
+mod scaffold;
+mod submit;
+mod table;
+mod verify;
+
+use std::{env, fmt, io, path::PathBuf, process::ExitCode};
+
+use aoc::ParseError;
+
+const USAGE: &str = "\
+usage: aoc <command> [<args>]
+
+commands:
+    run <day> [<part>] [--input <path|->] [--format <text|json>]
+                                             run the solutions of a single day
+    run --all [--format <text|json>]         run every solution on its own input
+    bench <day> [<part>] [--input <path|->] [--runs <n>] [--warmup <n>]
+          [--generate <seed>] [--size <n>] [--baseline <path>] [--save <path>]
+                                             time the solutions of a single day over many runs
+    bench --all [...]                        time every solution on its own input
+    verify [--answers <path>] [--tolerance <factor>]
+                                             check every solution against its recorded answer
+    fetch <day> [--year <year>]              download the input of a day, unless it's cached
+    submit <day> <part> [--input <path|->] [--answers <path>] [--wait]
+                                             submit the answer of a solution and record the verdict
+    check <day> [--input <path|->]           check the assumptions the solutions make about an input
+    new <year> <day>                         create and register the crate of a new day
+    crosscheck [<day>] [--input <path|->] [--python <cmd>]
+                                             compare the answers of the Python and Rust solutions";
+
+#[derive(Debug)]
+pub enum Error {
+    Usage(String),
+    Input(aoc::input::Error),
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+    Parse(PathBuf, ParseError),
+    Invalid(PathBuf, String),
+    Http(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Usage(msg) => write!(f, "{msg}"),
+            Error::Input(err) => write!(f, "{err}"),
+            Error::Read(path, err) => write!(f, "failed to read '{}': {err}", path.display()),
+            Error::Write(path, err) => write!(f, "failed to write '{}': {err}", path.display()),
+            Error::Parse(path, err) => write!(f, "{}: {err}", path.display()),
+            Error::Invalid(path, msg) => write!(f, "{}: {msg}", path.display()),
+            Error::Http(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl From<aoc::input::Error> for Error {
+    fn from(err: aoc::input::Error) -> Self {
+        Error::Input(err)
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run::run(&args[1..]),
+        Some("bench") => bench::run(&args[1..]),
+        Some("verify") => verify::verify(&args[1..]),
+        Some("fetch") => fetch::fetch(&args[1..]),
+        Some("submit") => submit::submit(&args[1..]),
+        Some("check") => check::check(&args[1..]),
+        Some("new") => scaffold::scaffold(&args[1..]),
+        Some("crosscheck") => crosscheck::crosscheck(&args[1..]),
+        Some("help" | "-h" | "--help") => {
+            println!("{USAGE}");
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(command) => Err(Error::Usage(format!("unknown command '{command}'"))),
+        None => Err(Error::Usage("missing command".to_owned())),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            if let Error::Usage(_) = err {
+                eprintln!("\n{USAGE}");
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses a day or part number, checking that it lies in `range`.
+pub fn parse_number(
+    arg: &str,
+    what: &str,
+    range: std::ops::RangeInclusive<u32>,
+) -> Result<u32, Error> {
+    arg.parse()
+        .ok()
+        .filter(|num| range.contains(num))
+        .ok_or_else(|| Error::Usage(format!("invalid {what} '{arg}'")))
+}
@@ -0,0 +1,76 @@
+//! The list of every solution the runner knows how to call.
+
+use std::path::{Path, PathBuf};
+
+use aoc::{Answer, ParseError, Solution};
+
+/// The year of the puzzles in this repository.
+pub const YEAR: u32 = 2023;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day25::Day25,
+];
+
+/// A single part of the puzzle of one day.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub solution: &'static dyn Solution,
+    pub part: u32,
+}
+
+impl Puzzle {
+    pub fn day(&self) -> u32 {
+        self.solution.day()
+    }
+
+    pub fn solve(&self, input: &str) -> Result<Answer, ParseError> {
+        self.solution.solve(self.part, input)
+    }
+
+    /// The `input.txt` in the root of the day's crate.
+    pub fn default_input(&self) -> PathBuf {
+        aoc::input::default_path(day_dir(self.day()))
+    }
+}
+
+/// The directory of the crate of `day`, which may not exist yet.
+pub fn day_dir(day: u32) -> PathBuf {
+    let crates_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    crates_dir.join(format!("day{day:02}"))
+}
+
+/// Every part that has been solved, ordered by day and part.
+pub fn puzzles() -> impl Iterator<Item = Puzzle> {
+    SOLUTIONS.iter().flat_map(|&solution| {
+        (1..=2)
+            .filter(move |&part| solution.has_part(part))
+            .map(move |part| Puzzle { solution, part })
+    })
+}
+
+pub fn find(day: u32, part: Option<u32>) -> impl Iterator<Item = Puzzle> {
+    puzzles()
+        .filter(move |puzzle| puzzle.day() == day && part.is_none_or(|part| puzzle.part == part))
+}
@@ -0,0 +1,232 @@
+use std::{
+    collections::HashMap,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    input::Source,
+    json::Value,
+    report::{self, Format},
+};
+
+use crate::{
+    parse_number,
+    registry::{self, Puzzle},
+    table::{format_duration, Table},
+    Error,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Selection {
+    All,
+    Day(u32, Option<u32>),
+}
+
+#[derive(PartialEq, Eq, Debug)]
+struct Args {
+    selection: Selection,
+    input: Option<Source>,
+    format: Format,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, Error> {
+    let mut all = false;
+    let mut numbers = Vec::new();
+    let mut input = None;
+    let mut format = Format::Text;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::Usage(format!("missing value for '{arg}'")))
+        };
+        match arg.as_str() {
+            "--all" => all = true,
+            "-i" | "--input" => input = Some(Source::from_arg(value()?)),
+            "--format" => {
+                let value = value()?;
+                format = Format::from_arg(value)
+                    .ok_or_else(|| Error::Usage(format!("invalid format '{value}'")))?;
+            }
+            _ if arg.starts_with('-') => {
+                return Err(Error::Usage(format!("unexpected argument '{arg}'")))
+            }
+            _ => numbers.push(arg),
+        }
+    }
+
+    if all && input.is_some() {
+        return Err(Error::Usage("'--input' can't be used with '--all'".into()));
+    }
+    let selection = parse_selection(all, &numbers)?;
+    Ok(Args {
+        selection,
+        input,
+        format,
+    })
+}
+
+/// Interprets the `<day> [<part>]` or `--all` arguments.
+pub fn parse_selection(all: bool, numbers: &[&String]) -> Result<Selection, Error> {
+    Ok(match (all, numbers) {
+        (true, []) => Selection::All,
+        (false, [day]) => Selection::Day(parse_number(day, "day", 1..=25)?, None),
+        (false, [day, part]) => Selection::Day(
+            parse_number(day, "day", 1..=25)?,
+            Some(parse_number(part, "part", 1..=2)?),
+        ),
+        _ => {
+            return Err(Error::Usage(
+                "expected a day and an optional part, or '--all'".into(),
+            ))
+        }
+    })
+}
+
+/// The selected puzzles, or an error if a single day was selected that hasn't been solved.
+pub fn select(selection: &Selection) -> Result<Vec<Puzzle>, Error> {
+    let puzzles: Vec<Puzzle> = match *selection {
+        Selection::All => registry::puzzles().collect(),
+        Selection::Day(day, part) => registry::find(day, part).collect(),
+    };
+
+    if let (Selection::Day(day, part), true) = (selection, puzzles.is_empty()) {
+        let part = part.map_or(String::new(), |part| format!(" part {part}"));
+        return Err(Error::Usage(format!(
+            "there is no solution for day {day}{part}"
+        )));
+    }
+    Ok(puzzles)
+}
+
+pub fn run(args: &[String]) -> Result<ExitCode, Error> {
+    let args = parse_args(args)?;
+    let puzzles = select(&args.selection)?;
+
+    let mut inputs: HashMap<Source, Result<String, String>> = HashMap::new();
+    let mut table = Table::new(&["day", "part", "answer", "time"]);
+    let mut total = Duration::ZERO;
+    let mut json = Vec::new();
+    let mut failed = false;
+
+    for puzzle in puzzles.iter() {
+        let source = match &args.input {
+            Some(source) => source.clone(),
+            None => Source::File(puzzle.default_input()),
+        };
+
+        let input = inputs
+            .entry(source.clone())
+            .or_insert_with(|| source.read().map_err(|err| err.to_string()));
+
+        let (answer, time) = match input {
+            Ok(input) => {
+                let start = Instant::now();
+                let answer = puzzle.solve(input);
+                let elapsed = start.elapsed();
+
+                total += elapsed;
+                match answer {
+                    Ok(answer) => {
+                        let run = report::Run {
+                            day: puzzle.day(),
+                            part: puzzle.part,
+                            answer: &answer,
+                            elapsed,
+                            input,
+                        };
+                        json.push(run.to_json());
+                        (answer.to_string(), format_duration(elapsed))
+                    }
+                    Err(err) => {
+                        failed = true;
+                        json.push(error_json(puzzle, &err.to_string()));
+                        (format!("error: {err}"), format_duration(elapsed))
+                    }
+                }
+            }
+            Err(err) => {
+                failed = true;
+                json.push(error_json(puzzle, err));
+                (format!("error: {err}"), "-".to_owned())
+            }
+        };
+        table.push(vec![
+            puzzle.day().to_string(),
+            puzzle.part.to_string(),
+            answer,
+            time,
+        ]);
+    }
+
+    if args.format == Format::Json {
+        json.iter().for_each(|run| println!("{run}"));
+        return Ok(exit_code(failed));
+    }
+    print!("{table}");
+    if puzzles.len() > 1 {
+        println!("\ntotal time: {}", format_duration(total));
+    }
+
+    Ok(exit_code(failed))
+}
+
+fn exit_code(failed: bool) -> ExitCode {
+    match failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+/// The line of `--format json` output of a puzzle that failed.
+fn error_json(puzzle: &Puzzle, err: &str) -> Value {
+    Value::object([
+        ("day", Value::from(puzzle.day() as u64)),
+        ("part", Value::from(puzzle.part as u64)),
+        ("error", Value::from(err)),
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, Error> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        parse_args(&args)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse(&["17", "2", "--input", "-"]).unwrap();
+        assert_eq!(args.selection, Selection::Day(17, Some(2)));
+        assert_eq!(args.input, Some(Source::Stdin));
+
+        assert_eq!(parse(&["3"]).unwrap().selection, Selection::Day(3, None));
+        assert_eq!(parse(&["--all"]).unwrap().selection, Selection::All);
+
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["26"]).is_err());
+        assert!(parse(&["1", "3"]).is_err());
+        assert!(parse(&["--all", "1"]).is_err());
+        assert!(parse(&["--all", "--input", "in.txt"]).is_err());
+
+        assert_eq!(parse(&["--all"]).unwrap().format, Format::Text);
+        let args = parse(&["--all", "--format", "json"]).unwrap();
+        assert_eq!(args.format, Format::Json);
+        assert!(parse(&["--all", "--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn test_registry() {
+        for puzzle in registry::puzzles() {
+            let path = puzzle.default_input();
+            assert!(path.ends_with(format!("day{:02}/input.txt", puzzle.day())));
+        }
+        assert_eq!(registry::find(21, None).count(), 2);
+        assert_eq!(registry::find(5, None).count(), 1);
+        assert_eq!(registry::find(4, None).count(), 0);
+    }
+}
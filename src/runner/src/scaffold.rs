@@ -0,0 +1,241 @@
+//! `aoc new`, which creates the crate of a new day from the files in `template/`.
+//!
+//! The day goes to `src/dayNN` and is registered with the runner and `answers.toml`. Those only
+//! know the puzzles of [`YEAR`], so a day of any other year is refused.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use crate::{answers, parse_number, registry::YEAR, Error};
+
+const CARGO_TOML: &str = include_str!("../template/Cargo.toml.tmpl");
+const LIB: &str = include_str!("../template/lib.rs.tmpl");
+const PART: &str = include_str!("../template/part.rs.tmpl");
+const MAIN: &str = include_str!("../template/main.rs.tmpl");
+const GEN: &str = include_str!("../template/gen.rs.tmpl");
+
+#[derive(PartialEq, Eq, Debug)]
+struct Args {
+    day: u32,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, Error> {
+    match args {
+        [year, day] => {
+            let year = parse_number(year, "year", 2015..=9999)?;
+            if year != YEAR {
+                return Err(Error::Usage(format!(
+                    "can't add a day of {year}, the runner and answers.toml only know the puzzles \
+                     of {YEAR}"
+                )));
+            }
+            Ok(Args {
+                day: parse_number(day, "day", 1..=25)?,
+            })
+        }
+        [_] | [] => Err(Error::Usage("expected a year and a day".into())),
+        [_, _, arg, ..] => Err(Error::Usage(format!("unexpected argument '{arg}'"))),
+    }
+}
+
+/// The crate of the puzzle of `day`.
+struct NewDay {
+    day: u32,
+}
+
+impl NewDay {
+    /// The name of the package, whose binaries are called `<name>-part1` and `<name>-part2`.
+    fn name(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    /// The directory of the crate, relative to the root of the repository.
+    fn dir(&self) -> PathBuf {
+        PathBuf::from(format!("src/day{:02}", self.day))
+    }
+
+    /// `template` with the placeholders filled in, where `{{part}}` becomes `part`.
+    fn fill(&self, template: &str, part: u32) -> String {
+        template
+            .replace("{{crate}}", &self.name())
+            .replace("{{lib}}", &self.name())
+            .replace("{{Day}}", &format!("Day{:02}", self.day))
+            .replace("{{day}}", &self.day.to_string())
+            .replace("{{part}}", &part.to_string())
+    }
+
+    /// Every file of the crate, relative to its directory. The example fixture starts out empty.
+    fn files(&self) -> Vec<(PathBuf, String)> {
+        let mut files = vec![
+            ("Cargo.toml".into(), self.fill(CARGO_TOML, 0)),
+            ("src/lib.rs".into(), self.fill(LIB, 0)),
+            ("src/gen.rs".into(), self.fill(GEN, 0)),
+            ("fixtures/example/input.txt".into(), String::new()),
+        ];
+        for part in 1..=2 {
+            files.extend([
+                (format!("src/part{part}.rs").into(), self.fill(PART, part)),
+                (
+                    format!("src/bin/part{part}.rs").into(),
+                    self.fill(MAIN, part),
+                ),
+                (
+                    format!("fixtures/example/part{part}.txt").into(),
+                    String::new(),
+                ),
+            ]);
+        }
+        files
+    }
+}
+
+/// `text` with `new_line` added among the lines for which `is_entry` holds, keeping them sorted.
+/// Returns `None` if there are no such lines to add it to.
+fn insert_sorted(text: &str, new_line: &str, is_entry: impl Fn(&str) -> bool) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&new_line) {
+        return Some(text.to_owned());
+    }
+
+    let entries: Vec<usize> = (0..lines.len()).filter(|&i| is_entry(lines[i])).collect();
+    let first = *entries.first()?;
+    let index = entries
+        .iter()
+        .rfind(|&&i| lines[i] < new_line)
+        .map_or(first, |&i| i + 1);
+    lines.insert(index, new_line);
+    Some(lines.join("\n") + "\n")
+}
+
+fn edit(path: &Path, edit: impl FnOnce(&str) -> Option<String>) -> Result<(), Error> {
+    let text = fs::read_to_string(path).map_err(|err| Error::Read(path.into(), err))?;
+    let text = edit(&text)
+        .ok_or_else(|| Error::Invalid(path.into(), "can't find where to add the new day".into()))?;
+    fs::write(path, text).map_err(|err| Error::Write(path.into(), err))
+}
+
+/// Creates the crate of `new_day` inside the repository at `root` and registers it.
+fn create(root: &Path, new_day: &NewDay) -> Result<(), Error> {
+    let dir = root.join(new_day.dir());
+    if dir.exists() {
+        return Err(Error::Invalid(dir, "already exists".into()));
+    }
+    for (file, contents) in new_day.files() {
+        let path = dir.join(file);
+        let write_error = |err| Error::Write(path.clone(), err);
+        fs::create_dir_all(path.parent().unwrap()).map_err(write_error)?;
+        fs::write(&path, contents).map_err(write_error)?;
+    }
+
+    let member = format!("    \"{}\",", new_day.dir().display());
+    edit(&root.join("Cargo.toml"), |text| {
+        insert_sorted(text, &member, |line| line.starts_with("    \"src/"))
+    })?;
+
+    let (name, day) = (new_day.name(), new_day.day);
+    let dependency = format!("{name} = {{ path = \"../{name}\" }}");
+    edit(&root.join("src/runner/Cargo.toml"), |text| {
+        insert_sorted(text, &dependency, |line| {
+            line.starts_with("day") && line.contains("path")
+        })
+    })?;
+    let solution = format!("    &{name}::Day{day:02},");
+    edit(&root.join("src/runner/src/registry.rs"), |text| {
+        insert_sorted(text, &solution, |line| line.starts_with("    &day"))
+    })?;
+    edit(&root.join("answers.toml"), |text| {
+        let text = answers::add_table(text, (day, 1));
+        Some(answers::add_table(&text, (day, 2)))
+    })
+}
+
+pub fn scaffold(args: &[String]) -> Result<ExitCode, Error> {
+    let Args { day } = parse_args(args)?;
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let new_day = NewDay { day };
+    create(&root, &new_day)?;
+
+    println!("created '{}'", new_day.dir().display());
+    println!("registered it with the runner and added its tables to answers.toml");
+    println!(
+        "the example goes in 'fixtures/example', the input can be downloaded with 'aoc fetch'"
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+            parse_args(&args)
+        };
+
+        assert_eq!(parse(&["2023", "4"]).unwrap(), Args { day: 4 });
+        assert!(parse(&["2023"]).is_err());
+        assert!(parse(&["2023", "26"]).is_err());
+        assert!(parse(&["1", "2023"]).is_err());
+        assert!(parse(&["2023", "4", "2"]).is_err());
+        assert!(matches!(parse(&["2024", "1"]), Err(Error::Usage(msg)) if msg.contains("2024")));
+    }
+
+    #[test]
+    fn test_create() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for file in [
+            "Cargo.toml",
+            "answers.toml",
+            "src/runner/Cargo.toml",
+            "src/runner/src/registry.rs",
+        ] {
+            fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            fs::copy(repo.join(file), root.join(file)).unwrap();
+        }
+        let read = |file: &str| fs::read_to_string(root.join(file)).unwrap();
+
+        create(&root, &NewDay { day: 4 }).unwrap();
+        let lib = read("src/day04/src/lib.rs");
+        assert!(lib.contains("pub struct Day04;") && lib.contains("        4\n"));
+        assert!(read("src/day04/src/bin/part2.rs")
+            .contains("aoc::report::print(&args, &Day04, 2, &input);"));
+        let part1 = read("src/day04/src/part1.rs");
+        assert!(part1.contains("part1: #[ignore = ") && !part1.contains("todo!"));
+        let gen = read("src/day04/src/gen.rs");
+        assert!(gen.contains("pub fn input(seed: u64, size: usize)") && !gen.contains("todo!"));
+        assert_eq!(read("src/day04/fixtures/example/input.txt"), "");
+        assert!(read("Cargo.toml").contains("\"src/day03\",\n    \"src/day04\",\n"));
+        assert!(read("src/runner/Cargo.toml").contains("day04 = { path = \"../day04\" }\nday05"));
+        assert!(read("src/runner/src/registry.rs").contains("Day03,\n    &day04::Day04,\n"));
+        let answers = read("answers.toml");
+        assert!(answers.contains("\n[day04.part1]\n\n[day04.part2]\n\n[day05.part2]\n"));
+        assert!(answers::Answers::parse(&answers).is_ok());
+        assert!(create(&root, &NewDay { day: 4 }).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let is_entry = |line: &str| line.starts_with("  ");
+        let text = "list:\n  a\n  c\nend\n";
+        assert_eq!(
+            insert_sorted(text, "  b", is_entry).unwrap(),
+            "list:\n  a\n  b\n  c\nend\n"
+        );
+        assert_eq!(
+            insert_sorted(text, "  0", is_entry).unwrap(),
+            "list:\n  0\n  a\n  c\nend\n"
+        );
+        assert_eq!(insert_sorted(text, "  c", is_entry).unwrap(), text);
+        assert_eq!(insert_sorted("list:\n", "  a", is_entry), None);
+    }
+}
@@ -0,0 +1,443 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::{input::Source, Answer};
+
+use crate::{
+    answers::{self, format_answer, format_time, Answers, Entry},
+    client::Client,
+    config::Config,
+    parse_number,
+    registry::YEAR,
+    run::{self, Selection},
+    verify, Error,
+};
+
+#[derive(PartialEq, Debug)]
+struct Args {
+    day: u32,
+    part: u32,
+    input: Option<Source>,
+    answers: PathBuf,
+    /// Whether to wait out a rate limit and try again, instead of giving up.
+    wait: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, Error> {
+    let mut numbers = Vec::new();
+    let mut input = None;
+    let mut answers = answers::default_path();
+    let mut wait = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::Usage(format!("missing value for '{arg}'")))
+        };
+        match arg.as_str() {
+            "-i" | "--input" => input = Some(Source::from_arg(value()?)),
+            "--answers" => answers = value()?.into(),
+            "--wait" => wait = true,
+            _ if arg.starts_with('-') => {
+                return Err(Error::Usage(format!("unexpected argument '{arg}'")))
+            }
+            _ => numbers.push(arg),
+        }
+    }
+
+    let [day, part] = numbers[..] else {
+        return Err(Error::Usage("expected a day and a part".into()));
+    };
+    Ok(Args {
+        day: parse_number(day, "day", 1..=25)?,
+        part: parse_number(part, "part", 1..=2)?,
+        input,
+        answers,
+        wait,
+    })
+}
+
+/// The verdict on a submitted answer.
+#[derive(PartialEq, Eq, Debug)]
+enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The last answer was submitted too recently, and the next one has to wait this long.
+    RateLimited(Duration),
+    AlreadySolved,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "that's the right answer"),
+            Outcome::TooHigh => write!(f, "that's not the right answer, it's too high"),
+            Outcome::TooLow => write!(f, "that's not the right answer, it's too low"),
+            Outcome::Wrong => write!(f, "that's not the right answer"),
+            Outcome::RateLimited(wait) => write!(
+                f,
+                "an answer was submitted too recently, the next one has to wait {}s",
+                wait.as_secs()
+            ),
+            Outcome::AlreadySolved => write!(f, "this part has already been solved"),
+        }
+    }
+}
+
+/// Reads the verdict from the page the site answers a submission with.
+fn parse_outcome(page: &str) -> Option<Outcome> {
+    // The verdict is the only `<article>` on the page.
+    let start = page.find("<article")?;
+    let end = page[start..]
+        .find("</article>")
+        .map_or(page.len(), |end| start + end);
+    let text = strip_tags(&page[start..end]);
+
+    if text.contains("That's the right answer") {
+        Some(Outcome::Correct)
+    } else if text.contains("not the right answer") {
+        Some(match () {
+            _ if text.contains("too high") => Outcome::TooHigh,
+            _ if text.contains("too low") => Outcome::TooLow,
+            _ => Outcome::Wrong,
+        })
+    } else if text.contains("gave an answer too recently") {
+        parse_wait(&text).map(Outcome::RateLimited)
+    } else if text.contains("solving the right level") {
+        Some(Outcome::AlreadySolved)
+    } else {
+        None
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut in_tag = false;
+    html.chars()
+        .filter(|&ch| {
+            let keep = !in_tag && ch != '<';
+            in_tag = (in_tag || ch == '<') && ch != '>';
+            keep
+        })
+        .collect()
+}
+
+/// The wait in "You have 1m 5s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let secs = text[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (num, unit) = part.split_at_checked(part.len() - 1)?;
+            let unit = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            Some(num.parse::<u64>().ok()? * unit)
+        })
+        .sum::<Option<u64>>()?;
+    Some(Duration::from_secs(secs))
+}
+
+fn post_answer(
+    client: &Client,
+    (day, part): (u32, u32),
+    answer: &Answer,
+) -> Result<Outcome, Error> {
+    let path = format!("/{YEAR}/day/{day}/answer");
+    let form = [("level", part.to_string()), ("answer", answer.to_string())];
+    let form = form.each_ref().map(|(key, value)| (*key, value.as_str()));
+    let page = client.post(&path, &form)?;
+
+    parse_outcome(&page).ok_or_else(|| {
+        let text = strip_tags(&page);
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let text: String = text.chars().take(200).collect();
+        Error::Http(format!("unexpected response to the answer: '{text}'"))
+    })
+}
+
+/// Records what `outcome` tells about the puzzle in the answers file at `path`.
+fn record(
+    path: &Path,
+    puzzle: (u32, u32),
+    entry: &Entry,
+    answer: &Answer,
+    outcome: &Outcome,
+    elapsed: Duration,
+) -> Result<(), Error> {
+    let mut values = Vec::new();
+    match outcome {
+        Outcome::Correct => {
+            values.push(("answer", format_answer(answer)));
+            if verify::check_times() {
+                values.push(("time_ms", format_time(elapsed)));
+            }
+        }
+        Outcome::TooLow if entry.too_low.as_ref().is_none_or(|low| answer > low) => {
+            values.push(("too_low", format_answer(answer)))
+        }
+        Outcome::TooHigh if entry.too_high.as_ref().is_none_or(|high| answer < high) => {
+            values.push(("too_high", format_answer(answer)))
+        }
+        Outcome::Wrong => {
+            let wrong: Vec<String> = entry
+                .wrong
+                .iter()
+                .chain([answer])
+                .map(format_answer)
+                .collect();
+            values.push(("wrong", format!("[{}]", wrong.join(", "))));
+        }
+        _ => return Ok(()),
+    }
+
+    let mut text = match path.exists() {
+        true => fs::read_to_string(path).map_err(|err| Error::Read(path.into(), err))?,
+        false => String::new(),
+    };
+    for (key, value) in values {
+        text = answers::set_value(&text, puzzle, key, &value);
+    }
+    fs::write(path, text).map_err(|err| Error::Write(path.into(), err))
+}
+
+/// What came of an answer, which is only sent to the site if the answers file can't tell.
+#[derive(PartialEq, Eq, Debug)]
+enum Submission {
+    /// The answer has already been accepted, so it wasn't submitted again.
+    Accepted,
+    /// The answer wasn't submitted, as the answers file already rules it out for this reason.
+    Refused(String),
+    /// The site's verdict on the answer, which has been recorded.
+    Submitted(Outcome),
+}
+
+/// Submits `answer` to `puzzle` unless the answers file at `path` already tells whether it's
+/// right, and records the verdict there. With `wait`, a rate limit is waited out once.
+fn submit_answer(
+    client: &Client,
+    path: &Path,
+    puzzle: (u32, u32),
+    answer: &Answer,
+    elapsed: Duration,
+    wait: bool,
+) -> Result<Submission, Error> {
+    let answers = match path.exists() {
+        true => Answers::load(path)?,
+        false => Answers::default(),
+    };
+    let entry = answers.get(puzzle.0, puzzle.1).cloned().unwrap_or_default();
+    match &entry.answer {
+        Some(accepted) if accepted == answer => return Ok(Submission::Accepted),
+        Some(accepted) => {
+            let reason = format!("{accepted} has already been accepted");
+            return Ok(Submission::Refused(reason));
+        }
+        None => {}
+    }
+    if let Some(reason) = entry.rejects(answer) {
+        return Ok(Submission::Refused(reason));
+    }
+
+    let mut outcome = post_answer(client, puzzle, answer)?;
+    if let (Outcome::RateLimited(duration), true) = (&outcome, wait) {
+        println!("{outcome}");
+        thread::sleep(*duration);
+        outcome = post_answer(client, puzzle, answer)?;
+    }
+    record(path, puzzle, &entry, answer, &outcome, elapsed)?;
+    Ok(Submission::Submitted(outcome))
+}
+
+pub fn submit(args: &[String]) -> Result<ExitCode, Error> {
+    let args = parse_args(args)?;
+    let puzzle = run::select(&Selection::Day(args.day, Some(args.part)))?[0];
+    let source = match args.input {
+        Some(source) => source,
+        None => Source::File(puzzle.default_input()),
+    };
+
+    let input = source.read()?;
+    let start = Instant::now();
+    let answer = puzzle
+        .solve(&input)
+        .map_err(|err| Error::Parse(source.to_string().into(), err))?;
+    let elapsed = start.elapsed();
+    println!("day {} part {}: {answer}", args.day, args.part);
+
+    let client = Client::new(&Config::load()?)?;
+    let key = (args.day, args.part);
+    match submit_answer(&client, &args.answers, key, &answer, elapsed, args.wait)? {
+        Submission::Accepted => {
+            println!("{answer} has already been accepted");
+            Ok(ExitCode::SUCCESS)
+        }
+        Submission::Refused(reason) => {
+            println!("not submitting, {reason}");
+            Ok(ExitCode::FAILURE)
+        }
+        Submission::Submitted(outcome) => {
+            println!("{outcome}");
+            Ok(match outcome {
+                Outcome::Correct | Outcome::AlreadySolved => ExitCode::SUCCESS,
+                _ => ExitCode::FAILURE,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+    use crate::mock::Server;
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+            parse_args(&args)
+        };
+
+        let args = parse(&["17", "2", "--wait", "--answers", "a.toml"]).unwrap();
+        assert_eq!((args.day, args.part), (17, 2));
+        assert_eq!(args.answers, PathBuf::from("a.toml"));
+        assert!(args.wait);
+        assert_eq!(parse(&["1", "1"]).unwrap().input, None);
+
+        assert!(parse(&["17"]).is_err());
+        assert!(parse(&["17", "3"]).is_err());
+        assert!(parse(&["17", "1", "2"]).is_err());
+    }
+
+    fn page(verdict: &str) -> String {
+        format!("<html><body><main>\n<article><p>{verdict}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let outcome = |verdict| parse_outcome(&page(verdict));
+        assert_eq!(
+            outcome("That's the right answer!  You are <em>one gold star</em> closer."),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too high.  Please wait."),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too low."),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            outcome("That's not the right answer.  If you're stuck, make sure you're using the full input data."),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            outcome("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+            Some(Outcome::RateLimited(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            outcome("You gave an answer too recently.  You have 37s left to wait."),
+            Some(Outcome::RateLimited(Duration::from_secs(37)))
+        );
+        assert_eq!(
+            outcome("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(Outcome::AlreadySolved)
+        );
+        assert_eq!(outcome("Something else entirely."), None);
+        assert_eq!(parse_outcome("<html>no article</html>"), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        // The site rate limits the first two submissions of 40.
+        let limited = AtomicUsize::new(0);
+        let server = Server::start(move |request| {
+            let verdict = match request.body.as_str() {
+                "level=1&answer=100" => "That's not the right answer; your answer is too high.",
+                "level=1&answer=10" => "That's not the right answer; your answer is too low.",
+                "level=1&answer=50" => "That's the right answer!",
+                "level=1&answer=40" if limited.fetch_add(1, Ordering::Relaxed) < 2 => {
+                    "You gave an answer too recently.  You have 0s left to wait."
+                }
+                _ => "That's not the right answer.",
+            };
+            (200, page(verdict))
+        });
+
+        let dir = env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let config = Config {
+            session: Some("53616c74".into()),
+            base_url: server.url.clone(),
+            ..Config::new(&dir.join("config.toml"), dir.clone())
+        };
+        let mut client = Client::new(&config).unwrap();
+        client.min_interval = Duration::ZERO;
+        let path = dir.join("answers.toml");
+
+        let submit = |answer: i64, wait: bool| {
+            let answer = Answer::from(answer);
+            submit_answer(&client, &path, (3, 1), &answer, Duration::ZERO, wait).unwrap()
+        };
+        let refused = |reason: &str| Submission::Refused(reason.to_owned());
+
+        assert_eq!(submit(100, false), Submission::Submitted(Outcome::TooHigh));
+        assert_eq!(submit(10, false), Submission::Submitted(Outcome::TooLow));
+        assert_eq!(submit(20, false), Submission::Submitted(Outcome::Wrong));
+        assert_eq!(submit(30, false), Submission::Submitted(Outcome::Wrong));
+        assert_eq!(
+            submit(120, false),
+            refused("120 is not below 100, which is too high")
+        );
+        assert_eq!(
+            submit(5, false),
+            refused("5 is not above 10, which is too low")
+        );
+        assert_eq!(submit(20, false), refused("20 was already rejected"));
+
+        // Without `--wait` a rate limit is only reported, with it the answer is sent again.
+        assert_eq!(
+            submit(40, false),
+            Submission::Submitted(Outcome::RateLimited(Duration::ZERO))
+        );
+        assert_eq!(submit(40, true), Submission::Submitted(Outcome::Wrong));
+
+        assert_eq!(submit(50, false), Submission::Submitted(Outcome::Correct));
+        assert_eq!(submit(50, false), Submission::Accepted);
+        assert_eq!(submit(60, false), refused("50 has already been accepted"));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 8);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/3/answer");
+        assert_eq!(requests[0].header("Cookie"), Some("session=53616c74"));
+
+        let text = fs::read_to_string(&path).unwrap();
+        let entry = Answers::parse(&text).unwrap().get(3, 1).cloned().unwrap();
+        assert_eq!(entry.answer, Some(Answer::from(50)));
+        assert_eq!(entry.too_high, Some(Answer::from(100)));
+        assert_eq!(entry.too_low, Some(Answer::from(10)));
+        assert_eq!(
+            entry.wrong,
+            [Answer::from(20), Answer::from(30), Answer::from(40)]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
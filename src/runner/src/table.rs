@@ -0,0 +1,72 @@
+use std::{fmt, time::Duration};
+
+/// A plain text table with right aligned columns.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(|header| header.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut widths: Vec<usize> = self.headers.iter().map(|header| header.len()).collect();
+        for row in self.rows.iter() {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in [&self.headers].into_iter().chain(self.rows.iter()) {
+            let cells: Vec<_> = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{cell:>width$}"))
+                .collect();
+            writeln!(f, "{}", cells.join("  ").trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs < 1e-3 {
+        format!("{:.1}µs", secs * 1e6)
+    } else if secs < 1.0 {
+        format!("{:.1}ms", secs * 1e3)
+    } else {
+        format!("{secs:.2}s")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let mut table = Table::new(&["day", "answer"]);
+        table.push(vec!["1".to_owned(), "54388".to_owned()]);
+        table.push(vec!["17".to_owned(), "825".to_owned()]);
+        assert_eq!(table.to_string(), "day  answer\n  1   54388\n 17     825\n");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.5µs");
+        assert_eq!(format_duration(Duration::from_micros(2_300)), "2.3ms");
+        assert_eq!(format_duration(Duration::from_millis(3_500)), "3.50s");
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    fmt,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc::{input::Source, Answer};
+
+use crate::{
+    answers::{self, Answers, Entry},
+    registry,
+    table::{format_duration, Table},
+    Error,
+};
+
+/// Below this a solution is never reported as slower, since such short times are mostly noise.
+const MIN_REGRESSION: Duration = Duration::from_millis(1);
+
+#[derive(PartialEq, Debug)]
+struct Args {
+    answers: PathBuf,
+    /// How many times slower than recorded a solution may get before it is reported.
+    tolerance: f64,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, Error> {
+    let mut parsed = Args {
+        answers: answers::default_path(),
+        tolerance: 2.0,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::Usage(format!("missing value for '{arg}'")))
+        };
+        match arg.as_str() {
+            "--answers" => parsed.answers = value()?.into(),
+            "--tolerance" => {
+                let value = value()?;
+                parsed.tolerance = value
+                    .parse()
+                    .ok()
+                    .filter(|&tolerance| tolerance >= 1.0)
+                    .ok_or_else(|| Error::Usage(format!("invalid tolerance '{value}'")))?;
+            }
+            _ => return Err(Error::Usage(format!("unexpected argument '{arg}'"))),
+        }
+    }
+    Ok(parsed)
+}
+
+/// The outcome of checking one part against its recorded answer.
+#[derive(PartialEq, Debug)]
+enum Status {
+    Ok,
+    Missing,
+    Mismatch(Answer),
+    Slower(Duration),
+    Error(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Missing => write!(f, "no recorded answer"),
+            Status::Mismatch(expected) => write!(f, "expected {expected}"),
+            Status::Slower(time) => write!(f, "slower, recorded {}", format_duration(*time)),
+            Status::Error(err) => write!(f, "error: {err}"),
+        }
+    }
+}
+
+fn check(entry: Option<&Entry>, answer: &Answer, elapsed: Duration, tolerance: f64) -> Status {
+    let Some(Entry {
+        answer: Some(expected),
+        time,
+        ..
+    }) = entry
+    else {
+        return Status::Missing;
+    };
+
+    if answer != expected {
+        return Status::Mismatch(expected.clone());
+    }
+    match time {
+        Some(time)
+            if check_times()
+                && elapsed > time.mul_f64(tolerance)
+                && elapsed - *time > MIN_REGRESSION =>
+        {
+            Status::Slower(*time)
+        }
+        _ => Status::Ok,
+    }
+}
+
+/// The recorded times come from release builds, so comparing against a debug build is pointless.
+pub fn check_times() -> bool {
+    !cfg!(debug_assertions)
+}
+
+pub fn verify(args: &[String]) -> Result<ExitCode, Error> {
+    let args = parse_args(args)?;
+    let answers = Answers::load(&args.answers)?;
+
+    let mut table = Table::new(&["day", "part", "answer", "time", "status"]);
+    let mut failed = 0;
+    let mut count = 0;
+
+    for puzzle in registry::puzzles() {
+        let (answer, time, status) = match Source::File(puzzle.default_input()).read() {
+            Ok(input) => {
+                let start = Instant::now();
+                let answer = puzzle.solve(&input);
+                let elapsed = start.elapsed();
+
+                match answer {
+                    Ok(answer) => {
+                        let entry = answers.get(puzzle.day(), puzzle.part);
+                        let status = check(entry, &answer, elapsed, args.tolerance);
+                        (answer.to_string(), format_duration(elapsed), status)
+                    }
+                    Err(err) => (
+                        "-".to_owned(),
+                        format_duration(elapsed),
+                        Status::Error(err.to_string()),
+                    ),
+                }
+            }
+            Err(err) => (
+                "-".to_owned(),
+                "-".to_owned(),
+                Status::Error(err.to_string()),
+            ),
+        };
+
+        count += 1;
+        if status != Status::Ok {
+            failed += 1;
+        }
+        table.push(vec![
+            puzzle.day().to_string(),
+            puzzle.part.to_string(),
+            answer,
+            time,
+            status.to_string(),
+        ]);
+    }
+
+    print!("{table}");
+    println!("\n{} of {count} parts verified", count - failed);
+    if !check_times() {
+        println!("note: run times are only compared in release builds");
+    }
+
+    Ok(match failed {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+            parse_args(&args)
+        };
+
+        let args = parse(&["--answers", "a.toml", "--tolerance", "1.5"]).unwrap();
+        assert_eq!(args.answers, PathBuf::from("a.toml"));
+        assert_eq!(args.tolerance, 1.5);
+        assert_eq!(parse(&[]).unwrap().answers, answers::default_path());
+
+        assert!(parse(&["--tolerance", "0.5"]).is_err());
+        assert!(parse(&["--answers"]).is_err());
+        assert!(parse(&["17"]).is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let entry = Entry {
+            answer: Some(Answer::from(42)),
+            time: Some(Duration::from_millis(10)),
+            ..Entry::default()
+        };
+        let ms = Duration::from_millis;
+
+        assert_eq!(
+            check(Some(&entry), &42_usize.into(), ms(5), 2.0),
+            Status::Ok
+        );
+        assert_eq!(
+            check(Some(&entry), &41.into(), ms(5), 2.0),
+            Status::Mismatch(42.into())
+        );
+        assert_eq!(check(None, &42.into(), ms(5), 2.0), Status::Missing);
+        assert_eq!(
+            check(Some(&Entry::default()), &42.into(), ms(5), 2.0),
+            Status::Missing
+        );
+
+        let slower = match check_times() {
+            true => Status::Slower(ms(10)),
+            false => Status::Ok,
+        };
+        assert_eq!(check(Some(&entry), &42.into(), ms(25), 2.0), slower);
+        assert_eq!(check(Some(&entry), &42.into(), ms(25), 3.0), Status::Ok);
+    }
+
+    #[test]
+    fn test_answers_file() {
+        let answers = Answers::load(&answers::default_path()).unwrap();
+        for puzzle in registry::puzzles() {
+            let entry = answers.get(puzzle.day(), puzzle.part);
+            assert!(entry.is_some_and(|entry| entry.answer.is_some()));
+        }
+    }
+}